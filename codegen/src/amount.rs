@@ -0,0 +1,498 @@
+use heck::ToSnakeCase;
+use openapiv3::OpenAPI;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+type Properties = indexmap::IndexMap<String, openapiv3::ReferenceOr<Box<openapiv3::Schema>>>;
+
+/// Schema extension marking a monetary amount in major units.
+///
+/// ```json
+/// "amount": { "type": "number", "format": "float", "x-sumup-amount": true }
+/// ```
+///
+/// The spec declares amounts as `float`, `decimal` or string-encoded `double` numbers.
+/// Marked schemas are generated as the exact `crate::money::Amount` instead of a binary
+/// float. Rates, coordinates and other non-monetary numbers are left unmarked.
+pub const AMOUNT_EXTENSION: &str = "x-sumup-amount";
+
+/// Gives every schema marked with [`AMOUNT_EXTENSION`] the codegen-only `amount` number
+/// format, which [`crate::schema::is_amount_number`] maps to `crate::money::Amount`.
+///
+/// Fails when a marked schema is not a number, so that a mistyped annotation is noticed
+/// instead of silently generating a float.
+pub fn apply_amount_annotations(spec: &mut OpenAPI) -> Result<(), String> {
+    let mut visit = |location: &str, schema: &mut openapiv3::Schema| {
+        if !is_amount_annotated(schema) {
+            return Ok(());
+        }
+        mark_amount(schema).map_err(|_| {
+            format!(
+                "{} at `{}` must be a number or a string-encoded number",
+                AMOUNT_EXTENSION, location
+            )
+        })
+    };
+
+    if let Some(components) = spec.components.as_mut() {
+        for (name, schema) in &mut components.schemas {
+            if let Some(schema) = schema.as_mut_item() {
+                visit_schema(
+                    &format!("#/components/schemas/{}", name),
+                    schema,
+                    &mut visit,
+                )?;
+            }
+        }
+        for (name, body) in &mut components.request_bodies {
+            if let Some(body) = body.as_mut_item() {
+                let location = format!("#/components/requestBodies/{}", name);
+                visit_content(&location, &mut body.content, &mut visit)?;
+            }
+        }
+        for (name, response) in &mut components.responses {
+            if let Some(response) = response.as_mut_item() {
+                let location = format!("#/components/responses/{}", name);
+                visit_content(&location, &mut response.content, &mut visit)?;
+            }
+        }
+        for (name, parameter) in &mut components.parameters {
+            if let Some(parameter) = parameter.as_mut_item() {
+                let location = format!("#/components/parameters/{}", name);
+                visit_parameter(&location, parameter, &mut visit)?;
+            }
+        }
+    }
+    for (path, path_item) in spec.paths.paths.iter_mut() {
+        if let Some(path_item) = path_item.as_mut_item() {
+            visit_path_item(path, path_item, &mut visit)?;
+        }
+    }
+    Ok(())
+}
+
+fn is_amount_annotated(schema: &openapiv3::Schema) -> bool {
+    schema.schema_data.extensions.get(AMOUNT_EXTENSION) == Some(&serde_json::Value::Bool(true))
+}
+
+/// Turns a number or string-encoded number schema into an `amount` number.
+fn mark_amount(schema: &mut openapiv3::Schema) -> Result<(), ()> {
+    let amount_format = openapiv3::VariantOrUnknownOrEmpty::Unknown("amount".to_string());
+    match &mut schema.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::Number(number_type)) => {
+            number_type.format = amount_format;
+        }
+        kind if crate::schema::string_schema_numeric_kind(kind).is_some() => {
+            *kind = openapiv3::SchemaKind::Type(openapiv3::Type::Number(openapiv3::NumberType {
+                format: amount_format,
+                ..Default::default()
+            }));
+        }
+        _ => return Err(()),
+    }
+    Ok(())
+}
+
+type Visitor<'a> = dyn FnMut(&str, &mut openapiv3::Schema) -> Result<(), String> + 'a;
+
+fn visit_path_item(
+    location: &str,
+    path_item: &mut openapiv3::PathItem,
+    visit: &mut Visitor<'_>,
+) -> Result<(), String> {
+    for (index, parameter) in path_item.parameters.iter_mut().enumerate() {
+        if let Some(parameter) = parameter.as_mut_item() {
+            let location = format!("{}/parameters/{}", location, index);
+            visit_parameter(&location, parameter, visit)?;
+        }
+    }
+    let operations = [
+        ("delete", path_item.delete.as_mut()),
+        ("get", path_item.get.as_mut()),
+        ("head", path_item.head.as_mut()),
+        ("options", path_item.options.as_mut()),
+        ("patch", path_item.patch.as_mut()),
+        ("post", path_item.post.as_mut()),
+        ("put", path_item.put.as_mut()),
+        ("trace", path_item.trace.as_mut()),
+    ];
+    for (method, operation) in operations {
+        let Some(operation) = operation else {
+            continue;
+        };
+        let location = format!("{} {}", location, method);
+        for (index, parameter) in operation.parameters.iter_mut().enumerate() {
+            if let Some(parameter) = parameter.as_mut_item() {
+                let location = format!("{}/parameters/{}", location, index);
+                visit_parameter(&location, parameter, visit)?;
+            }
+        }
+        if let Some(body) = operation
+            .request_body
+            .as_mut()
+            .and_then(|body| body.as_mut_item())
+        {
+            let location = format!("{}/requestBody", location);
+            visit_content(&location, &mut body.content, visit)?;
+        }
+        let responses = operation
+            .responses
+            .default
+            .iter_mut()
+            .chain(operation.responses.responses.values_mut());
+        for response in responses {
+            if let Some(response) = response.as_mut_item() {
+                let location = format!("{}/responses", location);
+                visit_content(&location, &mut response.content, visit)?;
+            }
+        }
+        for (name, callback) in operation.callbacks.iter_mut() {
+            for (expression, path_item) in callback.iter_mut() {
+                let location = format!("{}/callbacks/{}/{}", location, name, expression);
+                visit_path_item(&location, path_item, visit)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn visit_parameter(
+    location: &str,
+    parameter: &mut openapiv3::Parameter,
+    visit: &mut Visitor<'_>,
+) -> Result<(), String> {
+    let parameter_data = match parameter {
+        openapiv3::Parameter::Query { parameter_data, .. }
+        | openapiv3::Parameter::Header { parameter_data, .. }
+        | openapiv3::Parameter::Path { parameter_data, .. }
+        | openapiv3::Parameter::Cookie { parameter_data, .. } => parameter_data,
+    };
+    match &mut parameter_data.format {
+        openapiv3::ParameterSchemaOrContent::Schema(schema) => match schema.as_mut_item() {
+            Some(schema) => visit_schema(location, schema, visit),
+            None => Ok(()),
+        },
+        openapiv3::ParameterSchemaOrContent::Content(content) => {
+            visit_content(location, content, visit)
+        }
+    }
+}
+
+fn visit_content(
+    location: &str,
+    content: &mut indexmap::IndexMap<String, openapiv3::MediaType>,
+    visit: &mut Visitor<'_>,
+) -> Result<(), String> {
+    for media_type in content.values_mut() {
+        if let Some(schema) = media_type
+            .schema
+            .as_mut()
+            .and_then(|schema| schema.as_mut_item())
+        {
+            visit_schema(location, schema, visit)?;
+        }
+    }
+    Ok(())
+}
+
+/// Calls `visit` on `schema` and every inline schema nested in it.
+fn visit_schema(
+    location: &str,
+    schema: &mut openapiv3::Schema,
+    visit: &mut Visitor<'_>,
+) -> Result<(), String> {
+    visit(location, schema)?;
+    let nested: Vec<(String, &mut openapiv3::Schema)> = match &mut schema.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::Object(object)) => {
+            let mut nested: Vec<_> = object
+                .properties
+                .iter_mut()
+                .filter_map(|(name, property)| {
+                    let property = property.as_mut_item()?.as_mut();
+                    Some((format!("{}/properties/{}", location, name), property))
+                })
+                .collect();
+            if let Some(openapiv3::AdditionalProperties::Schema(schema)) =
+                object.additional_properties.as_mut()
+            {
+                if let Some(schema) = schema.as_mut().as_mut_item() {
+                    nested.push((format!("{}/additionalProperties", location), schema));
+                }
+            }
+            nested
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Array(array)) => array
+            .items
+            .as_mut()
+            .and_then(|items| items.as_mut_item())
+            .map(|items| vec![(format!("{}/items", location), items.as_mut())])
+            .unwrap_or_default(),
+        openapiv3::SchemaKind::AllOf { all_of: members } => members_of(location, "allOf", members),
+        openapiv3::SchemaKind::OneOf { one_of: members } => members_of(location, "oneOf", members),
+        openapiv3::SchemaKind::AnyOf { any_of: members } => members_of(location, "anyOf", members),
+        openapiv3::SchemaKind::Not { not } => not
+            .as_mut()
+            .as_mut_item()
+            .map(|not| vec![(format!("{}/not", location), not)])
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+    for (location, schema) in nested {
+        visit_schema(&location, schema, visit)?;
+    }
+    Ok(())
+}
+
+fn members_of<'a>(
+    location: &str,
+    keyword: &str,
+    members: &'a mut [openapiv3::ReferenceOr<openapiv3::Schema>],
+) -> Vec<(String, &'a mut openapiv3::Schema)> {
+    members
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(index, member)| {
+            let member = member.as_mut_item()?;
+            Some((format!("{}/{}/{}", location, keyword, index), member))
+        })
+        .collect()
+}
+
+/// Generates `{field}_money` accessors pairing every amount field of a struct with its
+/// `currency`, or nothing when the struct has no `Currency` field.
+pub(crate) fn generate_money_accessors(
+    struct_ident: &Ident,
+    properties: &Properties,
+    required: &[String],
+) -> TokenStream {
+    let currency_required = match properties.get("currency") {
+        Some(openapiv3::ReferenceOr::Reference { reference })
+            if reference == "#/components/schemas/Currency" =>
+        {
+            required.iter().any(|name| name == "currency")
+        }
+        _ => return quote! {},
+    };
+
+    let accessors: Vec<TokenStream> = properties
+        .iter()
+        .filter(|(_, prop_ref)| match prop_ref {
+            openapiv3::ReferenceOr::Item(prop) => {
+                !prop.schema_data.nullable
+                    && matches!(
+                        &prop.schema_kind,
+                        openapiv3::SchemaKind::Type(openapiv3::Type::Number(number_type))
+                            if crate::schema::is_amount_number(number_type)
+                    )
+            }
+            openapiv3::ReferenceOr::Reference { .. } => false,
+        })
+        .map(|(name, _)| {
+            let field = crate::schema::make_rust_field_ident(name);
+            let method = Ident::new(&format!("{}_money", name.to_snake_case()), Span::call_site());
+            let amount_required = required.contains(name);
+            if amount_required && currency_required {
+                let doc = format!(" Returns `{}` in `currency` as [`Money`](crate::Money).", name);
+                return quote! {
+                    #[doc = #doc]
+                    ///
+                    /// Fails if the amount has more decimal places than the currency allows.
+                    pub fn #method(&self) -> Result<crate::Money, crate::money::MoneyError> {
+                        crate::Money::from_amount(self.#field, self.currency.clone())
+                    }
+                };
+            }
+
+            let amount = if amount_required {
+                quote! { Some(self.#field) }
+            } else {
+                quote! { self.#field }
+            };
+            let currency = if currency_required {
+                quote! { Some(&self.currency) }
+            } else {
+                quote! { self.currency.as_ref() }
+            };
+            let doc = format!(
+                " Returns `{}` in `currency` as [`Money`](crate::Money), or `None` when either is missing.",
+                name
+            );
+            quote! {
+                #[doc = #doc]
+                ///
+                /// Fails if the amount has more decimal places than the currency allows.
+                pub fn #method(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+                    let (Some(amount), Some(currency)) = (#amount, #currency) else {
+                        return None;
+                    };
+                    Some(crate::Money::from_amount(amount, currency.clone()))
+                }
+            }
+        })
+        .collect();
+    if accessors.is_empty() {
+        return quote! {};
+    }
+
+    quote! {
+        impl #struct_ident {
+            #(#accessors)*
+        }
+    }
+}
+
+trait AsMutItem<T> {
+    fn as_mut_item(&mut self) -> Option<&mut T>;
+}
+
+impl<T> AsMutItem<T> for openapiv3::ReferenceOr<T> {
+    fn as_mut_item(&mut self) -> Option<&mut T> {
+        match self {
+            openapiv3::ReferenceOr::Item(item) => Some(item),
+            openapiv3::ReferenceOr::Reference { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse_spec(value: serde_json::Value) -> OpenAPI {
+        serde_json::from_value(value).expect("failed to parse OpenAPI fixture")
+    }
+
+    fn receipt(spec: &OpenAPI) -> serde_json::Value {
+        serde_json::to_value(&spec.components.as_ref().unwrap().schemas["Receipt"]).unwrap()
+    }
+
+    fn fixture(name: serde_json::Value) -> OpenAPI {
+        parse_spec(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Test", "version": "1.0.0" },
+            "paths": {
+                "/payment-methods": {
+                    "get": {
+                        "parameters": [{
+                            "name": "amount",
+                            "in": "query",
+                            "schema": { "type": "number", "x-sumup-amount": true }
+                        }],
+                        "responses": {}
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Receipt": {
+                        "type": "object",
+                        "properties": {
+                            "amount": {
+                                "type": "number",
+                                "format": "float",
+                                "description": "Total.",
+                                "x-sumup-amount": true
+                            },
+                            "products": {
+                                "type": "array",
+                                "items": {
+                                    "type": "object",
+                                    "properties": {
+                                        "price": {
+                                            "type": "string",
+                                            "format": "double",
+                                            "x-sumup-amount": true
+                                        }
+                                    }
+                                }
+                            },
+                            "rate": { "type": "number", "format": "float" },
+                            "name": name
+                        }
+                    }
+                }
+            }
+        }))
+    }
+
+    #[test]
+    fn marks_annotated_numbers_and_string_encoded_numbers() {
+        let mut spec = fixture(json!({ "type": "string" }));
+
+        apply_amount_annotations(&mut spec).unwrap();
+
+        let receipt = receipt(&spec);
+        assert_eq!(
+            receipt["properties"]["amount"],
+            json!({
+                "type": "number",
+                "format": "amount",
+                "description": "Total.",
+                "x-sumup-amount": true
+            })
+        );
+        assert_eq!(
+            receipt["properties"]["products"]["items"]["properties"]["price"],
+            json!({ "type": "number", "format": "amount", "x-sumup-amount": true })
+        );
+        assert_eq!(
+            receipt["properties"]["rate"],
+            json!({ "type": "number", "format": "float" })
+        );
+        let parameter = serde_json::to_value(&spec.paths.paths["/payment-methods"]).unwrap();
+        assert_eq!(
+            parameter["get"]["parameters"][0]["schema"]["format"],
+            json!("amount")
+        );
+    }
+
+    #[test]
+    fn rejects_annotated_non_numbers() {
+        let mut spec = fixture(json!({ "type": "string", "x-sumup-amount": true }));
+
+        let err = apply_amount_annotations(&mut spec).unwrap_err();
+        assert!(
+            err.contains("`#/components/schemas/Receipt/properties/name`"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn money_accessors_pair_amounts_with_currency() {
+        let properties: Properties = serde_json::from_value(json!({
+            "amount": { "type": "number", "format": "amount" },
+            "tip_amount": { "type": "number", "format": "amount" },
+            "rate": { "type": "number", "format": "float" },
+            "currency": { "$ref": "#/components/schemas/Currency" }
+        }))
+        .unwrap();
+        let ident = Ident::new("Checkout", Span::call_site());
+
+        let required = ["amount".to_string(), "currency".to_string()];
+        let code =
+            crate::format_generated_code(generate_money_accessors(&ident, &properties, &required));
+        assert!(code.contains(
+            "pub fn amount_money(&self) -> Result<crate::Money, crate::money::MoneyError> {"
+        ));
+        assert!(code.contains("crate::Money::from_amount(self.amount, self.currency.clone())"));
+        assert!(code.contains(
+            "pub fn tip_amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {"
+        ));
+        assert!(code.contains("(self.tip_amount, Some(&self.currency))"));
+        assert!(!code.contains("rate_money"));
+
+        let mut without_currency = properties.clone();
+        without_currency.shift_remove("currency");
+        assert!(generate_money_accessors(&ident, &without_currency, &required).is_empty());
+    }
+
+    #[test]
+    fn vendored_spec_annotates_numbers_only() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../openapi.json");
+        let file = std::fs::File::open(path).expect("vendored spec should exist");
+        let mut spec: OpenAPI = serde_json::from_reader(file).unwrap();
+
+        apply_amount_annotations(&mut spec).unwrap();
+    }
+}
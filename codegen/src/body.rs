@@ -60,7 +60,7 @@ pub(crate) fn generate_operation_bodies_with_registry(
         nested_schemas.extend(query_types);
 
         if let Some(params_struct) =
-            generate_query_params_struct(op, &operation_name, &operation_origin, symbols)?
+            generate_query_params_struct(spec, op, &operation_name, &operation_origin, symbols)?
         {
             body_structs.push(params_struct);
         }
@@ -103,6 +103,7 @@ pub(crate) fn generate_operation_bodies_with_registry(
 
 /// Creates a query params struct for the operation when query parameters are defined.
 fn generate_query_params_struct(
+    spec: &OpenAPI,
     operation: &openapiv3::Operation,
     operation_name: &str,
     operation_origin: &str,
@@ -127,15 +128,14 @@ fn generate_query_params_struct(
     }
 
     let params_struct_name = format!("{}Params", operation_name.to_upper_camel_case());
-    symbols.reserve(
-        params_struct_name.clone(),
-        format!("query parameters for {operation_origin}"),
-    )?;
+    let origin = format!("query parameters for {operation_origin}");
+    symbols.reserve(params_struct_name.clone(), origin.clone())?;
     let struct_name = Ident::new(&params_struct_name, Span::call_site());
 
     // Generate fields
     let mut fields = Vec::new();
-    for param_data in query_params {
+    let mut builder_fields = Vec::new();
+    for param_data in &query_params {
         let field_name = crate::schema::make_rust_field_ident(&param_data.name.to_snake_case());
         let original_name = &param_data.name;
 
@@ -188,6 +188,24 @@ fn generate_query_params_struct(
             }
         };
 
+        let value_type = match &param_data.format {
+            openapiv3::ParameterSchemaOrContent::Schema(schema_ref) => {
+                infer_param_type(operation_name, &field_name.to_string(), schema_ref, true).0
+            }
+            _ => quote! { String },
+        };
+        builder_fields.push(crate::builder::BuilderField {
+            ident: field_name.clone(),
+            value_type,
+            kind: match (param_data.required, is_nullable) {
+                (true, _) => crate::builder::BuilderFieldKind::Required,
+                (false, true) => crate::builder::BuilderFieldKind::Nullable,
+                (false, false) => crate::builder::BuilderFieldKind::Optional,
+            },
+            deprecated: false,
+            attrs: description.clone(),
+        });
+
         fields.push(quote! {
             #description
             #rename_attr
@@ -196,11 +214,19 @@ fn generate_query_params_struct(
         });
     }
 
+    let builder =
+        crate::builder::generate_builder(&struct_name, &builder_fields, &origin, symbols)?;
+    let validation =
+        crate::validation::generate_params_validation(spec, &struct_name, &query_params)?;
+
     Ok(Some(quote! {
         #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+        #[non_exhaustive]
         pub struct #struct_name {
             #(#fields,)*
         }
+        #builder
+        #validation
     }))
 }
 
@@ -246,6 +272,11 @@ fn infer_param_type(
                         }
                     }
                 }
+                openapiv3::SchemaKind::Type(openapiv3::Type::Number(number_type))
+                    if crate::schema::is_amount_number(number_type) =>
+                {
+                    quote! { crate::money::Amount }
+                }
                 openapiv3::SchemaKind::Type(openapiv3::Type::Number(_)) => quote! { f64 },
                 openapiv3::SchemaKind::Type(openapiv3::Type::Integer(_)) => quote! { i64 },
                 openapiv3::SchemaKind::Type(openapiv3::Type::Boolean(_)) => quote! { bool },
@@ -451,6 +482,8 @@ fn build_string_enum(
     };
 
     let description = description.map(crate::schema::generate_doc_comment);
+    let validation =
+        crate::validation::generate_string_enum_validation(&type_ident, &other_variant_ident);
 
     Ok(quote! {
         #description
@@ -460,6 +493,7 @@ fn build_string_enum(
             #[serde(untagged)]
             #other_variant_ident(String),
         }
+        #validation
     })
 }
 
@@ -502,10 +536,8 @@ fn generate_request_body_struct(
     let struct_name = operation_request_type_ident(operation_name);
     let struct_name_str = struct_name.to_string();
 
-    symbols.reserve(
-        struct_name_str,
-        format!("request body for {operation_origin}"),
-    )?;
+    let origin = format!("request body for {operation_origin}");
+    symbols.reserve(struct_name_str, origin.clone())?;
 
     let description = request_body
         .description
@@ -518,6 +550,7 @@ fn generate_request_body_struct(
         &struct_name,
         schema,
         description,
+        Some(&origin),
         nested_schemas,
         symbols,
     )
@@ -633,6 +666,7 @@ fn generate_response_body_structs(
                             &struct_name,
                             schema,
                             description,
+                            None,
                             nested_schemas,
                             symbols,
                         )?;
@@ -710,6 +744,7 @@ fn generate_response_body_structs(
                                         &struct_name,
                                         schema,
                                         description,
+                                        None,
                                         nested_schemas,
                                         symbols,
                                     )?;
@@ -757,14 +792,20 @@ fn generate_response_body_structs(
 }
 
 /// Converts an inline schema into a concrete struct or type alias and tracks nested schemas.
-fn generate_schema_struct(
+///
+/// Request bodies are marked by `builder_origin`, naming the struct in error messages:
+/// their structs and nested structs get a builder and are `#[non_exhaustive]`, and they
+/// implement `Validate` together with their nested types.
+pub(crate) fn generate_schema_struct(
     spec: &OpenAPI,
     struct_name: &Ident,
     schema: &openapiv3::Schema,
     description: Option<TokenStream>,
+    builder_origin: Option<&str>,
     nested_schemas: &mut Vec<TokenStream>,
     symbols: &mut crate::symbol::SymbolRegistry,
 ) -> Result<TokenStream, String> {
+    let validate = builder_origin.is_some();
     match &schema.schema_kind {
         openapiv3::SchemaKind::Type(openapiv3::Type::Object(obj)) => {
             if crate::schema::should_emit_free_form_object_alias(
@@ -784,6 +825,7 @@ fn generate_schema_struct(
                 spec,
                 &struct_name_str,
                 &obj.properties,
+                validate,
                 nested_schemas,
                 symbols,
             )?;
@@ -804,12 +846,42 @@ fn generate_schema_struct(
                 quote! { #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)] }
             };
 
+            let (non_exhaustive, builder) = crate::builder::request_struct_builder(
+                spec,
+                struct_name,
+                &obj.properties,
+                &obj.required,
+                obj.additional_properties.as_ref(),
+                builder_origin,
+                symbols,
+            )?;
+
+            let validation = if validate {
+                crate::validation::generate_struct_validation(
+                    spec,
+                    struct_name,
+                    &obj.properties,
+                    &obj.required,
+                )?
+            } else {
+                quote! {}
+            };
+            let money = crate::amount::generate_money_accessors(
+                struct_name,
+                &obj.properties,
+                &obj.required,
+            );
+
             Ok(quote! {
                 #description
                 #derives
+                #non_exhaustive
                 pub struct #struct_name {
                     #(#fields)*
                 }
+                #builder
+                #validation
+                #money
             })
         }
         openapiv3::SchemaKind::AllOf { all_of } => {
@@ -822,6 +894,7 @@ fn generate_schema_struct(
                     spec,
                     &struct_name_str,
                     &combined_properties,
+                    validate,
                     nested_schemas,
                     symbols,
                 )?;
@@ -844,12 +917,42 @@ fn generate_schema_struct(
                     quote! { #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)] }
                 };
 
+                let (non_exhaustive, builder) = crate::builder::request_struct_builder(
+                    spec,
+                    struct_name,
+                    &combined_properties,
+                    &combined_required,
+                    combined_additional_properties.as_ref(),
+                    builder_origin,
+                    symbols,
+                )?;
+
+                let validation = if validate {
+                    crate::validation::generate_struct_validation(
+                        spec,
+                        struct_name,
+                        &combined_properties,
+                        &combined_required,
+                    )?
+                } else {
+                    quote! {}
+                };
+                let money = crate::amount::generate_money_accessors(
+                    struct_name,
+                    &combined_properties,
+                    &combined_required,
+                );
+
                 Ok(quote! {
                     #description
                     #derives
+                    #non_exhaustive
                     pub struct #struct_name {
                         #(#fields)*
                     }
+                    #builder
+                    #validation
+                    #money
                 })
             } else {
                 let dummy_ref = openapiv3::ReferenceOr::Item(Box::new(schema.clone()));
@@ -866,6 +969,19 @@ fn generate_schema_struct(
                 })
             }
         }
+        openapiv3::SchemaKind::OneOf { one_of: members }
+        | openapiv3::SchemaKind::AnyOf { any_of: members } => {
+            crate::schema::generate_composite_enum(
+                spec,
+                &struct_name.to_string(),
+                schema,
+                members,
+                description,
+                validate,
+                nested_schemas,
+                symbols,
+            )
+        }
         _ => {
             // For non-object types, create a type alias
             let dummy_ref = openapiv3::ReferenceOr::Item(Box::new(schema.clone()));
@@ -956,4 +1072,190 @@ mod tests {
         let client_code = crate::format_generated_code(client_tokens);
         assert!(client_code.contains("body: CreateCheckoutRequest,"));
     }
+
+    #[test]
+    fn request_and_params_structs_get_builders() {
+        let spec = parse_spec(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {
+                "/refunds": {
+                    "post": {
+                        "operationId": "CreateRefund",
+                        "tags": ["Refunds"],
+                        "parameters": [
+                            { "name": "merchant", "in": "query", "required": true, "schema": { "type": "string" } },
+                            { "name": "limit", "in": "query", "schema": { "type": "integer" } }
+                        ],
+                        "requestBody": {
+                            "required": true,
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "object",
+                                        "properties": {
+                                            "amount": { "type": "integer" },
+                                            "reason": { "type": "string", "nullable": true }
+                                        },
+                                        "required": ["amount"]
+                                    }
+                                }
+                            }
+                        },
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "type": "object",
+                                            "properties": { "id": { "type": "string" } }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }));
+
+        let body_tokens =
+            generate_operation_bodies(&spec, "Refunds").expect("body generation should succeed");
+        let body_code = crate::format_generated_code(body_tokens);
+        assert!(body_code.contains("#[non_exhaustive]\npub struct CreateRefundRequest {"));
+        assert!(body_code
+            .contains("pub fn builder(amount: impl Into<i64>) -> CreateRefundRequestBuilder"));
+        assert!(body_code.contains(
+            "pub fn reason(mut self, reason: impl Into<crate::Nullable<String>>) -> Self"
+        ));
+        assert!(body_code.contains("#[non_exhaustive]\npub struct CreateRefundParams {"));
+        assert!(body_code
+            .contains("pub fn builder(merchant: impl Into<String>) -> CreateRefundParamsBuilder"));
+        assert!(body_code.contains("pub fn limit(mut self, limit: impl Into<i64>) -> Self"));
+        assert!(!body_code.contains("CreateRefundResponseBuilder"));
+    }
+
+    #[test]
+    fn request_and_params_structs_validate_constraints() {
+        let spec = parse_spec(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {
+                "/refunds": {
+                    "post": {
+                        "operationId": "CreateRefund",
+                        "tags": ["Refunds"],
+                        "parameters": [
+                            { "name": "merchant", "in": "query", "required": true, "schema": { "type": "string" } },
+                            { "name": "limit", "in": "query", "schema": { "type": "integer", "minimum": 1, "maximum": 100 } }
+                        ],
+                        "requestBody": {
+                            "required": true,
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "object",
+                                        "properties": {
+                                            "reason": { "type": "string", "maxLength": 90, "nullable": true },
+                                            "country": { "$ref": "#/components/schemas/CountryCode" },
+                                            "tags": {
+                                                "type": "array",
+                                                "maxItems": 3,
+                                                "items": { "type": "string", "minLength": 1 }
+                                            },
+                                            "kind": { "type": "string", "enum": ["full", "partial"] }
+                                        },
+                                        "required": ["country"]
+                                    }
+                                }
+                            }
+                        },
+                        "responses": { "204": { "description": "ok" } }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "CountryCode": { "type": "string", "pattern": "^[A-Z]{2}$" }
+                }
+            }
+        }));
+
+        let body_tokens =
+            generate_operation_bodies(&spec, "Refunds").expect("body generation should succeed");
+        let body_code = crate::format_generated_code(body_tokens);
+        assert!(body_code.contains("impl crate::validation::Validate for CreateRefundRequest {"));
+        assert!(body_code.contains(
+            "if let Some(crate::Nullable::Value(value)) = &self.reason {\n            let path = crate::validation::field_path(path, \"reason\");\n            crate::validation::check_length(value, None, Some(90), &path, violations);"
+        ));
+        assert!(body_code.contains("regex_lite::Regex::new(\"^[A-Z]{2}$\")"));
+        assert!(body_code.contains(
+            "crate::validation::check_items(value.len(), None, Some(3), &path, violations);"
+        ));
+        assert!(body_code.contains("let path = crate::validation::item_path(&path, index);"));
+        assert!(body_code.contains("crate::validation::ViolationKind::Enum {"));
+        assert!(body_code.contains("impl crate::validation::Validate for CreateRefundParams {"));
+        assert!(body_code.contains("crate::validation::check_required(value, &path, violations);"));
+        assert!(body_code.contains("Some((1.0, false)),\n                Some((100.0, false)),"));
+    }
+
+    #[test]
+    fn only_request_types_validate() {
+        let spec = parse_spec(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {
+                "/refunds": {
+                    "post": {
+                        "operationId": "CreateRefund",
+                        "tags": ["Refunds"],
+                        "requestBody": {
+                            "required": true,
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "object",
+                                        "properties": {
+                                            "reason": {
+                                                "type": "object",
+                                                "properties": { "code": { "type": "string", "maxLength": 8 } }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        "responses": {
+                            "201": {
+                                "description": "Created.",
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "type": "object",
+                                            "properties": {
+                                                "status": { "type": "string", "enum": ["pending", "done"] },
+                                                "id": { "type": "string", "maxLength": 8 }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }));
+
+        let body_code = crate::format_generated_code(
+            generate_operation_bodies(&spec, "Refunds").expect("body generation should succeed"),
+        );
+        assert!(body_code.contains("impl crate::validation::Validate for CreateRefundRequest {"));
+        assert!(
+            body_code.contains("impl crate::validation::Validate for CreateRefundRequestReason {")
+        );
+        assert!(body_code.contains("pub struct CreateRefundResponse {"));
+        assert!(body_code.contains("pub enum CreateRefundResponseStatus {"));
+        assert!(!body_code.contains("Validate for CreateRefundResponse"));
+    }
 }
@@ -0,0 +1,391 @@
+use heck::ToUpperCamelCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+type Properties = indexmap::IndexMap<String, openapiv3::ReferenceOr<Box<openapiv3::Schema>>>;
+
+/// How a builder initializes and sets a struct field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BuilderFieldKind {
+    /// Passed to `builder()`.
+    Required,
+    /// `Option<T>`, set with `Some`.
+    Optional,
+    /// `Option<Nullable<T>>`, set with anything converting into `Nullable<T>`.
+    Nullable,
+    /// Starts from `Default::default()`, e.g. flattened additional properties.
+    Defaulted,
+}
+
+/// A field of a request struct, as seen by its builder.
+pub(crate) struct BuilderField {
+    pub(crate) ident: Ident,
+    /// Type of the value, without the `Option`/`Nullable` wrappers.
+    pub(crate) value_type: TokenStream,
+    pub(crate) kind: BuilderFieldKind,
+    pub(crate) deprecated: bool,
+    /// Doc comment and deprecation attributes copied to the setter.
+    pub(crate) attrs: TokenStream,
+}
+
+/// Describes the fields of a struct generated by [`crate::schema::generate_struct_fields`].
+///
+/// Setters of fields referencing a component schema are documented with the description of
+/// that schema, as the field itself has none. Setters without any description name the
+/// property they set.
+pub(crate) fn schema_builder_fields(
+    spec: &openapiv3::OpenAPI,
+    parent_name: &str,
+    properties: &Properties,
+    required: &[String],
+    additional_properties: Option<&openapiv3::AdditionalProperties>,
+) -> Vec<BuilderField> {
+    let mut fields: Vec<BuilderField> = properties
+        .iter()
+        .map(|(name, prop_ref)| {
+            let ident = crate::schema::make_rust_field_ident(name);
+            let is_required = required.contains(name);
+            let optional_kind = |nullable: bool| match (is_required, nullable) {
+                (true, _) => BuilderFieldKind::Required,
+                (false, true) => BuilderFieldKind::Nullable,
+                (false, false) => BuilderFieldKind::Optional,
+            };
+
+            match prop_ref {
+                openapiv3::ReferenceOr::Reference { reference } => {
+                    let type_name = reference.split('/').next_back().unwrap_or("Unknown");
+                    let type_ident =
+                        Ident::new(&type_name.to_upper_camel_case(), Span::call_site());
+                    let description = spec
+                        .components
+                        .as_ref()
+                        .and_then(|components| components.schemas.get(type_name))
+                        .and_then(|schema| schema.as_item())
+                        .and_then(|schema| schema.schema_data.description.as_deref())
+                        .map(crate::schema::generate_doc_comment)
+                        .unwrap_or_else(|| fallback_doc(name));
+                    BuilderField {
+                        ident,
+                        value_type: quote! { #type_ident },
+                        kind: optional_kind(false),
+                        deprecated: false,
+                        attrs: quote! { #description },
+                    }
+                }
+                openapiv3::ReferenceOr::Item(prop) => {
+                    let description = prop
+                        .schema_data
+                        .description
+                        .as_ref()
+                        .map(|d| crate::schema::generate_schema_doc_comment(Some(d), prop))
+                        .unwrap_or_else(|| fallback_doc(name));
+                    let deprecation =
+                        crate::schema::generate_deprecation_attribute(&prop.schema_data);
+                    BuilderField {
+                        ident,
+                        value_type: crate::schema::infer_rust_type(
+                            &prop.schema_kind,
+                            true,
+                            false,
+                            Some((parent_name, name)),
+                            prop_ref,
+                        ),
+                        kind: optional_kind(prop.schema_data.nullable),
+                        deprecated: prop.schema_data.deprecated,
+                        attrs: quote! { #description #deprecation },
+                    }
+                }
+            }
+        })
+        .collect();
+
+    if let Some(value_type) =
+        additional_properties.and_then(crate::schema::infer_additional_properties_value_type)
+    {
+        fields.push(BuilderField {
+            ident: crate::schema::pick_additional_properties_field_ident(properties),
+            value_type: quote! { std::collections::HashMap<String, #value_type> },
+            kind: BuilderFieldKind::Defaulted,
+            deprecated: false,
+            attrs: quote! { #[doc = " Properties not described by the API specification."] },
+        });
+    }
+
+    fields
+}
+
+fn fallback_doc(name: &str) -> TokenStream {
+    crate::schema::generate_doc_comment(&format!("Sets the `{name}` property."))
+}
+
+/// Returns `#[non_exhaustive]` and the builder of a struct sent in requests, or nothing for
+/// other structs.
+///
+/// `origin` names the struct in error messages and is `None` for structs only received
+/// from the API. Marking request structs `#[non_exhaustive]` makes the builder the only
+/// way to construct them outside the crate, so that new optional fields are not breaking.
+pub(crate) fn request_struct_builder(
+    spec: &openapiv3::OpenAPI,
+    struct_ident: &Ident,
+    properties: &Properties,
+    required: &[String],
+    additional_properties: Option<&openapiv3::AdditionalProperties>,
+    origin: Option<&str>,
+    symbols: &mut crate::symbol::SymbolRegistry,
+) -> Result<(TokenStream, TokenStream), String> {
+    let Some(origin) = origin else {
+        return Ok((quote! {}, quote! {}));
+    };
+    let fields = schema_builder_fields(
+        spec,
+        &struct_ident.to_string(),
+        properties,
+        required,
+        additional_properties,
+    );
+    Ok((
+        quote! { #[non_exhaustive] },
+        generate_builder(struct_ident, &fields, origin, symbols)?,
+    ))
+}
+
+/// Generates `builder()` for a request struct together with its builder type.
+///
+/// Required fields are arguments of `builder()` so that they cannot be forgotten; every
+/// other field has a setter named after it.
+pub(crate) fn generate_builder(
+    struct_ident: &Ident,
+    fields: &[BuilderField],
+    origin: &str,
+    symbols: &mut crate::symbol::SymbolRegistry,
+) -> Result<TokenStream, String> {
+    let builder_name = format!("{struct_ident}Builder");
+    symbols.reserve(builder_name.clone(), format!("builder for {origin}"))?;
+    let builder_ident = Ident::new(&builder_name, Span::call_site());
+
+    if let Some(field) = fields
+        .iter()
+        .find(|field| field.kind != BuilderFieldKind::Required && field.ident == "build")
+    {
+        return Err(format!(
+            "Field `{}` of {origin} clashes with `{builder_name}::build`",
+            field.ident
+        ));
+    }
+
+    let mut arguments = Vec::new();
+    let mut initializers = Vec::new();
+    let mut setters = Vec::new();
+    for field in fields {
+        let ident = &field.ident;
+        let value_type = &field.value_type;
+        match field.kind {
+            BuilderFieldKind::Required => {
+                arguments.push(quote! { #ident: impl Into<#value_type> });
+                initializers.push(quote! { #ident: #ident.into() });
+                continue;
+            }
+            BuilderFieldKind::Defaulted => {
+                initializers.push(quote! { #ident: Default::default() });
+            }
+            BuilderFieldKind::Optional | BuilderFieldKind::Nullable => {
+                initializers.push(quote! { #ident: None });
+            }
+        }
+
+        let (argument_type, value) = match field.kind {
+            BuilderFieldKind::Nullable => (
+                quote! { impl Into<crate::Nullable<#value_type>> },
+                quote! { Some(#ident.into()) },
+            ),
+            BuilderFieldKind::Optional => (
+                quote! { impl Into<#value_type> },
+                quote! { Some(#ident.into()) },
+            ),
+            _ => (quote! { impl Into<#value_type> }, quote! { #ident.into() }),
+        };
+        let attrs = &field.attrs;
+        let allow_deprecated = field.deprecated.then(|| quote! { #[allow(deprecated)] });
+        setters.push(quote! {
+            #attrs
+            #allow_deprecated
+            pub fn #ident(mut self, #ident: #argument_type) -> Self {
+                self.inner.#ident = #value;
+                self
+            }
+        });
+    }
+
+    let allow_deprecated = fields
+        .iter()
+        .any(|field| field.deprecated)
+        .then(|| quote! { #[allow(deprecated)] });
+    let allow_many_arguments =
+        (arguments.len() > 7).then(|| quote! { #[allow(clippy::too_many_arguments)] });
+    let builder_doc =
+        format!(" Returns a builder for [`{struct_ident}`], taking its required fields.");
+    let struct_doc = format!(" Builder for [`{struct_ident}`], see [`{struct_ident}::builder`].");
+    let build_doc = format!(" Returns the built [`{struct_ident}`].");
+
+    Ok(quote! {
+        impl #struct_ident {
+            #[doc = #builder_doc]
+            #allow_deprecated
+            #allow_many_arguments
+            pub fn builder(#(#arguments),*) -> #builder_ident {
+                #builder_ident {
+                    inner: Self {
+                        #(#initializers,)*
+                    },
+                }
+            }
+        }
+
+        #[doc = #struct_doc]
+        #[derive(Debug, Clone)]
+        #[must_use]
+        pub struct #builder_ident {
+            inner: #struct_ident,
+        }
+
+        impl #builder_ident {
+            #(#setters)*
+
+            #[doc = #build_doc]
+            pub fn build(self) -> #struct_ident {
+                self.inner
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Builds properties in the given order, which `serde_json::Value` maps would sort.
+    fn properties(entries: &[(&str, serde_json::Value)]) -> Properties {
+        entries
+            .iter()
+            .map(|(name, schema)| {
+                let schema = serde_json::from_value(schema.clone())
+                    .expect("failed to parse property fixture");
+                (name.to_string(), schema)
+            })
+            .collect()
+    }
+
+    fn generate(
+        properties: &Properties,
+        required: &[&str],
+        additional_properties: Option<&openapiv3::AdditionalProperties>,
+    ) -> Result<String, String> {
+        let spec: openapiv3::OpenAPI = serde_json::from_value(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Currency": { "type": "string", "description": "Currency of the amount." }
+                }
+            }
+        }))
+        .expect("failed to parse OpenAPI fixture");
+        let required: Vec<String> = required.iter().map(|name| name.to_string()).collect();
+        let fields = schema_builder_fields(
+            &spec,
+            "CreateDemo",
+            properties,
+            &required,
+            additional_properties,
+        );
+        let ident = Ident::new("CreateDemo", Span::call_site());
+        let mut symbols = crate::symbol::SymbolRegistry::new("demo");
+        generate_builder(&ident, &fields, "schema `CreateDemo`", &mut symbols)
+            .map(crate::format_generated_code)
+    }
+
+    #[test]
+    fn required_fields_are_builder_arguments_in_property_order() {
+        let properties = properties(&[
+            ("description", json!({ "type": "string" })),
+            (
+                "currency",
+                json!({ "$ref": "#/components/schemas/Currency" }),
+            ),
+            ("amount", json!({ "type": "number", "format": "double" })),
+            ("reference", json!({ "type": "string" })),
+        ]);
+        let code = generate(&properties, &["reference", "amount", "currency"], None).unwrap();
+
+        assert!(code.contains(
+            "pub fn builder(\n        currency: impl Into<Currency>,\n        amount: impl Into<f64>,\n        reference: impl Into<String>,\n    ) -> CreateDemoBuilder {"
+        ));
+        assert!(code.contains("currency: currency.into(),"));
+        assert!(code.contains("description: None,"));
+        assert!(!code.contains("pub fn currency("));
+        assert!(!code.contains("pub fn amount("));
+        assert!(!code.contains("pub fn reference("));
+    }
+
+    #[test]
+    fn optional_fields_get_setters() {
+        let properties = properties(&[
+            ("id", json!({ "type": "string" })),
+            (
+                "description",
+                json!({ "type": "string", "description": "Shown to the customer." }),
+            ),
+            ("tip", json!({ "type": "integer", "nullable": true })),
+        ]);
+        let additional_properties = openapiv3::AdditionalProperties::Any(true);
+        let code = generate(&properties, &["id"], Some(&additional_properties)).unwrap();
+
+        assert!(code.contains("pub fn builder(id: impl Into<String>) -> CreateDemoBuilder {"));
+        assert!(code.contains(
+            "    /// Shown to the customer.\n    pub fn description(mut self, description: impl Into<String>) -> Self {\n        self.inner.description = Some(description.into());"
+        ));
+        assert!(code.contains(
+            "pub fn tip(mut self, tip: impl Into<crate::Nullable<i64>>) -> Self {\n        self.inner.tip = Some(tip.into());"
+        ));
+        assert!(code.contains("additional_properties: Default::default(),"));
+        assert!(code.contains("self.inner.additional_properties = additional_properties.into();"));
+        assert!(code.contains("pub fn build(self) -> CreateDemo {"));
+    }
+
+    #[test]
+    fn setters_of_referenced_schemas_copy_their_description() {
+        let properties = properties(&[(
+            "currency",
+            json!({ "$ref": "#/components/schemas/Currency" }),
+        )]);
+        let code = generate(&properties, &[], None).unwrap();
+
+        assert!(code.contains(
+            "    /// Currency of the amount.\n    pub fn currency(mut self, currency: impl Into<Currency>) -> Self {"
+        ));
+    }
+
+    #[test]
+    fn setters_without_description_name_their_property() {
+        let properties = properties(&[(
+            "roles",
+            json!({ "type": "array", "items": { "type": "string" } }),
+        )]);
+        let code = generate(&properties, &[], None).unwrap();
+
+        assert!(code.contains(
+            "    /// Sets the `roles` property.\n    pub fn roles(mut self, roles: impl Into<Vec<String>>) -> Self {"
+        ));
+    }
+
+    #[test]
+    fn optional_fields_named_build_are_rejected() {
+        let properties = properties(&[("build", json!({ "type": "string" }))]);
+        let err = generate(&properties, &[], None).expect_err("setter clashes with build");
+        assert!(err.contains("Field `build` of schema `CreateDemo` clashes"));
+
+        assert!(generate(&properties, &["build"], None).is_ok());
+    }
+}
@@ -0,0 +1,303 @@
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use openapiv3::OpenAPI;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// Generates the `callbacks` module describing the requests SumUp sends for the
+/// operations under the given tag, together with structs for inline payloads.
+pub fn generate_callbacks(spec: &OpenAPI, tag: &str) -> Result<TokenStream, String> {
+    let mut symbols = crate::symbol::SymbolRegistry::new(tag.to_snake_case());
+    generate_callbacks_with_registry(spec, tag, &mut symbols)
+}
+
+pub(crate) fn generate_callbacks_with_registry(
+    spec: &OpenAPI,
+    tag: &str,
+    symbols: &mut crate::symbol::SymbolRegistry,
+) -> Result<TokenStream, String> {
+    let mut payload_structs = Vec::new();
+    let mut nested_schemas = Vec::new();
+    let mut callbacks = Vec::new();
+    let mut callback_names =
+        crate::symbol::SymbolRegistry::new(format!("{}::callbacks", tag.to_snake_case()));
+
+    for tagged_operation in crate::collect_tagged_operations(spec, tag) {
+        let operation_name = crate::operation_name(tagged_operation.operation);
+        let method_ident = Ident::new(&operation_name.to_snake_case(), Span::call_site());
+
+        for (callback_name, callback) in &tagged_operation.operation.callbacks {
+            for (url, callback_item) in callback {
+                for (http_method, callback_operation) in
+                    crate::operations_for_path_item(callback_item)
+                {
+                    let type_name = callback_name.to_upper_camel_case();
+                    // Suffixed so the marker is not confused with the payload type, which
+                    // often shares the callback's name.
+                    let marker_name = format!("{type_name}Callback");
+                    let origin = format!(
+                        "callback `{callback_name}` of operation `{operation_name}` ({} {url})",
+                        http_method.to_uppercase()
+                    );
+                    callback_names.reserve(marker_name.clone(), origin.clone())?;
+
+                    let mut request_description = None;
+                    let request_type = match &callback_operation.request_body {
+                        Some(request_body_ref) => {
+                            let request_body =
+                                crate::body::resolve_request_body(spec, request_body_ref)?;
+                            let schema_ref = crate::body::request_body_schema(request_body);
+                            let description = request_body.description.as_deref();
+                            request_description = match schema_ref {
+                                Some(schema_ref) => {
+                                    crate::schema::dereference_schema(spec, schema_ref)?
+                                        .schema_data
+                                        .description
+                                        .as_deref()
+                                        .or(description)
+                                }
+                                None => description,
+                            };
+                            payload_type(
+                                spec,
+                                schema_ref,
+                                &format!("{type_name}CallbackRequest"),
+                                description,
+                                &format!("request body of {origin}"),
+                                &mut payload_structs,
+                                &mut nested_schemas,
+                                symbols,
+                            )?
+                        }
+                        None => quote! { () },
+                    };
+
+                    let mut responses = Vec::new();
+                    let mut response_type = None;
+                    for (status, response_ref) in &callback_operation.responses.responses {
+                        let openapiv3::StatusCode::Code(code) = status else {
+                            continue;
+                        };
+                        let openapiv3::ReferenceOr::Item(response) = response_ref else {
+                            return Err(format!(
+                                "Unsupported response reference in {origin}, callback responses must be inline"
+                            ));
+                        };
+                        let description = response.description.trim();
+                        let status = proc_macro2::Literal::u16_unsuffixed(*code);
+                        responses.push(quote! {
+                            crate::callback::CallbackResponse {
+                                status: #status,
+                                description: #description,
+                            }
+                        });
+
+                        if response_type.is_none() && (200..300).contains(code) {
+                            let schema_ref =
+                                crate::preferred_response_media_type(&response.content)
+                                    .and_then(|media_type| media_type.schema.as_ref());
+                            response_type = Some(payload_type(
+                                spec,
+                                schema_ref,
+                                &format!("{type_name}CallbackResponse"),
+                                Some(description),
+                                &format!("response body of {origin}"),
+                                &mut payload_structs,
+                                &mut nested_schemas,
+                                symbols,
+                            )?);
+                        }
+                    }
+                    let response_type = response_type.unwrap_or_else(|| quote! { () });
+
+                    let summary = callback_operation
+                        .summary
+                        .as_deref()
+                        .or(callback_operation.description.as_deref())
+                        .or(request_description)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| format!("The `{callback_name}` callback."));
+                    let method_upper = http_method.to_uppercase();
+                    let doc = crate::schema::generate_doc_comment_from_lines(vec![
+                        summary,
+                        String::new(),
+                        format!(
+                            "Sent as `{method_upper} {url}` for requests made with [`{method_ident}`](super::{}Client::{method_ident}).",
+                            tag.to_upper_camel_case()
+                        ),
+                    ]);
+                    let type_ident = Ident::new(&marker_name, Span::call_site());
+                    let method_const = Ident::new(&method_upper, Span::call_site());
+                    let operation = method_ident.to_string();
+
+                    callbacks.push(quote! {
+                        #doc
+                        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                        pub struct #type_ident;
+                        impl crate::callback::Callback for #type_ident {
+                            const NAME: &'static str = #callback_name;
+                            const OPERATION: &'static str = #operation;
+                            const METHOD: reqwest::Method = reqwest::Method::#method_const;
+                            const URL: &'static str = #url;
+                            const RESPONSES: &'static [crate::callback::CallbackResponse] = &[
+                                #(#responses),*
+                            ];
+                            type Request = #request_type;
+                            type Response = #response_type;
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    if callbacks.is_empty() {
+        return Ok(quote! {});
+    }
+    symbols.reserve("callbacks", "callbacks module")?;
+
+    Ok(quote! {
+        #(#nested_schemas)*
+        #(#payload_structs)*
+        /// Requests SumUp sends to your server for the operations of this module.
+        pub mod callbacks {
+            #(#callbacks)*
+        }
+    })
+}
+
+/// Returns the type of a callback payload as seen from the `callbacks` module, generating
+/// a struct named `struct_name` for inline schemas.
+#[allow(clippy::too_many_arguments)]
+fn payload_type(
+    spec: &OpenAPI,
+    schema_ref: Option<&openapiv3::ReferenceOr<openapiv3::Schema>>,
+    struct_name: &str,
+    description: Option<&str>,
+    origin: &str,
+    payload_structs: &mut Vec<TokenStream>,
+    nested_schemas: &mut Vec<TokenStream>,
+    symbols: &mut crate::symbol::SymbolRegistry,
+) -> Result<TokenStream, String> {
+    match schema_ref {
+        None => Ok(quote! { () }),
+        Some(openapiv3::ReferenceOr::Reference { reference }) => {
+            let schema_name = reference
+                .strip_prefix("#/components/schemas/")
+                .ok_or_else(|| format!("Unsupported schema reference in {origin}: {reference}"))?;
+            let ident = Ident::new(schema_name, Span::call_site());
+            Ok(quote! { super::#ident })
+        }
+        Some(openapiv3::ReferenceOr::Item(schema)) => {
+            symbols.reserve(struct_name, origin)?;
+            let ident = Ident::new(struct_name, Span::call_site());
+            let description = description
+                .or(schema.schema_data.description.as_deref())
+                .map(|description| {
+                    crate::schema::generate_schema_doc_comment(Some(description), schema)
+                });
+            payload_structs.push(crate::body::generate_schema_struct(
+                spec,
+                &ident,
+                schema,
+                description,
+                None,
+                nested_schemas,
+                symbols,
+            )?);
+            Ok(quote! { super::#ident })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse_spec(value: serde_json::Value) -> OpenAPI {
+        serde_json::from_value(value).expect("failed to parse OpenAPI fixture")
+    }
+
+    fn callback_spec(request_schema: serde_json::Value) -> OpenAPI {
+        parse_spec(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {
+                "/demo": {
+                    "post": {
+                        "operationId": "CreateDemo",
+                        "tags": ["Demo"],
+                        "responses": { "204": { "description": "ok" } },
+                        "callbacks": {
+                            "DemoChanged": {
+                                "{$request.body#/return_url}": {
+                                    "post": {
+                                        "requestBody": {
+                                            "required": true,
+                                            "content": {
+                                                "application/json": { "schema": request_schema }
+                                            }
+                                        },
+                                        "responses": {
+                                            "200": { "description": "Accepted." },
+                                            "410": { "description": "Stop sending." }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "DemoChanged": {
+                        "type": "object",
+                        "properties": { "status": { "type": "string" } }
+                    }
+                }
+            }
+        }))
+    }
+
+    #[test]
+    fn callbacks_module_describes_request_and_responses() {
+        let spec = callback_spec(json!({ "$ref": "#/components/schemas/DemoChanged" }));
+        let code = generate_callbacks(&spec, "Demo")
+            .expect("callbacks should generate")
+            .to_string();
+
+        assert!(code.contains("pub mod callbacks"));
+        assert!(code.contains("pub struct DemoChangedCallback ;"));
+        assert!(code.contains("impl crate :: callback :: Callback for DemoChangedCallback"));
+        assert!(code.contains("const NAME : & 'static str = \"DemoChanged\""));
+        assert!(code.contains("const OPERATION : & 'static str = \"create_demo\""));
+        assert!(code.contains("reqwest :: Method :: POST"));
+        assert!(code.contains("\"{$request.body#/return_url}\""));
+        assert!(code.contains("type Request = super :: DemoChanged"));
+        assert!(code.contains("type Response = ()"));
+        assert!(code.contains("status : 410 ,"));
+    }
+
+    #[test]
+    fn inline_callback_payloads_get_structs() {
+        let spec = callback_spec(json!({
+            "type": "object",
+            "properties": { "event_id": { "type": "string" } },
+            "required": ["event_id"]
+        }));
+        let code = generate_callbacks(&spec, "Demo")
+            .expect("callbacks should generate")
+            .to_string();
+
+        assert!(code.contains("pub struct DemoChangedCallbackRequest"));
+        assert!(code.contains("type Request = super :: DemoChangedCallbackRequest"));
+    }
+
+    #[test]
+    fn tags_without_callbacks_generate_nothing() {
+        let spec = callback_spec(json!({ "$ref": "#/components/schemas/DemoChanged" }));
+        let code = generate_callbacks(&spec, "Other").expect("callbacks should generate");
+        assert!(code.is_empty());
+    }
+}
@@ -63,6 +63,10 @@ pub fn generate_client_file(
 
     let tokens = quote! {
         use crate::auth::Authorization;
+        use crate::middleware::{Middleware, Pipeline};
+        use crate::request_options::{RequestOptions, IDEMPOTENCY_KEY_HEADER};
+        use crate::retry::RetryPolicy;
+        use crate::scope::Scope;
 
         /// The main SumUp API client.
         ///
@@ -74,6 +78,10 @@ pub fn generate_client_file(
             authorization: Option<Authorization>,
             timeout: std::time::Duration,
             runtime_info: Vec<(&'static str, String)>,
+            retry_policy: Option<RetryPolicy>,
+            middleware: Pipeline,
+            granted_scopes: Option<Vec<Scope>>,
+            request_validation: bool,
         }
 
         impl Client {
@@ -105,13 +113,17 @@ pub fn generate_client_file(
             pub fn new() -> Self {
                 let authorization = std::env::var("SUMUP_API_KEY")
                     .ok()
-                    .map(Authorization::APIKey);
+                    .map(Authorization::api_key);
                 Self {
                     http_client: Self::build_http_client(),
                     base_url: "https://api.sumup.com".to_string(),
                     authorization,
                     timeout: std::time::Duration::from_secs(10),
                     runtime_info: crate::version::runtime_info(),
+                    retry_policy: None,
+                    middleware: Pipeline::default(),
+                    granted_scopes: None,
+                    request_validation: false,
                 }
             }
 
@@ -143,9 +155,39 @@ pub fn generate_client_file(
                 self
             }
 
-            /// Returns a reference to the HTTP client.
-            pub(crate) fn http_client(&self) -> &reqwest::Client {
-                &self.http_client
+            /// Sets the retry policy for API requests.
+            /// Returns a new client with the updated retry policy.
+            pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+                self.retry_policy = Some(retry_policy);
+                self
+            }
+
+            /// Sets the OAuth 2.0 scopes granted to the access token.
+            /// Operations whose scopes are not covered then fail fast with
+            /// [`AuthError::MissingScopes`](crate::auth::AuthError::MissingScopes) instead of
+            /// being rejected by the API.
+            /// Returns a new client with the granted scopes set.
+            pub fn with_granted_scopes(mut self, scopes: impl IntoIterator<Item = Scope>) -> Self {
+                self.granted_scopes = Some(scopes.into_iter().collect());
+                self
+            }
+
+            /// Enables or disables validating request bodies and query parameters against
+            /// the constraints of the API specification before sending them.
+            /// Invalid requests then fail with [`SdkError::Validation`](crate::SdkError::Validation)
+            /// listing the offending fields instead of being rejected by the API.
+            /// Returns a new client with the updated setting.
+            pub fn with_request_validation(mut self, enabled: bool) -> Self {
+                self.request_validation = enabled;
+                self
+            }
+
+            /// Registers a middleware that can inspect and modify every request and response.
+            /// Middleware runs in the order it was registered.
+            /// Returns a new client with the middleware appended.
+            pub fn with_middleware(mut self, middleware: impl Middleware) -> Self {
+                self.middleware.push(std::sync::Arc::new(middleware));
+                self
             }
 
             /// Returns the base URL for the API.
@@ -154,8 +196,9 @@ pub fn generate_client_file(
             }
 
             /// Returns the authorization token if set.
+            /// Returns `None` when the token is supplied by a token provider.
             pub fn authorization(&self) -> Option<&str> {
-                self.authorization.as_ref().map(|auth| auth.get_header())
+                self.authorization.as_ref().and_then(Authorization::get_header)
             }
 
             /// Returns the request timeout.
@@ -163,11 +206,156 @@ pub fn generate_client_file(
                 self.timeout
             }
 
+            /// Returns the retry policy if set.
+            pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+                self.retry_policy.as_ref()
+            }
+
+            /// Returns the granted OAuth 2.0 scopes if set.
+            pub fn granted_scopes(&self) -> Option<&[Scope]> {
+                self.granted_scopes.as_deref()
+            }
+
+            /// Returns whether requests are validated before they are sent.
+            pub fn request_validation(&self) -> bool {
+                self.request_validation
+            }
+
+            /// Validates `value` when request validation is enabled.
+            pub(crate) fn validate_request(&self, value: &impl crate::validation::Validate) -> Result<(), crate::validation::ValidationError> {
+                if self.request_validation {
+                    value.validate()
+                } else {
+                    Ok(())
+                }
+            }
+
+            /// Checks that the granted scopes include at least one of `required`.
+            pub(crate) fn check_scopes(&self, required: &[Scope]) -> Result<(), crate::auth::AuthError> {
+                match &self.granted_scopes {
+                    Some(granted) if !required.is_empty() && !required.iter().any(|scope| granted.contains(scope)) => {
+                        Err(crate::auth::AuthError::MissingScopes(required.to_vec()))
+                    }
+                    _ => Ok(()),
+                }
+            }
+
             /// Returns the runtime headers sent with each request.
             pub(crate) fn runtime_headers(&self) -> &[(&'static str, String)] {
                 &self.runtime_info
             }
 
+            /// Starts a request with the SDK headers, timeout and request options applied.
+            /// Authorization is added when the request is sent.
+            pub(crate) fn request(
+                &self,
+                method: reqwest::Method,
+                url: &str,
+                options: &RequestOptions,
+            ) -> reqwest::RequestBuilder {
+                let mut request = self
+                    .http_client
+                    .request(method, url)
+                    .header("User-Agent", crate::version::user_agent())
+                    .timeout(self.timeout);
+                for (header_name, header_value) in self.runtime_headers() {
+                    request = request.header(*header_name, header_value);
+                }
+                if let Some(idempotency_key) = options.idempotency_key() {
+                    request = request.header(IDEMPOTENCY_KEY_HEADER, idempotency_key);
+                }
+                request
+            }
+
+            /// Authorizes the request and sends it through the middleware, retrying transient
+            /// failures according to the retry policy.
+            ///
+            /// The method and elapsed time are recorded in the response extensions for
+            /// [`ResponseMeta`](crate::response::ResponseMeta).
+            pub(crate) async fn send<E>(
+                &self,
+                request: reqwest::RequestBuilder,
+            ) -> Result<reqwest::Response, crate::error::SdkError<E>> {
+                self.send_request(request, false).await
+            }
+
+            /// Same as [`Self::send`] for a request the API deduplicates by a field of its
+            /// body (`x-idempotency-key` in the API specification), which makes retrying it
+            /// safe whatever its method.
+            pub(crate) async fn send_deduplicated<E>(
+                &self,
+                request: reqwest::RequestBuilder,
+            ) -> Result<reqwest::Response, crate::error::SdkError<E>> {
+                self.send_request(request, true).await
+            }
+
+            async fn send_request<E>(
+                &self,
+                request: reqwest::RequestBuilder,
+                deduplicated: bool,
+            ) -> Result<reqwest::Response, crate::error::SdkError<E>> {
+                let request = request.build()?;
+                let method = request.method().clone();
+                let started = std::time::Instant::now();
+                let mut response = self.authorize_and_execute(request, deduplicated).await?;
+                response.extensions_mut().insert(crate::response::RequestInfo {
+                    method,
+                    elapsed: started.elapsed(),
+                });
+                Ok(response)
+            }
+
+            /// Sets the bearer token on the request and sends it.
+            ///
+            /// When the token of a token provider is rejected with `401 Unauthorized`, the
+            /// request is repeated once with a refreshed token.
+            async fn authorize_and_execute<E>(
+                &self,
+                mut request: reqwest::Request,
+                deduplicated: bool,
+            ) -> Result<reqwest::Response, crate::error::SdkError<E>> {
+                let Some(authorization) = &self.authorization else {
+                    return Ok(self.execute(request, deduplicated).await?);
+                };
+
+                let provider = match authorization {
+                    Authorization::Provider(provider) => provider,
+                    Authorization::AccessToken(token) | Authorization::APIKey(token) => {
+                        crate::auth::set_bearer(&mut request, token)?;
+                        return Ok(self.execute(request, deduplicated).await?);
+                    }
+                };
+
+                let token = provider.access_token().await?;
+                crate::auth::set_bearer(&mut request, &token)?;
+                let replay = request.try_clone();
+                let response = self.execute(request, deduplicated).await?;
+                match replay {
+                    Some(mut replay) if response.status() == reqwest::StatusCode::UNAUTHORIZED => {
+                        let refreshed = provider.refresh_access_token(token.secret()).await?;
+                        if refreshed == token {
+                            return Ok(response);
+                        }
+                        crate::auth::set_bearer(&mut replay, &refreshed)?;
+                        Ok(self.execute(replay, deduplicated).await?)
+                    }
+                    _ => Ok(response),
+                }
+            }
+
+            /// Sends a request through the middleware, retrying transient failures
+            /// according to the retry policy.
+            async fn execute(
+                &self,
+                request: reqwest::Request,
+                deduplicated: bool,
+            ) -> Result<reqwest::Response, reqwest::Error> {
+                crate::retry::send(request, self.retry_policy.as_ref(), deduplicated, |request| {
+                    self.middleware.execute(&self.http_client, request)
+                })
+                .await
+            }
+
             #(#tag_methods)*
         }
 
@@ -12,14 +12,21 @@ use quote::quote;
 
 use operation::GeneratedClientMethods;
 
+pub mod amount;
 pub mod body;
+mod builder;
+pub mod callback;
 pub mod client;
 pub mod operation;
+pub mod pagination;
 pub mod samples;
 pub mod schema;
+pub mod scope;
 mod symbol;
 pub mod tag;
+mod validation;
 
+pub use amount::apply_amount_annotations;
 pub use body::generate_operation_bodies;
 pub use client::generate_client_file;
 pub use operation::generate_client_methods;
@@ -126,7 +133,8 @@ pub struct Generator {
 
 impl Generator {
     /// Prepares a generator by loading derived schema metadata for later use.
-    pub fn new(spec: OpenAPI, out_path: impl Into<PathBuf>) -> Result<Self, String> {
+    pub fn new(mut spec: OpenAPI, out_path: impl Into<PathBuf>) -> Result<Self, String> {
+        apply_amount_annotations(&mut spec)?;
         let mut out_path = out_path.into();
         out_path.push("src");
         let schemas_by_tag = collect_schemas_by_tag(&spec)?;
@@ -148,6 +156,7 @@ impl Generator {
 
         self.ensure_directories()?;
         self.generate_api_version_file()?;
+        self.generate_scope_module()?;
         self.generate_common_module()?;
         self.generate_tag_modules()?;
         self.generate_client_module()?;
@@ -214,11 +223,14 @@ impl Generator {
             &self.spec,
             &tag_data.all_schemas,
             &tag_data.error_schemas,
+            &tag_data.request_schemas,
             &mut symbols,
         )?;
         let body_tokens =
             body::generate_operation_bodies_with_registry(&self.spec, tag, &mut symbols)?;
         let client_tokens = generate_tag_client_with_registry(&self.spec, tag, &mut symbols)?;
+        let callback_tokens =
+            callback::generate_callbacks_with_registry(&self.spec, tag, &mut symbols)?;
         let module_doc_comment = tag_description(&self.spec, tag)
             .map(generate_module_doc_comment)
             .unwrap_or_default();
@@ -241,6 +253,8 @@ impl Generator {
             #body_tokens
 
             #client_tokens
+
+            #callback_tokens
         };
 
         let contents = format_generated_code(combined_tokens);
@@ -276,6 +290,11 @@ impl Generator {
         generate_client_file(&self.out_path, &self.spec, &self.schemas_by_tag.tag_schemas)
     }
 
+    fn generate_scope_module(&self) -> Result<(), String> {
+        Self::log("[generate sdk] generating scope.rs ...");
+        scope::generate_scope_file(&self.out_path, &self.spec)
+    }
+
     fn generate_api_version_file(&self) -> Result<(), String> {
         let api_version = self.spec.info.version.trim().to_string();
 
@@ -387,6 +406,7 @@ pub fn generate_common_file(
         spec,
         &schemas_by_tag.common_schemas,
         &schemas_by_tag.common_error_schemas,
+        &schemas_by_tag.common_request_schemas,
         &mut symbols,
     )?;
 
@@ -479,7 +499,7 @@ fn generate_tag_client_with_registry(
         #extra_items_tokens
 
         #doc_comment
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         pub struct #client_type<'a> {
             client: &'a Client,
         }
@@ -707,6 +727,7 @@ mod tests {
             spec,
             &tag_data.all_schemas,
             &tag_data.error_schemas,
+            &tag_data.request_schemas,
             &mut symbols,
         )?;
         body::generate_operation_bodies_with_registry(spec, tag, &mut symbols)?;
@@ -1099,6 +1120,28 @@ mod tests {
         assert!(error.contains("inline schema for field `CreateRequest.details`"));
     }
 
+    #[test]
+    fn amount_number_format_maps_to_exact_amount() {
+        let rust_type = |schema: serde_json::Value| {
+            let schema: openapiv3::Schema = serde_json::from_value(schema).unwrap();
+            let schema_ref = openapiv3::ReferenceOr::Item(Box::new(schema.clone()));
+            schema::infer_rust_type(&schema.schema_kind, true, false, None, &schema_ref).to_string()
+        };
+
+        assert_eq!(
+            rust_type(json!({ "type": "number", "format": "amount" })),
+            "crate :: money :: Amount"
+        );
+        assert_eq!(
+            rust_type(json!({ "type": "number", "format": "float" })),
+            "f32"
+        );
+        assert_eq!(
+            rust_type(json!({ "type": "number", "format": "decimal" })),
+            "f64"
+        );
+    }
+
     #[test]
     fn format_generated_code_falls_back_for_non_file_token_streams() {
         let tokens = TokenStream::from_str("not valid rust syntax").expect("valid token stream");
@@ -76,6 +76,48 @@ pub(crate) fn generate_client_methods_with_registry(
     })
 }
 
+/// Returns the request body field named by the `x-idempotency-key` extension of the
+/// operation. The API deduplicates requests by this field, so the field must be required.
+///
+/// ```json
+/// "x-idempotency-key": "checkout_reference"
+/// ```
+pub(crate) fn operation_idempotency_key(
+    spec: &OpenAPI,
+    operation: &openapiv3::Operation,
+    origin: &str,
+) -> Result<Option<String>, String> {
+    let Some(value) = operation.extensions.get("x-idempotency-key") else {
+        return Ok(None);
+    };
+    let field = value
+        .as_str()
+        .ok_or_else(|| format!("{origin}: x-idempotency-key must name a request body field"))?;
+    let required = match &operation.request_body {
+        Some(request_body_ref) => {
+            let request_body = crate::body::resolve_request_body(spec, request_body_ref)?;
+            match crate::body::request_body_schema(request_body) {
+                Some(schema_ref) if request_body.required => {
+                    match &crate::schema::dereference_schema(spec, schema_ref)?.schema_kind {
+                        openapiv3::SchemaKind::Type(openapiv3::Type::Object(object)) => {
+                            object.required.iter().any(|name| name == field)
+                        }
+                        _ => false,
+                    }
+                }
+                _ => false,
+            }
+        }
+        None => false,
+    };
+    if !required {
+        return Err(format!(
+            "{origin}: x-idempotency-key `{field}` must be a required field of the request body"
+        ));
+    }
+    Ok(Some(field.to_string()))
+}
+
 /// Generates a concrete client method for the provided HTTP operation and path.
 fn generate_operation_method(
     spec: &OpenAPI,
@@ -96,9 +138,18 @@ fn generate_operation_method(
     let method_name = operation_name.to_snake_case();
 
     let method_ident = Ident::new(&method_name, Span::call_site());
+    let with_response_ident =
+        Ident::new(&format!("{method_name}_with_response"), Span::call_site());
+    let with_options_ident = Ident::new(&format!("{method_name}_with_options"), Span::call_site());
+    let with_response_and_options_ident = Ident::new(
+        &format!("{method_name}_with_response_and_options"),
+        Span::call_site(),
+    );
+    let send_ident = Ident::new(&format!("send_{method_name}"), Span::call_site());
 
     // Collect path parameters from both path-level and operation-level
     let mut path_params = Vec::new();
+    let mut call_args = Vec::new();
     let mut path_param_names = Vec::new();
 
     // Process path-level parameters first
@@ -114,6 +165,7 @@ fn generate_operation_method(
                 let param_ident = crate::schema::make_rust_field_ident(&param_name);
 
                 path_params.push(quote! { #param_ident: impl Into<String> });
+                call_args.push(quote! { #param_ident });
                 path_param_names.push((parameter_data.name.clone(), param_ident));
             }
         }
@@ -132,6 +184,7 @@ fn generate_operation_method(
                 let param_ident = crate::schema::make_rust_field_ident(&param_name);
 
                 path_params.push(quote! { #param_ident: impl Into<String> });
+                call_args.push(quote! { #param_ident });
                 path_param_names.push((parameter_data.name.clone(), param_ident));
             }
         }
@@ -158,6 +211,8 @@ fn generate_operation_method(
             (None, false, false)
         };
 
+    let deduplicated = operation_idempotency_key(spec, operation, &operation_origin)?.is_some();
+
     // Collect query parameters
     let mut query_params = Vec::new();
 
@@ -175,6 +230,7 @@ fn generate_operation_method(
     // Add body parameter after path parameters
     if let Some(body_param) = body_param {
         path_params.push(body_param);
+        call_args.push(quote! { body });
     }
 
     // Add query params parameter if there are query parameters
@@ -183,6 +239,7 @@ fn generate_operation_method(
         let params_type_name = format!("{}Params", operation_name.to_upper_camel_case());
         let params_type = Ident::new(&params_type_name, Span::call_site());
         path_params.push(quote! { params: #params_type });
+        call_args.push(quote! { params });
     }
 
     // Build the path with parameter substitution using format!
@@ -207,7 +264,7 @@ fn generate_operation_method(
     };
 
     // Generate HTTP method call
-    let http_method_ident = Ident::new(http_method, Span::call_site());
+    let http_method_ident = Ident::new(&http_method.to_uppercase(), Span::call_site());
 
     // Determine response type and error handling
     let OperationResponse {
@@ -217,7 +274,27 @@ fn generate_operation_method(
         error_definition,
     } = generate_response_handling(&operation_name, &operation_origin, operation, spec, symbols)?;
 
-    let doc_comment = build_operation_doc_comment(operation);
+    let scopes = crate::scope::operation_scopes(spec, operation);
+    let doc_comment = build_operation_doc_comment(operation, &scopes);
+    let scopes_ident = Ident::new(
+        &format!("{}_SCOPES", method_name.to_uppercase()),
+        Span::call_site(),
+    );
+    let scope_variants = scopes
+        .iter()
+        .map(|scope| crate::scope::scope_variant_ident(scope));
+    let with_response_doc = crate::schema::generate_doc_comment(&format!(
+        "Same as [`Self::{method_name}`], additionally returning the metadata of the response."
+    ));
+    let with_options_doc = crate::schema::generate_doc_comment(&format!(
+        "Same as [`Self::{method_name}`], sending the request with the given options, e.g. an idempotency key."
+    ));
+    let with_response_and_options_doc = crate::schema::generate_doc_comment(&format!(
+        "Same as [`Self::{method_name}_with_response`], sending the request with the given options."
+    ));
+    let scopes_doc = crate::schema::generate_doc_comment(&format!(
+        "OAuth 2.0 scopes accepted by [`Self::{method_name}`]; any one of them grants access."
+    ));
 
     // Build query parameter additions
     let query_additions = if has_query_params {
@@ -239,7 +316,27 @@ fn generate_operation_method(
                 false
             };
 
-            if query_param.required {
+            // Lists repeat the parameter once per item, e.g. `statuses[]=A&statuses[]=B`.
+            let is_array = match &query_param.format {
+                openapiv3::ParameterSchemaOrContent::Schema(schema_ref) => matches!(
+                    crate::schema::dereference_schema(spec, schema_ref)?.schema_kind,
+                    openapiv3::SchemaKind::Type(openapiv3::Type::Array(_))
+                ),
+                openapiv3::ParameterSchemaOrContent::Content(_) => false,
+            };
+
+            if is_array && !is_nullable {
+                let values = if query_param.required {
+                    quote! { Some(&params.#field_name) }
+                } else {
+                    quote! { params.#field_name.as_ref() }
+                };
+                query_field_additions.push(quote! {
+                    for value in #values.into_iter().flatten() {
+                        request = request.query(&[(#param_name, value)]);
+                    }
+                });
+            } else if query_param.required {
                 query_field_additions.push(quote! {
                     request = request.query(&[(#param_name, &params.#field_name)]);
                 });
@@ -270,65 +367,96 @@ fn generate_operation_method(
         quote! {}
     };
 
+    // The API deduplicates these requests by a field of their body, so they may be retried.
+    let send_method = if deduplicated {
+        quote! { send_deduplicated }
+    } else {
+        quote! { send }
+    };
+
     // Build the request with or without body
+    let request_binding = if has_query_params || has_optional_body {
+        quote! { let mut request }
+    } else {
+        quote! { let request }
+    };
     let request_send = if has_body {
         if has_optional_body {
-            // Optional body - use a let mut and conditionally add json
+            // Optional body - conditionally add json
             quote! {
-                let mut request = self.client.http_client().#http_method_ident(&url)
-                    .header("User-Agent", crate::version::user_agent())
-                    .timeout(self.client.timeout());
-                if let Some(authorization) = self.client.authorization() {
-                    request = request.header("Authorization", format!("Bearer {}", authorization));
-                }
-                for (header_name, header_value) in self.client.runtime_headers() {
-                    request = request.header(*header_name, header_value);
-                }
+                #request_binding = self.client.request(reqwest::Method::#http_method_ident, &url, options);
                 #query_additions
                 if let Some(body) = body {
                     request = request.json(&body);
                 }
-                let response = request.send().await?;
+                let response = self.client.#send_method(request).await?;
             }
         } else {
             // Required body
             quote! {
-                let mut request = self.client.http_client()
-                    .#http_method_ident(&url)
-                    .header("User-Agent", crate::version::user_agent())
-                    .timeout(self.client.timeout())
+                #request_binding = self.client
+                    .request(reqwest::Method::#http_method_ident, &url, options)
                     .json(&body);
-                if let Some(authorization) = self.client.authorization() {
-                    request = request.header("Authorization", format!("Bearer {}", authorization));
-                }
-                for (header_name, header_value) in self.client.runtime_headers() {
-                    request = request.header(*header_name, header_value);
-                }
                 #query_additions
-                let response = request.send().await?;
+                let response = self.client.#send_method(request).await?;
             }
         }
     } else {
         // No body
         quote! {
-            let mut request = self.client.http_client()
-                .#http_method_ident(&url)
-                .header("User-Agent", crate::version::user_agent())
-                .timeout(self.client.timeout());
-            if let Some(authorization) = self.client.authorization() {
-                request = request.header("Authorization", format!("Bearer {}", authorization));
-            }
-            for (header_name, header_value) in self.client.runtime_headers() {
-                request = request.header(*header_name, header_value);
-            }
+            #request_binding = self.client.request(reqwest::Method::#http_method_ident, &url, options);
             #query_additions
-            let response = request.send().await?;
+            let response = self.client.#send_method(request).await?;
         }
     };
 
+    let mut validations = Vec::new();
+    if has_body {
+        validations.push(if has_optional_body {
+            quote! {
+                if let Some(body) = &body {
+                    self.client.validate_request(body)?;
+                }
+            }
+        } else {
+            quote! { self.client.validate_request(&body)?; }
+        });
+    }
+    if has_query_params {
+        validations.push(quote! { self.client.validate_request(&params)?; });
+    }
+
     let method_tokens = quote! {
+        #scopes_doc
+        pub const #scopes_ident: &[crate::scope::Scope] = &[#(crate::scope::Scope::#scope_variants),*];
+
         #doc_comment
         pub async fn #method_ident(&self, #(#path_params),*) -> crate::error::SdkResult<#return_type, #error_type> {
+            self.#send_ident(#(#call_args,)* &Default::default())
+                .await
+                .map(|(data, _)| data)
+        }
+
+        #with_options_doc
+        pub async fn #with_options_ident(&self, #(#path_params,)* options: crate::request_options::RequestOptions) -> crate::error::SdkResult<#return_type, #error_type> {
+            self.#send_ident(#(#call_args,)* &options)
+                .await
+                .map(|(data, _)| data)
+        }
+
+        #with_response_doc
+        pub async fn #with_response_ident(&self, #(#path_params),*) -> crate::error::SdkResult<(#return_type, crate::response::ResponseMeta), #error_type> {
+            self.#send_ident(#(#call_args,)* &Default::default()).await
+        }
+
+        #with_response_and_options_doc
+        pub async fn #with_response_and_options_ident(&self, #(#path_params,)* options: crate::request_options::RequestOptions) -> crate::error::SdkResult<(#return_type, crate::response::ResponseMeta), #error_type> {
+            self.#send_ident(#(#call_args,)* &options).await
+        }
+
+        async fn #send_ident(&self, #(#path_params,)* options: &crate::request_options::RequestOptions) -> crate::error::SdkResult<(#return_type, crate::response::ResponseMeta), #error_type> {
+            self.client.check_scopes(Self::#scopes_ident)?;
+            #(#validations)*
             #path_construction
             let url = format!("{}{}", self.client.base_url(), path);
             #request_send
@@ -336,6 +464,32 @@ fn generate_operation_method(
         }
     };
 
+    let pagination_methods = match crate::pagination::operation_pagination(operation)? {
+        Some(pagination) => {
+            let path_param_idents: Vec<Ident> = path_param_names
+                .iter()
+                .map(|(_, ident)| ident.clone())
+                .collect();
+            crate::pagination::generate_pagination_methods(
+                spec,
+                operation,
+                &pagination,
+                &crate::pagination::PaginatedMethod {
+                    operation_name: &operation_name,
+                    method_name: &method_name,
+                    path_params: &path_param_idents,
+                    has_body,
+                    error_type: &error_type,
+                },
+            )?
+        }
+        None => quote! {},
+    };
+    let method_tokens = quote! {
+        #method_tokens
+        #pagination_methods
+    };
+
     let mut extra_items = Vec::new();
     if let Some(definition) = error_definition {
         extra_items.push(definition);
@@ -461,7 +615,10 @@ fn get_response_type_for_single(
     }
 }
 
-fn build_operation_doc_comment(operation: &openapiv3::Operation) -> Option<TokenStream> {
+fn build_operation_doc_comment(
+    operation: &openapiv3::Operation,
+    scopes: &[String],
+) -> Option<TokenStream> {
     let mut lines = Vec::new();
 
     match (&operation.summary, &operation.description) {
@@ -513,6 +670,18 @@ fn build_operation_doc_comment(operation: &openapiv3::Operation) -> Option<Token
         lines.extend(response_lines);
     }
 
+    if !scopes.is_empty() {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        let scopes = scopes
+            .iter()
+            .map(|scope| format!("`{scope}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(format!("OAuth 2.0 scopes (any of): {scopes}"));
+    }
+
     if lines.is_empty() {
         None
     } else {
@@ -696,13 +865,45 @@ fn generate_error_handling(
     use heck::ToUpperCamelCase;
 
     let mut entries = Vec::new();
+    let mut composite_bodies = Vec::new();
+    let mut nested_schemas = Vec::new();
 
     for (status_code, response_ref) in error_responses {
         let status_const = status_code_to_constant(*status_code);
         let body_kind = match extract_error_schema_ident(response_ref, spec) {
             Some(ident) => BodyKind::Schema(ident),
-            None if response_has_content(response_ref, spec) => BodyKind::Unknown,
-            None => BodyKind::Empty,
+            None => match inline_composite_error_schema(response_ref, spec) {
+                Some((schema, members)) => {
+                    let type_name = format!(
+                        "{}{}Body",
+                        operation_name.to_upper_camel_case(),
+                        status_code_to_variant_name(*status_code)
+                    );
+                    symbols.reserve(
+                        type_name.clone(),
+                        format!("{status_code} response body for {operation_origin}"),
+                    )?;
+                    let description = schema
+                        .schema_data
+                        .description
+                        .as_deref()
+                        .or_else(|| response_description(response_ref, spec))
+                        .map(|d| crate::schema::generate_schema_doc_comment(Some(d), schema));
+                    composite_bodies.push(crate::schema::generate_composite_enum(
+                        spec,
+                        &type_name,
+                        schema,
+                        members,
+                        description,
+                        false,
+                        &mut nested_schemas,
+                        symbols,
+                    )?);
+                    BodyKind::Schema(Ident::new(&type_name, Span::call_site()))
+                }
+                None if response_has_content(response_ref, spec) => BodyKind::Unknown,
+                None => BodyKind::Empty,
+            },
         };
         entries.push(ErrorEntry {
             status_code: *status_code,
@@ -727,6 +928,7 @@ fn generate_error_handling(
     let enum_ident = Ident::new(&enum_name, Span::call_site());
 
     let mut variant_defs = Vec::new();
+    let mut status_arms = Vec::new();
     let mut seen_variants: Vec<String> = Vec::new();
     let mut match_arms = Vec::new();
 
@@ -748,6 +950,17 @@ fn generate_error_handling(
                     variant_defs.push(quote! { #variant_ident, });
                 }
             }
+            let pattern = match &body_kind {
+                BodyKind::Empty => quote! { Self::#variant_ident },
+                _ => quote! { Self::#variant_ident(_) },
+            };
+            let status_code = entry.status_code;
+            let status = if status_const.to_string().starts_with("reqwest") {
+                quote! { Some(#status_const) }
+            } else {
+                quote! { reqwest::StatusCode::from_u16(#status_code).ok() }
+            };
+            status_arms.push(quote! { #pattern => #status, });
             seen_variants.push(variant_name.clone());
         }
 
@@ -755,24 +968,26 @@ fn generate_error_handling(
             BodyKind::Schema(ident) => {
                 match_arms.push(quote! {
                     #status_const => {
-                        let body: #ident = response.json().await?;
-                        Err(crate::error::SdkError::api(#enum_ident::#variant_ident(body)))
+                        let (body, meta): (#ident, _) = crate::error::read_json(response).await?;
+                        Err(crate::error::SdkError::api(#enum_ident::#variant_ident(body), meta))
                     }
                 });
             }
             BodyKind::Unknown => {
                 match_arms.push(quote! {
                     #status_const => {
+                        let meta = crate::response::ResponseMeta::from_response(&response);
                         let body_bytes = response.bytes().await?;
                         let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                        Err(crate::error::SdkError::api(#enum_ident::#variant_ident(body)))
+                        Err(crate::error::SdkError::api(#enum_ident::#variant_ident(body), meta))
                     }
                 });
             }
             BodyKind::Empty => {
                 match_arms.push(quote! {
                     #status_const => {
-                        Err(crate::error::SdkError::api(#enum_ident::#variant_ident))
+                        let meta = crate::response::ResponseMeta::from_response(&response);
+                        Err(crate::error::SdkError::api(#enum_ident::#variant_ident, meta))
                     }
                 });
             }
@@ -780,10 +995,21 @@ fn generate_error_handling(
     }
 
     let body_definition = Some(quote! {
+        #(#nested_schemas)*
+        #(#composite_bodies)*
+
         #[derive(Debug, PartialEq)]
         pub enum #enum_ident {
             #(#variant_defs)*
         }
+
+        impl crate::error::ErrorBody for #enum_ident {
+            fn status(&self) -> Option<reqwest::StatusCode> {
+                match self {
+                    #(#status_arms)*
+                }
+            }
+        }
     });
 
     Ok(ErrorGeneration {
@@ -835,6 +1061,48 @@ fn extract_schema_from_response(response: &openapiv3::Response) -> Option<Ident>
     }
 }
 
+/// Returns the inline `oneOf`/`anyOf` schema of an error response, with its members.
+fn inline_composite_error_schema<'a>(
+    response_ref: &'a openapiv3::ReferenceOr<openapiv3::Response>,
+    spec: &'a openapiv3::OpenAPI,
+) -> Option<(
+    &'a openapiv3::Schema,
+    &'a [openapiv3::ReferenceOr<openapiv3::Schema>],
+)> {
+    let response = resolve_response(response_ref, spec)?;
+    let media_type = crate::preferred_response_media_type(&response.content)?;
+    let openapiv3::ReferenceOr::Item(schema) = media_type.schema.as_ref()? else {
+        return None;
+    };
+    match &schema.schema_kind {
+        openapiv3::SchemaKind::OneOf { one_of: members }
+        | openapiv3::SchemaKind::AnyOf { any_of: members } => Some((schema, members)),
+        _ => None,
+    }
+}
+
+fn response_description<'a>(
+    response_ref: &'a openapiv3::ReferenceOr<openapiv3::Response>,
+    spec: &'a openapiv3::OpenAPI,
+) -> Option<&'a str> {
+    let description = resolve_response(response_ref, spec)?.description.trim();
+    (!description.is_empty()).then_some(description)
+}
+
+fn resolve_response<'a>(
+    response_ref: &'a openapiv3::ReferenceOr<openapiv3::Response>,
+    spec: &'a openapiv3::OpenAPI,
+) -> Option<&'a openapiv3::Response> {
+    match response_ref {
+        openapiv3::ReferenceOr::Item(response) => Some(response),
+        openapiv3::ReferenceOr::Reference { reference } => {
+            let response_name = reference.strip_prefix("#/components/responses/")?;
+            let response_ref = spec.components.as_ref()?.responses.get(response_name)?;
+            resolve_response(response_ref, spec)
+        }
+    }
+}
+
 fn response_has_content(
     response_ref: &openapiv3::ReferenceOr<openapiv3::Response>,
     spec: &openapiv3::OpenAPI,
@@ -865,14 +1133,15 @@ fn generate_no_success_response_handling(
     Ok(quote! {
         let status = response.status();
         if status.is_success() {
-            Ok(())
+            Ok(((), crate::response::ResponseMeta::from_response(&response)))
         } else {
             match status {
                 #(#error_arms)*
                 _ => {
+                    let meta = crate::response::ResponseMeta::from_response(&response);
                     let body_bytes = response.bytes().await?;
                     let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                    Err(crate::error::SdkError::unexpected(status, body))
+                    Err(crate::error::SdkError::unexpected(body, meta))
                 }
             }
         }
@@ -906,14 +1175,15 @@ fn generate_single_response_handling(
             let status = response.status();
             match status {
                 #status_const => {
-                    let data: #response_type = response.json().await?;
-                    Ok(data)
+                    let (data, meta): (#response_type, _) = crate::error::read_json(response).await?;
+                    Ok((data, meta))
                 }
                 #(#error_arms)*
                 _ => {
+                    let meta = crate::response::ResponseMeta::from_response(&response);
                     let body_bytes = response.bytes().await?;
                     let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                    Err(crate::error::SdkError::unexpected(status, body))
+                    Err(crate::error::SdkError::unexpected(body, meta))
                 }
             }
         })
@@ -921,12 +1191,13 @@ fn generate_single_response_handling(
         Ok(quote! {
             let status = response.status();
             match status {
-                #status_const => Ok(()),
+                #status_const => Ok(((), crate::response::ResponseMeta::from_response(&response))),
                 #(#error_arms)*
                 _ => {
+                    let meta = crate::response::ResponseMeta::from_response(&response);
                     let body_bytes = response.bytes().await?;
                     let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                    Err(crate::error::SdkError::unexpected(status, body))
+                    Err(crate::error::SdkError::unexpected(body, meta))
                 }
             }
         })
@@ -998,13 +1269,16 @@ fn generate_multi_response_handling(
         if has_content {
             match_arms.push(quote! {
                 #status_const => {
-                    let data: #inner_type = response.json().await?;
-                    Ok(#response_type::#variant(data))
+                    let (data, meta): (#inner_type, _) = crate::error::read_json(response).await?;
+                    Ok((#response_type::#variant(data), meta))
                 }
             });
         } else {
             match_arms.push(quote! {
-                #status_const => Ok(#response_type::#variant)
+                #status_const => Ok((
+                    #response_type::#variant,
+                    crate::response::ResponseMeta::from_response(&response),
+                ))
             });
         }
     }
@@ -1017,9 +1291,10 @@ fn generate_multi_response_handling(
             #(#match_arms)*
             #(#error_arms)*
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body = response.text().await?;
                 let body = crate::error::UnknownApiBody::from_text(body);
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     })
@@ -0,0 +1,793 @@
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use openapiv3::OpenAPI;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// Pagination metadata declared with the `x-pagination` extension of an operation.
+///
+/// ```json
+/// "x-pagination": { "style": "offset", "items": "items", "total": "total_count" }
+/// ```
+///
+/// All field names are optional and default to the names used across the SumUp API.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(tag = "style", rename_all = "lowercase")]
+pub enum Pagination {
+    /// Pages are addressed by an offset and a limit query parameter.
+    Offset {
+        #[serde(default = "default_items")]
+        items: String,
+        #[serde(default = "default_offset")]
+        offset: String,
+        #[serde(default = "default_limit")]
+        limit: String,
+        /// Response field with the total number of items, if any.
+        #[serde(default = "default_total")]
+        total: Option<String>,
+    },
+    /// The response links to the next page with a query string, e.g.
+    /// `{"rel": "next", "href": "limit=10&oldest_ref=..."}`.
+    Link {
+        #[serde(default = "default_items")]
+        items: String,
+        #[serde(default = "default_links")]
+        links: String,
+        #[serde(default = "default_rel")]
+        rel: String,
+        /// Query parameters locating a page, which the caller's values must not carry over
+        /// to the pages of links.
+        #[serde(default)]
+        cursors: Vec<String>,
+    },
+    /// The response carries an opaque string cursor that is sent back as a query parameter.
+    Cursor {
+        #[serde(default = "default_items")]
+        items: String,
+        #[serde(default = "default_cursor")]
+        cursor: String,
+        #[serde(default = "default_next_cursor")]
+        next_cursor: String,
+    },
+}
+
+fn default_items() -> String {
+    "items".to_string()
+}
+
+fn default_offset() -> String {
+    "offset".to_string()
+}
+
+fn default_limit() -> String {
+    "limit".to_string()
+}
+
+fn default_total() -> Option<String> {
+    Some("total_count".to_string())
+}
+
+fn default_links() -> String {
+    "links".to_string()
+}
+
+fn default_rel() -> String {
+    "next".to_string()
+}
+
+fn default_cursor() -> String {
+    "cursor".to_string()
+}
+
+fn default_next_cursor() -> String {
+    "next_cursor".to_string()
+}
+
+/// Returns the pagination metadata of the operation, if it declares any.
+pub fn operation_pagination(
+    operation: &openapiv3::Operation,
+) -> Result<Option<Pagination>, String> {
+    operation
+        .extensions
+        .get("x-pagination")
+        .map(|value| {
+            serde_json::from_value(value.clone())
+                .map_err(|e| format!("Invalid x-pagination extension: {}", e))
+        })
+        .transpose()
+}
+
+/// Signature details of the single-page method that the pagination methods wrap.
+pub(crate) struct PaginatedMethod<'a> {
+    pub operation_name: &'a str,
+    pub method_name: &'a str,
+    pub path_params: &'a [Ident],
+    pub has_body: bool,
+    pub error_type: &'a TokenStream,
+}
+
+/// Emits the `<method>_all` methods streaming the items of every page.
+///
+/// Link and cursor pagination use `String` cursors; offset pagination uses the `i64`
+/// offset as cursor.
+pub(crate) fn generate_pagination_methods(
+    spec: &OpenAPI,
+    operation: &openapiv3::Operation,
+    pagination: &Pagination,
+    method: &PaginatedMethod<'_>,
+) -> Result<TokenStream, String> {
+    let origin = format!("x-pagination of `{}`", method.operation_name);
+    if method.has_body {
+        return Err(format!(
+            "{origin}: paginated operations must not have a request body"
+        ));
+    }
+    let query_params = query_parameters(operation);
+    let response = response_object(spec, operation)
+        .ok_or_else(|| format!("{origin}: success response must be a JSON object"))?;
+
+    let items_name = match pagination {
+        Pagination::Offset { items, .. }
+        | Pagination::Link { items, .. }
+        | Pagination::Cursor { items, .. } => items,
+    };
+    let item_type = array_item_type(response.property(spec, items_name))
+        .ok_or_else(|| format!("{origin}: `{items_name}` must be an array of a named schema"))?;
+    let items_field = field_ident(items_name);
+    let items = if response.is_required(items_name) {
+        quote! { page.#items_field }
+    } else {
+        quote! { page.#items_field.unwrap_or_default() }
+    };
+
+    let method_ident = Ident::new(method.method_name, Span::call_site());
+    let all_ident = Ident::new(&format!("{}_all", method.method_name), Span::call_site());
+    let params_type = Ident::new(
+        &format!("{}Params", method.operation_name.to_upper_camel_case()),
+        Span::call_site(),
+    );
+    let error_type = method.error_type;
+    let path_params = method.path_params;
+    let path_param_decls: Vec<_> = path_params
+        .iter()
+        .map(|param| quote! { #param: impl Into<String>, })
+        .collect();
+    let path_param_conversions = path_params
+        .iter()
+        .map(|param| quote! { let #param: String = #param.into(); });
+    let path_param_clones = path_params
+        .iter()
+        .map(|param| quote! { let #param = #param.clone(); });
+    let fetch_page = quote! {
+        let page = client.#method_ident(#(#path_params,)* params).await?;
+    };
+
+    match pagination {
+        Pagination::Offset {
+            offset,
+            limit,
+            total,
+            ..
+        } => {
+            let offset_param = query_param(&query_params, offset, &origin)?;
+            let offset_field = field_ident(offset);
+            let (initial_offset, offset_value) = if offset_param.required {
+                (quote! { params.#offset_field }, quote! { offset })
+            } else {
+                (
+                    quote! { params.#offset_field.unwrap_or_default() },
+                    quote! { Some(offset) },
+                )
+            };
+            let limit_field = field_ident(limit);
+            let limit = match query_params.iter().find(|param| &param.name == limit) {
+                Some(param) if param.required => quote! { Some(params.#limit_field) },
+                Some(_) => quote! { params.#limit_field },
+                None => quote! { None },
+            };
+            let total = match total {
+                Some(total) if response.property(spec, total).is_some() => {
+                    let total_field = field_ident(total);
+                    if response.is_required(total) {
+                        quote! { Some(page.#total_field) }
+                    } else {
+                        quote! { page.#total_field }
+                    }
+                }
+                _ => quote! { None },
+            };
+            let doc = crate::schema::generate_doc_comment(&format!(
+                "Streams the items of [`Self::{}`] across all pages, starting at `params.{}`.",
+                method.method_name, offset_field
+            ));
+
+            Ok(quote! {
+                #doc
+                pub fn #all_ident(
+                    &self,
+                    #(#path_param_decls)*
+                    params: #params_type,
+                ) -> crate::pagination::Paginator<'a, #item_type, #error_type, i64> {
+                    let client = self.clone();
+                    #(#path_param_conversions)*
+                    let limit = #limit;
+                    crate::pagination::Paginator::offset(#initial_offset, limit, move |offset| {
+                        let client = client.clone();
+                        #(#path_param_clones)*
+                        let params = #params_type {
+                            #offset_field: #offset_value,
+                            ..params.clone()
+                        };
+                        async move {
+                            #fetch_page
+                            Ok((#items, #total))
+                        }
+                    })
+                }
+            })
+        }
+        Pagination::Link {
+            links,
+            rel,
+            cursors,
+            ..
+        } => {
+            let link = response
+                .property(spec, links)
+                .and_then(|links| array_item_object(spec, links))
+                .ok_or_else(|| format!("{origin}: `{links}` must be an array of objects"))?;
+            let links_field = field_ident(links);
+            let links = if response.is_required(links) {
+                quote! { page.#links_field }
+            } else {
+                quote! { page.#links_field.unwrap_or_default() }
+            };
+            let rel_matches = if link.is_required("rel") {
+                quote! { link.rel == #rel }
+            } else {
+                quote! { link.rel.as_deref() == Some(#rel) }
+            };
+            let href = if link.is_required("href") {
+                quote! { map(|link| link.href) }
+            } else {
+                quote! { and_then(|link| link.href) }
+            };
+            let (resets, assignments): (Vec<_>, Vec<_>) = query_params
+                .iter()
+                .map(|param| cursor_assignment(spec, param, &origin))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .unzip();
+            let cursor_fields = cursors
+                .iter()
+                .map(|cursor| {
+                    if query_param(&query_params, cursor, &origin)?.required {
+                        return Err(format!(
+                            "{origin}: cursor parameter `{cursor}` must be optional"
+                        ));
+                    }
+                    Ok(field_ident(cursor))
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+
+            let from_ident = Ident::new(
+                &format!("{}_all_from", method.method_name),
+                Span::call_site(),
+            );
+            let doc = crate::schema::generate_doc_comment(&format!(
+                "Streams the items of [`Self::{}`] across all pages, following the `{rel}` links.",
+                method.method_name
+            ));
+            let from_doc = crate::schema::generate_doc_comment(&format!(
+                "Streams the items of [`Self::{}`] starting at a saved cursor.\n\n\
+                 The cursor is the `href` of a `{rel}` link, as returned by\n\
+                 [`Paginator::cursor`](crate::pagination::Paginator::cursor). Its query\n\
+                 parameters replace those of `params`, whose other filters still apply.",
+                method.method_name
+            ));
+
+            Ok(quote! {
+                #doc
+                pub fn #all_ident(
+                    &self,
+                    #(#path_param_decls)*
+                    params: #params_type,
+                ) -> crate::pagination::Paginator<'a, #item_type, #error_type> {
+                    let client = self.clone();
+                    #(#path_param_conversions)*
+                    crate::pagination::Paginator::new(None, move |cursor: Option<String>| {
+                        let client = client.clone();
+                        #(#path_param_clones)*
+                        // Links may leave out filters, so they apply on top of the caller's
+                        // params, whose own position is dropped.
+                        let params = match &cursor {
+                            Some(cursor) => crate::pagination::params_from_cursor(
+                                cursor,
+                                #params_type {
+                                    #(#cursor_fields: None,)*
+                                    ..params.clone()
+                                },
+                                |params: &mut #params_type, key| match key {
+                                    #(#resets)*
+                                    _ => {}
+                                },
+                                |params: &mut #params_type, key, value| {
+                                    match key {
+                                        #(#assignments)*
+                                        _ => {}
+                                    }
+                                    Some(())
+                                },
+                            ),
+                            None => Ok(params.clone()),
+                        };
+                        async move {
+                            let params = params?;
+                            #fetch_page
+                            let next = #links.into_iter().find(|link| #rel_matches).#href;
+                            Ok(crate::pagination::Page { items: #items, next })
+                        }
+                    })
+                }
+
+                #from_doc
+                pub fn #from_ident(
+                    &self,
+                    #(#path_param_decls)*
+                    params: #params_type,
+                    cursor: impl Into<String>,
+                ) -> crate::pagination::Paginator<'a, #item_type, #error_type> {
+                    self.#all_ident(#(#path_params,)* params).with_cursor(cursor.into())
+                }
+            })
+        }
+        Pagination::Cursor {
+            cursor,
+            next_cursor,
+            ..
+        } => {
+            let cursor_param = query_param(&query_params, cursor, &origin)?;
+            if cursor_param.required {
+                return Err(format!(
+                    "{origin}: cursor parameter `{cursor}` must be optional"
+                ));
+            }
+            if response.property(spec, next_cursor).is_none() {
+                return Err(format!("{origin}: response has no `{next_cursor}` field"));
+            }
+            let cursor_field = field_ident(cursor);
+            let next_field = field_ident(next_cursor);
+            let next = if response.is_required(next_cursor) {
+                quote! { Some(page.#next_field) }
+            } else {
+                quote! { page.#next_field }
+            };
+            let doc = crate::schema::generate_doc_comment(&format!(
+                "Streams the items of [`Self::{}`] across all pages, starting at `params.{}`.",
+                method.method_name, cursor_field
+            ));
+
+            Ok(quote! {
+                #doc
+                pub fn #all_ident(
+                    &self,
+                    #(#path_param_decls)*
+                    params: #params_type,
+                ) -> crate::pagination::Paginator<'a, #item_type, #error_type> {
+                    let client = self.clone();
+                    #(#path_param_conversions)*
+                    let cursor = params.#cursor_field.clone();
+                    crate::pagination::Paginator::new(cursor, move |cursor: Option<String>| {
+                        let client = client.clone();
+                        #(#path_param_clones)*
+                        let params = #params_type {
+                            #cursor_field: cursor,
+                            ..params.clone()
+                        };
+                        async move {
+                            #fetch_page
+                            Ok(crate::pagination::Page { items: #items, next: #next })
+                        }
+                    })
+                }
+            })
+        }
+    }
+}
+
+fn field_ident(name: &str) -> Ident {
+    crate::schema::make_rust_field_ident(&name.to_snake_case())
+}
+
+fn query_parameters(operation: &openapiv3::Operation) -> Vec<&openapiv3::ParameterData> {
+    operation
+        .parameters
+        .iter()
+        .filter_map(|param| match param {
+            openapiv3::ReferenceOr::Item(openapiv3::Parameter::Query {
+                parameter_data, ..
+            }) => Some(parameter_data),
+            _ => None,
+        })
+        .collect()
+}
+
+fn query_param<'a>(
+    query_params: &[&'a openapiv3::ParameterData],
+    name: &str,
+    origin: &str,
+) -> Result<&'a openapiv3::ParameterData, String> {
+    query_params
+        .iter()
+        .find(|param| param.name == name)
+        .copied()
+        .ok_or_else(|| format!("{origin}: operation has no `{name}` query parameter"))
+}
+
+/// Generates the match arms clearing a list parameter before the pairs of a `next` link
+/// are applied, and assigning one such pair to the parameter.
+fn cursor_assignment(
+    spec: &OpenAPI,
+    param: &openapiv3::ParameterData,
+    origin: &str,
+) -> Result<(TokenStream, TokenStream), String> {
+    let unsupported = || {
+        format!(
+            "{origin}: query parameter `{}` cannot be read from a link",
+            param.name
+        )
+    };
+    let openapiv3::ParameterSchemaOrContent::Schema(schema_ref) = &param.format else {
+        return Err(unsupported());
+    };
+    let schema = crate::schema::dereference_schema(spec, schema_ref).map_err(|_| unsupported())?;
+    let name = &param.name;
+    let field = field_ident(name);
+    let parsed = quote! { crate::pagination::parse_query_value(value)? };
+
+    if let Some(items) = array_items(schema) {
+        if !resolve_boxed(spec, items).is_some_and(is_scalar_schema) || schema.schema_data.nullable
+        {
+            return Err(unsupported());
+        }
+        let (reset, list) = if param.required {
+            (quote! { params.#field.clear() }, quote! { params.#field })
+        } else {
+            (
+                quote! { params.#field = None },
+                quote! { params.#field.get_or_insert_with(Vec::new) },
+            )
+        };
+        return Ok((
+            quote! { #name => #reset, },
+            quote! { #name => #list.push(#parsed), },
+        ));
+    }
+    if !is_scalar_schema(schema) {
+        return Err(unsupported());
+    }
+    // Only inline schemas make the field `Nullable`, see the query parameter structs.
+    let nullable = matches!(
+        schema_ref,
+        openapiv3::ReferenceOr::Item(schema) if schema.schema_data.nullable
+    );
+    let value = match (param.required, nullable) {
+        (true, _) => parsed,
+        (false, false) => quote! { Some(#parsed) },
+        (false, true) => quote! {
+            Some(if value == "null" {
+                crate::Nullable::Null
+            } else {
+                crate::Nullable::Value(#parsed)
+            })
+        },
+    };
+    Ok((
+        TokenStream::new(),
+        quote! { #name => params.#field = #value, },
+    ))
+}
+
+/// Reports whether values of the schema can be parsed from a single query string value.
+fn is_scalar_schema(schema: &openapiv3::Schema) -> bool {
+    matches!(
+        schema.schema_kind,
+        openapiv3::SchemaKind::Type(
+            openapiv3::Type::String(_)
+                | openapiv3::Type::Number(_)
+                | openapiv3::Type::Integer(_)
+                | openapiv3::Type::Boolean(_)
+        )
+    )
+}
+
+/// Object schema together with its required properties.
+struct ObjectSchema<'a> {
+    object: &'a openapiv3::ObjectType,
+}
+
+impl<'a> ObjectSchema<'a> {
+    fn from_schema(schema: &'a openapiv3::Schema) -> Option<Self> {
+        match &schema.schema_kind {
+            openapiv3::SchemaKind::Type(openapiv3::Type::Object(object)) => Some(Self { object }),
+            _ => None,
+        }
+    }
+
+    fn property(&self, spec: &'a OpenAPI, name: &str) -> Option<&'a openapiv3::Schema> {
+        resolve_boxed(spec, self.object.properties.get(name)?)
+    }
+
+    fn is_required(&self, name: &str) -> bool {
+        self.object.required.iter().any(|required| required == name)
+    }
+}
+
+fn resolve_boxed<'a>(
+    spec: &'a OpenAPI,
+    schema_ref: &'a openapiv3::ReferenceOr<Box<openapiv3::Schema>>,
+) -> Option<&'a openapiv3::Schema> {
+    match schema_ref {
+        openapiv3::ReferenceOr::Item(schema) => Some(schema),
+        openapiv3::ReferenceOr::Reference { reference } => component_schema(spec, reference),
+    }
+}
+
+fn component_schema<'a>(spec: &'a OpenAPI, reference: &str) -> Option<&'a openapiv3::Schema> {
+    let name = reference.strip_prefix("#/components/schemas/")?;
+    match spec.components.as_ref()?.schemas.get(name)? {
+        openapiv3::ReferenceOr::Item(schema) => Some(schema),
+        openapiv3::ReferenceOr::Reference { reference } => component_schema(spec, reference),
+    }
+}
+
+/// Resolves the JSON object returned by the first successful response of the operation.
+fn response_object<'a>(
+    spec: &'a OpenAPI,
+    operation: &'a openapiv3::Operation,
+) -> Option<ObjectSchema<'a>> {
+    let response_ref = operation
+        .responses
+        .responses
+        .iter()
+        .find_map(|(status, response)| match status {
+            openapiv3::StatusCode::Code(code) if (200..300).contains(code) => Some(response),
+            _ => None,
+        })?;
+    let response = match response_ref {
+        openapiv3::ReferenceOr::Item(response) => response,
+        openapiv3::ReferenceOr::Reference { reference } => {
+            let name = reference.strip_prefix("#/components/responses/")?;
+            match spec.components.as_ref()?.responses.get(name)? {
+                openapiv3::ReferenceOr::Item(response) => response,
+                openapiv3::ReferenceOr::Reference { .. } => return None,
+            }
+        }
+    };
+    let schema_ref = crate::preferred_response_media_type(&response.content)?
+        .schema
+        .as_ref()?;
+    let schema = crate::schema::dereference_schema(spec, schema_ref).ok()?;
+    ObjectSchema::from_schema(schema)
+}
+
+fn array_items(
+    schema: &openapiv3::Schema,
+) -> Option<&openapiv3::ReferenceOr<Box<openapiv3::Schema>>> {
+    match &schema.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::Array(array)) => array.items.as_ref(),
+        _ => None,
+    }
+}
+
+/// Returns the type of the named schema the array items refer to.
+fn array_item_type(schema: Option<&openapiv3::Schema>) -> Option<Ident> {
+    match array_items(schema?)? {
+        openapiv3::ReferenceOr::Reference { reference } => {
+            let name = reference.strip_prefix("#/components/schemas/")?;
+            Some(Ident::new(name, Span::call_site()))
+        }
+        openapiv3::ReferenceOr::Item(_) => None,
+    }
+}
+
+/// Resolves the object schema of the array items.
+fn array_item_object<'a>(
+    spec: &'a OpenAPI,
+    schema: &'a openapiv3::Schema,
+) -> Option<ObjectSchema<'a>> {
+    ObjectSchema::from_schema(resolve_boxed(spec, array_items(schema)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse_spec(value: serde_json::Value) -> OpenAPI {
+        serde_json::from_value(value).expect("failed to parse OpenAPI fixture")
+    }
+
+    fn list_spec(pagination: serde_json::Value) -> OpenAPI {
+        parse_spec(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {
+                "/members": {
+                    "get": {
+                        "operationId": "ListMembers",
+                        "x-pagination": pagination,
+                        "parameters": [
+                            { "name": "offset", "in": "query", "schema": { "type": "integer" } },
+                            { "name": "limit", "in": "query", "schema": { "type": "integer" } }
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "type": "object",
+                                            "required": ["items"],
+                                            "properties": {
+                                                "items": {
+                                                    "type": "array",
+                                                    "items": { "$ref": "#/components/schemas/Member" }
+                                                },
+                                                "total_count": { "type": "integer" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Member": { "type": "object", "properties": { "id": { "type": "string" } } }
+                }
+            }
+        }))
+    }
+
+    fn link_spec(parameters: serde_json::Value) -> OpenAPI {
+        let spec = list_spec(json!({ "style": "link", "cursors": ["since"] }));
+        let mut value = serde_json::to_value(&spec).unwrap();
+        let get = &mut value["paths"]["/members"]["get"];
+        get["parameters"] = parameters;
+        get["responses"]["200"]["content"]["application/json"]["schema"]["properties"]["links"] = json!({
+            "type": "array",
+            "items": {
+                "type": "object",
+                "required": ["rel", "href"],
+                "properties": { "rel": { "type": "string" }, "href": { "type": "string" } }
+            }
+        });
+        parse_spec(value)
+    }
+
+    fn list_operation(spec: &OpenAPI) -> &openapiv3::Operation {
+        match &spec.paths.paths["/members"] {
+            openapiv3::ReferenceOr::Item(item) => item.get.as_ref().expect("GET operation"),
+            openapiv3::ReferenceOr::Reference { .. } => panic!("unexpected reference"),
+        }
+    }
+
+    fn generate(spec: &OpenAPI) -> Result<String, String> {
+        let operation = list_operation(spec);
+        let pagination = operation_pagination(operation)?.expect("x-pagination");
+        let error_type = quote! { ListErrorBody };
+        generate_pagination_methods(
+            spec,
+            operation,
+            &pagination,
+            &PaginatedMethod {
+                operation_name: "ListMembers",
+                method_name: "list",
+                path_params: &[],
+                has_body: false,
+                error_type: &error_type,
+            },
+        )
+        .map(|tokens| tokens.to_string())
+    }
+
+    #[test]
+    fn pagination_styles_default_field_names() {
+        let offset: Pagination = serde_json::from_value(json!({ "style": "offset" })).unwrap();
+        assert_eq!(
+            offset,
+            Pagination::Offset {
+                items: "items".to_string(),
+                offset: "offset".to_string(),
+                limit: "limit".to_string(),
+                total: Some("total_count".to_string()),
+            }
+        );
+
+        let link: Pagination =
+            serde_json::from_value(json!({ "style": "link", "items": "transactions" })).unwrap();
+        assert_eq!(
+            link,
+            Pagination::Link {
+                items: "transactions".to_string(),
+                links: "links".to_string(),
+                rel: "next".to_string(),
+                cursors: Vec::new(),
+            }
+        );
+
+        assert!(serde_json::from_value::<Pagination>(json!({ "style": "page" })).is_err());
+    }
+
+    #[test]
+    fn offset_pagination_generates_all_method() {
+        let spec = list_spec(json!({ "style": "offset" }));
+        let generated = generate(&spec).expect("generate pagination methods");
+
+        assert!(generated.contains("pub fn list_all"));
+        assert!(generated.contains("Paginator :: offset"));
+        assert!(generated.contains("Paginator < 'a , Member , ListErrorBody , i64 >"));
+        assert!(generated.contains("Ok ((page . items , page . total_count))"));
+    }
+
+    #[test]
+    fn link_pagination_applies_links_on_top_of_params() {
+        let spec = link_spec(json!([
+            { "name": "limit", "in": "query", "schema": { "type": "integer" } },
+            { "name": "order", "in": "query", "required": true, "schema": { "type": "string" } },
+            { "name": "since", "in": "query", "schema": { "type": "string", "nullable": true } },
+            {
+                "name": "users[]",
+                "in": "query",
+                "schema": { "type": "array", "items": { "type": "string" } }
+            }
+        ]));
+        let generated = generate(&spec).expect("generate pagination methods");
+
+        assert!(generated.contains("pub fn list_all_from"));
+        assert!(generated.contains("params_from_cursor"));
+        assert!(generated.contains("ListMembersParams { since : None , .. params . clone () }"));
+        assert!(generated.contains("\"users[]\" => params . users = None ,"));
+        assert!(generated.contains(
+            "\"limit\" => params . limit = Some (crate :: pagination :: parse_query_value (value) ?)"
+        ));
+        assert!(generated.contains(
+            "\"order\" => params . order = crate :: pagination :: parse_query_value (value) ?"
+        ));
+        assert!(generated.contains("crate :: Nullable :: Null"));
+        assert!(generated.contains(
+            "\"users[]\" => params . users . get_or_insert_with (Vec :: new) . push (crate :: pagination :: parse_query_value (value) ?)"
+        ));
+        assert!(generated.contains("let params = params ? ;"));
+    }
+
+    #[test]
+    fn link_pagination_rejects_parameters_links_cannot_hold() {
+        let spec = link_spec(json!([
+            { "name": "filter", "in": "query", "schema": { "type": "object" } }
+        ]));
+        let err = generate(&spec).expect_err("object parameter");
+        assert!(err.contains("`filter` cannot be read from a link"), "{err}");
+    }
+
+    #[test]
+    fn link_pagination_requires_optional_cursor_parameters() {
+        let spec = link_spec(json!([
+            { "name": "since", "in": "query", "required": true, "schema": { "type": "string" } }
+        ]));
+        let err = generate(&spec).expect_err("required cursor parameter");
+        assert!(
+            err.contains("cursor parameter `since` must be optional"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn missing_pagination_parameter_is_rejected() {
+        let spec = list_spec(json!({ "style": "offset", "offset": "start" }));
+        let err = generate(&spec).expect_err("missing offset parameter");
+        assert!(err.contains("x-pagination of `ListMembers`"), "{err}");
+    }
+}
@@ -170,6 +170,7 @@ fn collect_schema_constraints(schema: &openapiv3::Schema) -> Vec<String> {
                         ));
                     }
                 }
+                VariantOrUnknownOrEmpty::Unknown(_) if is_amount_number(number_type) => {}
                 VariantOrUnknownOrEmpty::Unknown(format) => {
                     constraints.push(format!("format: `{}`", format));
                 }
@@ -362,7 +363,7 @@ fn is_rust_mapped_integer_format(format: &openapiv3::IntegerFormat) -> bool {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum StringEncodedNumericKind {
+pub(crate) enum StringEncodedNumericKind {
     F32,
     F64,
     I32,
@@ -386,7 +387,7 @@ fn string_encoded_numeric_kind(
     }
 }
 
-fn string_schema_numeric_kind(
+pub(crate) fn string_schema_numeric_kind(
     schema_kind: &openapiv3::SchemaKind,
 ) -> Option<StringEncodedNumericKind> {
     let openapiv3::SchemaKind::Type(openapiv3::Type::String(string_type)) = schema_kind else {
@@ -395,6 +396,16 @@ fn string_schema_numeric_kind(
     string_encoded_numeric_kind(&string_type.format)
 }
 
+/// Returns whether the number is a monetary amount in major units, marked with the
+/// codegen-only `amount` format by [`crate::amount::apply_amount_annotations`], which
+/// maps to the exact `crate::money::Amount` type.
+pub fn is_amount_number(number_type: &openapiv3::NumberType) -> bool {
+    matches!(
+        &number_type.format,
+        openapiv3::VariantOrUnknownOrEmpty::Unknown(format) if format == "amount"
+    )
+}
+
 fn numeric_kind_rust_type(kind: StringEncodedNumericKind) -> TokenStream {
     match kind {
         StringEncodedNumericKind::F32 => quote! { f32 },
@@ -405,19 +416,30 @@ fn numeric_kind_rust_type(kind: StringEncodedNumericKind) -> TokenStream {
 }
 
 /// Generates struct definitions for the selected component schemas.
+///
+/// Only the schemas in `request_schema_names`, and the inline types nested in them,
+/// implement `Validate`.
 pub fn generate_structs_for_schemas(
     spec: &OpenAPI,
     schema_names: &HashSet<String>,
     error_schema_names: &HashSet<String>,
+    request_schema_names: &HashSet<String>,
 ) -> Result<TokenStream, String> {
     let mut symbols = crate::symbol::SymbolRegistry::new("standalone schemas");
-    generate_structs_for_schemas_with_registry(spec, schema_names, error_schema_names, &mut symbols)
+    generate_structs_for_schemas_with_registry(
+        spec,
+        schema_names,
+        error_schema_names,
+        request_schema_names,
+        &mut symbols,
+    )
 }
 
 pub(crate) fn generate_structs_for_schemas_with_registry(
     spec: &OpenAPI,
     schema_names: &HashSet<String>,
     error_schema_names: &HashSet<String>,
+    request_schema_names: &HashSet<String>,
     symbols: &mut crate::symbol::SymbolRegistry,
 ) -> Result<TokenStream, String> {
     let mut items = Vec::new();
@@ -448,16 +470,15 @@ pub(crate) fn generate_structs_for_schemas_with_registry(
 
         let struct_name = Ident::new(&name.to_upper_camel_case(), Span::call_site());
         let is_error_schema = error_schema_names.contains(name);
+        let validate = request_schema_names.contains(name);
 
         let schema = match schema_ref {
             openapiv3::ReferenceOr::Item(s) => s,
             openapiv3::ReferenceOr::Reference { .. } => continue,
         };
 
-        symbols.reserve(
-            struct_name.to_string(),
-            format!("component schema `{name}`"),
-        )?;
+        let origin = format!("component schema `{name}`");
+        symbols.reserve(struct_name.to_string(), origin.clone())?;
 
         match &schema.schema_kind {
             openapiv3::SchemaKind::Type(openapiv3::Type::Object(obj)) => {
@@ -476,6 +497,7 @@ pub(crate) fn generate_structs_for_schemas_with_registry(
                     spec,
                     name,
                     &obj.properties,
+                    validate,
                     &mut nested_schemas,
                     symbols,
                 )?;
@@ -503,16 +525,41 @@ pub(crate) fn generate_structs_for_schemas_with_registry(
                     quote! { #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)] }
                 };
 
+                let (non_exhaustive, builder) = crate::builder::request_struct_builder(
+                    spec,
+                    &struct_name,
+                    &obj.properties,
+                    &obj.required,
+                    obj.additional_properties.as_ref(),
+                    validate.then_some(origin.as_str()),
+                    symbols,
+                )?;
+
                 let struct_def = quote! {
                     #description
                     #deprecation
                     #derives
+                    #non_exhaustive
                     pub struct #struct_name {
                         #(#fields)*
                     }
+                    #builder
                 };
 
                 items.push(struct_def);
+                if validate {
+                    items.push(crate::validation::generate_struct_validation(
+                        spec,
+                        &struct_name,
+                        &obj.properties,
+                        &obj.required,
+                    )?);
+                }
+                items.push(crate::amount::generate_money_accessors(
+                    &struct_name,
+                    &obj.properties,
+                    &obj.required,
+                ));
 
                 // If this is an error schema, implement Error trait
                 if is_error_schema {
@@ -542,6 +589,7 @@ pub(crate) fn generate_structs_for_schemas_with_registry(
                         spec,
                         name,
                         &combined_properties,
+                        validate,
                         &mut nested_schemas,
                         symbols,
                     )?;
@@ -570,16 +618,41 @@ pub(crate) fn generate_structs_for_schemas_with_registry(
                         quote! { #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)] }
                     };
 
+                    let (non_exhaustive, builder) = crate::builder::request_struct_builder(
+                        spec,
+                        &struct_name,
+                        &combined_properties,
+                        &combined_required,
+                        combined_additional_properties.as_ref(),
+                        validate.then_some(origin.as_str()),
+                        symbols,
+                    )?;
+
                     let struct_def = quote! {
                         #description
                         #deprecation
                         #derives
+                        #non_exhaustive
                         pub struct #struct_name {
                             #(#fields)*
                         }
+                        #builder
                     };
 
                     items.push(struct_def);
+                    if validate {
+                        items.push(crate::validation::generate_struct_validation(
+                            spec,
+                            &struct_name,
+                            &combined_properties,
+                            &combined_required,
+                        )?);
+                    }
+                    items.push(crate::amount::generate_money_accessors(
+                        &struct_name,
+                        &combined_properties,
+                        &combined_required,
+                    ));
 
                     if is_error_schema {
                         let error_impl = generate_error_impl(
@@ -645,6 +718,12 @@ pub(crate) fn generate_structs_for_schemas_with_registry(
                                 #other_variant_ident(String),
                             }
                         });
+                        if validate {
+                            items.push(crate::validation::generate_string_enum_validation(
+                                &struct_name,
+                                &other_variant_ident,
+                            ));
+                        }
                     } else {
                         items.push(quote! {
                             pub type #struct_name = String;
@@ -656,6 +735,24 @@ pub(crate) fn generate_structs_for_schemas_with_registry(
                     });
                 }
             }
+            openapiv3::SchemaKind::OneOf { one_of: members }
+            | openapiv3::SchemaKind::AnyOf { any_of: members } => {
+                let description = schema
+                    .schema_data
+                    .description
+                    .as_ref()
+                    .map(|d| generate_schema_doc_comment(Some(d), schema));
+                items.push(generate_composite_enum(
+                    spec,
+                    &struct_name.to_string(),
+                    schema,
+                    members,
+                    description,
+                    validate,
+                    &mut nested_schemas,
+                    symbols,
+                )?);
+            }
             _ => {
                 let dummy_ref = openapiv3::ReferenceOr::Item(Box::new(schema.clone()));
                 let base_type = infer_rust_type(&schema.schema_kind, true, false, None, &dummy_ref);
@@ -684,13 +781,14 @@ pub(crate) fn schema_symbol_names(
         spec,
         schema_names,
         error_schema_names,
+        &HashSet::new(),
         &mut symbols,
     )?;
     Ok(symbols.names().map(str::to_string).collect())
 }
 
 /// Produces a `#[deprecated]` attribute when the schema marks itself as deprecated.
-fn generate_deprecation_attribute(schema_data: &openapiv3::SchemaData) -> TokenStream {
+pub(crate) fn generate_deprecation_attribute(schema_data: &openapiv3::SchemaData) -> TokenStream {
     if !schema_data.deprecated {
         return quote! {};
     }
@@ -711,6 +809,9 @@ fn generate_deprecation_attribute(schema_data: &openapiv3::SchemaData) -> TokenS
 
 struct NestedStructGenerator<'spec, 'schemas> {
     spec: &'spec OpenAPI,
+    /// Whether the nested types are sent in requests, like their parent: they implement
+    /// `Validate`, and nested structs get a builder.
+    validate: bool,
     nested_schemas: &'schemas mut Vec<TokenStream>,
     symbols: &'schemas mut crate::symbol::SymbolRegistry,
 }
@@ -719,11 +820,13 @@ impl<'spec, 'schemas> NestedStructGenerator<'spec, 'schemas> {
     /// Creates a helper that appends generated nested structs to the shared buffer.
     fn new(
         spec: &'spec OpenAPI,
+        validate: bool,
         nested_schemas: &'schemas mut Vec<TokenStream>,
         symbols: &'schemas mut crate::symbol::SymbolRegistry,
     ) -> Self {
         Self {
             spec,
+            validate,
             nested_schemas,
             symbols,
         }
@@ -761,15 +864,15 @@ impl<'spec, 'schemas> NestedStructGenerator<'spec, 'schemas> {
         let nested_struct_name = nested_inline_type_name(parent_name, field_name, fallback_suffix);
         let struct_ident = Ident::new(&nested_struct_name, Span::call_site());
 
-        self.symbols.reserve(
-            nested_struct_name.clone(),
-            format!("inline schema for field `{parent_name}.{field_name}`"),
-        )?;
+        let origin = format!("inline schema for field `{parent_name}.{field_name}`");
+        self.symbols
+            .reserve(nested_struct_name.clone(), origin.clone())?;
 
         collect_nested_schemas_with_registry(
             self.spec,
             &nested_struct_name,
             properties,
+            self.validate,
             &mut *self.nested_schemas,
             &mut *self.symbols,
         )?;
@@ -794,12 +897,37 @@ impl<'spec, 'schemas> NestedStructGenerator<'spec, 'schemas> {
             quote! { #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)] }
         };
 
+        let validation = if self.validate {
+            crate::validation::generate_struct_validation(
+                self.spec,
+                &struct_ident,
+                properties,
+                required,
+            )?
+        } else {
+            quote! {}
+        };
+        let (non_exhaustive, builder) = crate::builder::request_struct_builder(
+            self.spec,
+            &struct_ident,
+            properties,
+            required,
+            additional_properties,
+            self.validate.then_some(origin.as_str()),
+            &mut *self.symbols,
+        )?;
+        let money = crate::amount::generate_money_accessors(&struct_ident, properties, required);
+
         self.nested_schemas.push(quote! {
             #description
             #derives
+            #non_exhaustive
             pub struct #struct_ident {
                 #(#fields)*
             }
+            #builder
+            #validation
+            #money
         });
 
         Ok(())
@@ -820,8 +948,45 @@ impl<'spec, 'schemas> NestedStructGenerator<'spec, 'schemas> {
             format!("inline enum for field `{parent_name}.{field_name}`"),
         )?;
         let description = schema.schema_data.description.as_deref();
-        let enum_tokens =
-            generate_inline_string_enum(&type_ident, enumeration, description, schema)?;
+        let enum_tokens = generate_inline_string_enum(
+            &type_ident,
+            enumeration,
+            description,
+            schema,
+            self.validate,
+        )?;
+        self.nested_schemas.push(enum_tokens);
+        Ok(())
+    }
+
+    /// Generates a nested enum for an inline `oneOf`/`anyOf` schema.
+    fn generate_for_composite(
+        &mut self,
+        parent_name: &str,
+        field_name: &str,
+        schema: &openapiv3::Schema,
+        members: &[openapiv3::ReferenceOr<openapiv3::Schema>],
+    ) -> Result<(), String> {
+        let type_name = nested_inline_type_name(parent_name, field_name, "");
+        self.symbols.reserve(
+            type_name.clone(),
+            format!("inline composite for field `{parent_name}.{field_name}`"),
+        )?;
+        let description = schema
+            .schema_data
+            .description
+            .as_ref()
+            .map(|d| generate_schema_doc_comment(Some(d), schema));
+        let enum_tokens = generate_composite_enum(
+            self.spec,
+            &type_name,
+            schema,
+            members,
+            description,
+            self.validate,
+            &mut *self.nested_schemas,
+            &mut *self.symbols,
+        )?;
         self.nested_schemas.push(enum_tokens);
         Ok(())
     }
@@ -870,10 +1035,13 @@ fn collect_all_of_references_from_schema(
 }
 
 /// Collects nested inline schemas for a parent type so callers can emit them later.
+///
+/// The nested types implement `Validate` when `validate` is set.
 pub fn collect_nested_schemas(
     spec: &OpenAPI,
     parent_name: &str,
     properties: &Properties,
+    validate: bool,
     nested_schemas: &mut Vec<TokenStream>,
 ) -> Result<(), String> {
     let mut symbols = crate::symbol::SymbolRegistry::new(parent_name);
@@ -881,6 +1049,7 @@ pub fn collect_nested_schemas(
         spec,
         parent_name,
         properties,
+        validate,
         nested_schemas,
         &mut symbols,
     )
@@ -890,10 +1059,11 @@ pub(crate) fn collect_nested_schemas_with_registry(
     spec: &OpenAPI,
     parent_name: &str,
     properties: &Properties,
+    validate: bool,
     nested_schemas: &mut Vec<TokenStream>,
     symbols: &mut crate::symbol::SymbolRegistry,
 ) -> Result<(), String> {
-    let mut generator = NestedStructGenerator::new(spec, nested_schemas, symbols);
+    let mut generator = NestedStructGenerator::new(spec, validate, nested_schemas, symbols);
 
     for (field_name, prop_ref) in properties {
         if let openapiv3::ReferenceOr::Item(schema) = prop_ref {
@@ -941,6 +1111,10 @@ pub(crate) fn collect_nested_schemas_with_registry(
                         )?;
                     }
                 }
+                openapiv3::SchemaKind::OneOf { one_of: members }
+                | openapiv3::SchemaKind::AnyOf { any_of: members } => {
+                    generator.generate_for_composite(parent_name, field_name, schema, members)?;
+                }
                 openapiv3::SchemaKind::Type(openapiv3::Type::Array(arr)) => {
                     if let Some(openapiv3::ReferenceOr::Item(item_schema)) = &arr.items {
                         match &item_schema.schema_kind {
@@ -1290,7 +1464,7 @@ fn generate_additional_properties_field(
     })
 }
 
-fn infer_additional_properties_value_type(
+pub(crate) fn infer_additional_properties_value_type(
     additional_properties: &openapiv3::AdditionalProperties,
 ) -> Option<TokenStream> {
     match additional_properties {
@@ -1314,7 +1488,7 @@ fn infer_additional_properties_value_type(
     }
 }
 
-fn pick_additional_properties_field_ident(properties: &Properties) -> Ident {
+pub(crate) fn pick_additional_properties_field_ident(properties: &Properties) -> Ident {
     let mut candidates = vec![
         "additional_properties".to_string(),
         "extra_properties".to_string(),
@@ -1377,6 +1551,11 @@ pub fn infer_rust_type(
                 }
             }
         }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Number(number_type))
+            if is_amount_number(number_type) =>
+        {
+            quote! { crate::money::Amount }
+        }
         openapiv3::SchemaKind::Type(openapiv3::Type::Number(number_type)) => {
             match &number_type.format {
                 openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::NumberFormat::Float) => {
@@ -1504,7 +1683,9 @@ pub fn infer_rust_type(
                 quote! { serde_json::Value }
             }
         }
-        openapiv3::SchemaKind::AllOf { .. } => {
+        openapiv3::SchemaKind::AllOf { .. }
+        | openapiv3::SchemaKind::OneOf { .. }
+        | openapiv3::SchemaKind::AnyOf { .. } => {
             let nested_type = parent_field.map(|(parent_name, field_name)| {
                 nested_inline_type_name(parent_name, field_name, "")
             });
@@ -1579,6 +1760,7 @@ fn generate_inline_string_enum(
     enumeration: &[Option<String>],
     description: Option<&str>,
     schema: &openapiv3::Schema,
+    validate: bool,
 ) -> Result<TokenStream, String> {
     let mut variant_names: HashSet<String> = HashSet::new();
     let mut variants_tokens = Vec::new();
@@ -1612,6 +1794,9 @@ fn generate_inline_string_enum(
         Ident::new("Other", Span::call_site())
     };
     let description = generate_schema_doc_comment(description, schema);
+    let validation = validate.then(|| {
+        crate::validation::generate_string_enum_validation(type_ident, &other_variant_ident)
+    });
 
     Ok(quote! {
         #description
@@ -1621,9 +1806,284 @@ fn generate_inline_string_enum(
             #[serde(untagged)]
             #other_variant_ident(String),
         }
+        #validation
+    })
+}
+
+/// Generates an enum with one variant per member of a `oneOf`/`anyOf` schema.
+///
+/// Without a discriminator the enum is untagged and serde tries the variants in order,
+/// starting with the members requiring the most properties: a member whose properties
+/// are all optional matches any object and would shadow the members after it. With one, the discriminator property selects the variant, using the `mapping` when
+/// given and the schema name otherwise. Either way, payloads matching no variant are
+/// kept as `serde_json::Value` in a trailing `Other` variant. The enum and its inline
+/// members implement `Validate` when `validate` is set.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn generate_composite_enum(
+    spec: &OpenAPI,
+    type_name: &str,
+    schema: &openapiv3::Schema,
+    members: &[openapiv3::ReferenceOr<openapiv3::Schema>],
+    description: Option<TokenStream>,
+    validate: bool,
+    nested_schemas: &mut Vec<TokenStream>,
+    symbols: &mut crate::symbol::SymbolRegistry,
+) -> Result<TokenStream, String> {
+    let type_ident = Ident::new(type_name, Span::call_site());
+    let mut variant_names: HashSet<String> = HashSet::new();
+    let mut variants = Vec::new();
+
+    for (index, member) in members.iter().enumerate() {
+        let (variant_name, variant_type, schema_name) = match member {
+            openapiv3::ReferenceOr::Reference { reference } => {
+                let schema_name =
+                    reference
+                        .strip_prefix("#/components/schemas/")
+                        .ok_or_else(|| {
+                            format!(
+                            "Unsupported reference in composite schema `{type_name}`: {reference}"
+                        )
+                        })?;
+                let name = schema_name.to_upper_camel_case();
+                let ident = Ident::new(&name, Span::call_site());
+                (name, quote! { #ident }, Some(schema_name))
+            }
+            openapiv3::ReferenceOr::Item(member_schema) => {
+                let name = composite_member_variant_name(member_schema);
+                let name = if variant_names.contains(&name) {
+                    format!("{name}{}", index + 1)
+                } else {
+                    name
+                };
+                let variant_type = composite_member_type(
+                    spec,
+                    (type_name, &name),
+                    member_schema,
+                    validate,
+                    nested_schemas,
+                    symbols,
+                )?;
+                (name, variant_type, None)
+            }
+        };
+        if !variant_names.insert(variant_name.clone()) {
+            return Err(format!(
+                "Duplicate variant name generated for composite schema `{type_name}`: {variant_name}"
+            ));
+        }
+        variants.push((
+            Ident::new(&variant_name, Span::call_site()),
+            variant_type,
+            schema_name,
+            required_property_count(spec, member)?,
+        ));
+    }
+
+    // Inline members cannot be selected by a discriminator, so such schemas stay untagged.
+    let discriminator = schema.schema_data.discriminator.as_ref().filter(|_| {
+        variants
+            .iter()
+            .all(|(_, _, schema_name, _)| schema_name.is_some())
+    });
+    if discriminator.is_none() {
+        // Stable, so that members requiring as many properties keep the order of the spec.
+        variants.sort_by_key(|(_, _, _, required)| std::cmp::Reverse(*required));
+    }
+
+    let other_variant_ident = if variant_names.contains("Other") {
+        Ident::new("OtherValue", Span::call_site())
+    } else {
+        Ident::new("Other", Span::call_site())
+    };
+    let deprecation = generate_deprecation_attribute(&schema.schema_data);
+    let variant_defs = variants
+        .iter()
+        .map(|(ident, variant_type, _, _)| quote! { #ident(#variant_type), });
+    let variant_idents: Vec<&Ident> = variants
+        .iter()
+        .map(|(ident, _, _, _)| ident)
+        .chain([&other_variant_ident])
+        .collect();
+    let validation = validate
+        .then(|| crate::validation::generate_composite_validation(&type_ident, &variant_idents));
+
+    let Some(discriminator) = discriminator else {
+        return Ok(quote! {
+            #description
+            #deprecation
+            #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+            #[serde(untagged)]
+            pub enum #type_ident {
+                #(#variant_defs)*
+                #other_variant_ident(serde_json::Value),
+            }
+            #validation
+        });
+    };
+
+    let property_name = &discriminator.property_name;
+    let match_arms = variants.iter().map(|(ident, _, schema_name, _)| {
+        let schema_name = schema_name.unwrap_or_default();
+        let reference = format!("#/components/schemas/{schema_name}");
+        let mut tags: Vec<&str> = discriminator
+            .mapping
+            .iter()
+            .filter(|(_, target)| *target == schema_name || **target == reference)
+            .map(|(tag, _)| tag.as_str())
+            .collect();
+        if tags.is_empty() {
+            tags.push(schema_name);
+        }
+        quote! {
+            Some(#(#tags)|*) => serde_json::from_value(value)
+                .map(Self::#ident)
+                .map_err(serde::de::Error::custom),
+        }
+    });
+
+    Ok(quote! {
+        #description
+        #deprecation
+        #[derive(Debug, Clone, PartialEq, serde::Serialize)]
+        #[serde(untagged)]
+        pub enum #type_ident {
+            #(#variant_defs)*
+            #other_variant_ident(serde_json::Value),
+        }
+
+        impl<'de> serde::Deserialize<'de> for #type_ident {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+                match value.get(#property_name).and_then(serde_json::Value::as_str) {
+                    #(#match_arms)*
+                    _ => Ok(Self::#other_variant_ident(value)),
+                }
+            }
+        }
+
+        #validation
+    })
+}
+
+/// Returns how many properties an object member of a `oneOf`/`anyOf` schema requires, or
+/// zero for other members.
+fn required_property_count(
+    spec: &OpenAPI,
+    member: &openapiv3::ReferenceOr<openapiv3::Schema>,
+) -> Result<usize, String> {
+    Ok(match &dereference_schema(spec, member)?.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::Object(object)) => object.required.len(),
+        openapiv3::SchemaKind::AllOf { all_of } => {
+            flatten_all_of_object(spec, all_of)?.map_or(0, |(_, required, _)| required.len())
+        }
+        _ => 0,
     })
 }
 
+/// Names the variant of an inline `oneOf`/`anyOf` member after its title or its type.
+fn composite_member_variant_name(schema: &openapiv3::Schema) -> String {
+    if let Some(title) = &schema.schema_data.title {
+        return title.to_upper_camel_case();
+    }
+    match &schema.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::String(_)) => "String".to_string(),
+        openapiv3::SchemaKind::Type(openapiv3::Type::Number(_)) => "Number".to_string(),
+        openapiv3::SchemaKind::Type(openapiv3::Type::Integer(_)) => "Integer".to_string(),
+        openapiv3::SchemaKind::Type(openapiv3::Type::Boolean(_)) => "Boolean".to_string(),
+        openapiv3::SchemaKind::Type(openapiv3::Type::Array(arr)) => match &arr.items {
+            Some(openapiv3::ReferenceOr::Reference { reference }) => format!(
+                "{}List",
+                reference
+                    .split('/')
+                    .next_back()
+                    .unwrap_or("Unknown")
+                    .to_upper_camel_case()
+            ),
+            _ => "Array".to_string(),
+        },
+        openapiv3::SchemaKind::Type(openapiv3::Type::Object(_))
+        | openapiv3::SchemaKind::AllOf { .. } => "Object".to_string(),
+        _ => "Value".to_string(),
+    }
+}
+
+/// Returns the type of an inline `oneOf`/`anyOf` member, generating a nested type named
+/// after the enum and the variant for inline objects, enums and composites.
+fn composite_member_type(
+    spec: &OpenAPI,
+    parent_field: (&str, &str),
+    schema: &openapiv3::Schema,
+    validate: bool,
+    nested_schemas: &mut Vec<TokenStream>,
+    symbols: &mut crate::symbol::SymbolRegistry,
+) -> Result<TokenStream, String> {
+    let (parent_name, variant_name) = parent_field;
+    let nested_type_name = nested_inline_type_name(parent_name, variant_name, "");
+    let nested_type_ident = Ident::new(&nested_type_name, Span::call_site());
+    let mut generator = NestedStructGenerator::new(spec, validate, nested_schemas, symbols);
+
+    match &schema.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::Object(obj))
+            if !should_emit_free_form_object_alias(
+                &obj.properties,
+                obj.additional_properties.as_ref(),
+            ) =>
+        {
+            generator.generate_for_object(parent_name, variant_name, schema, obj)?;
+            Ok(quote! { #nested_type_ident })
+        }
+        openapiv3::SchemaKind::AllOf { all_of } => match flatten_all_of_object(spec, all_of)? {
+            Some((properties, required, additional_properties))
+                if !should_emit_free_form_object_alias(
+                    &properties,
+                    additional_properties.as_ref(),
+                ) =>
+            {
+                generator.generate_for_object_like(
+                    parent_field,
+                    schema,
+                    &properties,
+                    &required,
+                    additional_properties.as_ref(),
+                    "",
+                )?;
+                Ok(quote! { #nested_type_ident })
+            }
+            _ => Ok(quote! { serde_json::Value }),
+        },
+        openapiv3::SchemaKind::Type(openapiv3::Type::String(string_type))
+            if !string_type.enumeration.is_empty() =>
+        {
+            generator.generate_for_string_enum(
+                parent_name,
+                variant_name,
+                schema,
+                &string_type.enumeration,
+                "",
+            )?;
+            Ok(quote! { #nested_type_ident })
+        }
+        openapiv3::SchemaKind::OneOf { one_of: members }
+        | openapiv3::SchemaKind::AnyOf { any_of: members } => {
+            generator.generate_for_composite(parent_name, variant_name, schema, members)?;
+            Ok(quote! { #nested_type_ident })
+        }
+        _ => {
+            let dummy_ref = openapiv3::ReferenceOr::Item(Box::new(schema.clone()));
+            Ok(infer_rust_type(
+                &schema.schema_kind,
+                true,
+                false,
+                None,
+                &dummy_ref,
+            ))
+        }
+    }
+}
+
 /// Generates a `std::error::Error` implementation for schemas marked as error types.
 fn generate_error_impl(
     struct_name: &Ident,
@@ -1790,4 +2250,176 @@ mod tests {
             Some(&additional_properties)
         ));
     }
+
+    fn composite_spec(schemas: serde_json::Value) -> OpenAPI {
+        serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {},
+            "components": { "schemas": schemas }
+        }))
+        .expect("failed to parse OpenAPI fixture")
+    }
+
+    fn generate_all(spec: &OpenAPI) -> String {
+        let names = spec
+            .components
+            .as_ref()
+            .map(|components| components.schemas.keys().cloned().collect())
+            .unwrap_or_default();
+        generate_structs_for_schemas(spec, &names, &HashSet::new(), &names)
+            .expect("schemas should generate")
+            .to_string()
+    }
+
+    #[test]
+    fn one_of_schema_becomes_untagged_enum_with_value_fallback() {
+        let spec = composite_spec(serde_json::json!({
+            "Card": { "type": "object", "properties": { "last_4_digits": { "type": "string" } } },
+            "Instrument": {
+                "oneOf": [
+                    { "$ref": "#/components/schemas/Card" },
+                    { "type": "array", "items": { "$ref": "#/components/schemas/Card" } },
+                    { "type": "object", "properties": { "iban": { "type": "string" } } },
+                    { "type": "string" }
+                ]
+            }
+        }));
+        let code = generate_all(&spec);
+
+        assert!(code.contains("# [serde (untagged)] pub enum Instrument"));
+        assert!(code.contains("Card (Card) ,"));
+        assert!(code.contains("CardList (Vec < Card >) ,"));
+        assert!(code.contains("Object (InstrumentObject) ,"));
+        assert!(code.contains("pub struct InstrumentObject"));
+        assert!(code.contains("String (String) ,"));
+        assert!(code.contains("Other (serde_json :: Value) ,"));
+        assert!(code.contains("impl crate :: validation :: Validate for Instrument"));
+        assert!(code.contains(
+            "Self :: CardList (value) => { crate :: validation :: Validate :: validate_at (value , path , violations) }"
+        ));
+    }
+
+    #[test]
+    fn untagged_members_requiring_more_properties_come_first() {
+        let spec = composite_spec(serde_json::json!({
+            "ErrorExtended": {
+                "type": "object",
+                "properties": { "message": { "type": "string" }, "param": { "type": "string" } }
+            },
+            "BadRequest": {
+                "oneOf": [
+                    { "$ref": "#/components/schemas/ErrorExtended" },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "instance": { "type": "string" },
+                            "error_message": { "type": "string" }
+                        },
+                        "required": ["instance", "error_message"]
+                    },
+                    { "type": "string" }
+                ]
+            }
+        }));
+        let code = generate_all(&spec);
+
+        assert!(code.contains(
+            "pub enum BadRequest { Object (BadRequestObject) , ErrorExtended (ErrorExtended) , String (String) , Other (serde_json :: Value) , }"
+        ));
+    }
+
+    #[test]
+    fn discriminator_selects_variant_by_mapping() {
+        let spec = composite_spec(serde_json::json!({
+            "Card": { "type": "object", "properties": { "type": { "type": "string" } } },
+            "Wallet": { "type": "object", "properties": { "type": { "type": "string" } } },
+            "PaymentMethod": {
+                "anyOf": [
+                    { "$ref": "#/components/schemas/Card" },
+                    { "$ref": "#/components/schemas/Wallet" }
+                ],
+                "discriminator": {
+                    "propertyName": "type",
+                    "mapping": { "card": "#/components/schemas/Card" }
+                }
+            }
+        }));
+        let code = generate_all(&spec);
+
+        assert!(code.contains("impl < 'de > serde :: Deserialize < 'de > for PaymentMethod"));
+        assert!(code.contains("value . get (\"type\")"));
+        assert!(code
+            .contains("Some (\"card\") => serde_json :: from_value (value) . map (Self :: Card)"));
+        assert!(code.contains(
+            "Some (\"Wallet\") => serde_json :: from_value (value) . map (Self :: Wallet)"
+        ));
+        assert!(code.contains("_ => Ok (Self :: Other (value))"));
+    }
+
+    #[test]
+    fn inline_one_of_property_gets_nested_enum() {
+        let spec = composite_spec(serde_json::json!({
+            "Refund": {
+                "type": "object",
+                "properties": {
+                    "amount": { "oneOf": [{ "type": "number" }, { "type": "string" }] }
+                }
+            }
+        }));
+        let code = generate_all(&spec);
+
+        assert!(code.contains("pub amount : Option < RefundAmount >"));
+        assert!(code.contains("pub enum RefundAmount { Number (f64) , String (String) , Other (serde_json :: Value) , }"));
+    }
+
+    #[test]
+    fn request_structs_and_their_nested_structs_get_builders() {
+        let spec = composite_spec(serde_json::json!({
+            "CreateOrder": {
+                "type": "object",
+                "required": ["affiliate"],
+                "properties": {
+                    "affiliate": {
+                        "type": "object",
+                        "required": ["app_id"],
+                        "properties": {
+                            "app_id": { "type": "string" },
+                            "tags": { "type": "object", "properties": { "source": { "type": "string" } } }
+                        }
+                    }
+                }
+            },
+            "Order": {
+                "type": "object",
+                "properties": {
+                    "affiliate": { "type": "object", "properties": { "app_id": { "type": "string" } } }
+                }
+            }
+        }));
+        let names = spec
+            .components
+            .as_ref()
+            .map(|components| components.schemas.keys().cloned().collect())
+            .unwrap_or_default();
+        let request_schema_names = HashSet::from(["CreateOrder".to_string()]);
+        let code =
+            generate_structs_for_schemas(&spec, &names, &HashSet::new(), &request_schema_names)
+                .expect("schemas should generate")
+                .to_string();
+
+        for name in [
+            "CreateOrder",
+            "CreateOrderAffiliate",
+            "CreateOrderAffiliateTags",
+        ] {
+            assert!(code.contains(&format!("# [non_exhaustive] pub struct {name} {{")));
+            assert!(code.contains(&format!("pub struct {name}Builder")));
+        }
+        assert!(code.contains(
+            "pub fn builder (app_id : impl Into < String >) -> CreateOrderAffiliateBuilder"
+        ));
+        assert!(!code.contains("# [non_exhaustive] pub struct Order"));
+        assert!(!code.contains("pub struct OrderAffiliateBuilder"));
+    }
 }
@@ -0,0 +1,240 @@
+use heck::ToUpperCamelCase;
+use openapiv3::OpenAPI;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use std::path::Path;
+
+/// OAuth 2.0 scope declared in the spec, with its description when available.
+pub struct ScopeDefinition {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Collects all OAuth 2.0 scopes declared by the security schemes and used by operations.
+///
+/// Scopes declared in the flows come first, in declaration order; scopes that are only
+/// referenced by operations follow in the order they first appear.
+pub fn collect_scopes(spec: &OpenAPI) -> Vec<ScopeDefinition> {
+    let mut scopes: Vec<ScopeDefinition> = Vec::new();
+    let mut push = |name: &str, description: Option<&str>| {
+        if let Some(existing) = scopes.iter_mut().find(|scope| scope.name == name) {
+            if existing.description.is_none() {
+                existing.description = description.map(str::to_string);
+            }
+        } else {
+            scopes.push(ScopeDefinition {
+                name: name.to_string(),
+                description: description.map(str::to_string),
+            });
+        }
+    };
+
+    for flows in oauth2_schemes(spec).map(|(_, flows)| flows) {
+        let flow_scopes = [
+            flows.authorization_code.as_ref().map(|flow| &flow.scopes),
+            flows.client_credentials.as_ref().map(|flow| &flow.scopes),
+            flows.implicit.as_ref().map(|flow| &flow.scopes),
+            flows.password.as_ref().map(|flow| &flow.scopes),
+        ];
+        for (name, description) in flow_scopes.into_iter().flatten().flatten() {
+            push(name, Some(description.as_str()));
+        }
+    }
+
+    for path_item in spec.paths.paths.values() {
+        let openapiv3::ReferenceOr::Item(path_item) = path_item else {
+            continue;
+        };
+        for (_, operation) in path_item.iter() {
+            for name in operation_scopes(spec, operation) {
+                push(&name, None);
+            }
+        }
+    }
+
+    scopes
+}
+
+/// Returns the OAuth 2.0 scopes accepted by the operation; any of them grants access.
+pub fn operation_scopes(spec: &OpenAPI, operation: &openapiv3::Operation) -> Vec<String> {
+    let Some(requirements) = operation.security.as_ref().or(spec.security.as_ref()) else {
+        return Vec::new();
+    };
+    let oauth2_names: Vec<&str> = oauth2_schemes(spec).map(|(name, _)| name).collect();
+
+    let mut scopes: Vec<String> = Vec::new();
+    for requirement in requirements {
+        for (scheme, requirement_scopes) in requirement {
+            if !oauth2_names.contains(&scheme.as_str()) {
+                continue;
+            }
+            for scope in requirement_scopes {
+                if !scopes.contains(scope) {
+                    scopes.push(scope.clone());
+                }
+            }
+        }
+    }
+    scopes
+}
+
+/// Returns the identifier of the `Scope` variant for the given scope name.
+pub fn scope_variant_ident(name: &str) -> Ident {
+    Ident::new(&name.to_upper_camel_case(), Span::call_site())
+}
+
+fn oauth2_schemes(spec: &OpenAPI) -> impl Iterator<Item = (&str, &openapiv3::OAuth2Flows)> {
+    spec.components
+        .iter()
+        .flat_map(|components| components.security_schemes.iter())
+        .filter_map(|(name, scheme)| match scheme {
+            openapiv3::ReferenceOr::Item(openapiv3::SecurityScheme::OAuth2 { flows, .. }) => {
+                Some((name.as_str(), flows))
+            }
+            _ => None,
+        })
+}
+
+/// Writes `scope.rs` containing the `Scope` enum for all OAuth 2.0 scopes.
+pub fn generate_scope_file(out_path: &Path, spec: &OpenAPI) -> Result<(), String> {
+    let mut scope_path = out_path.to_path_buf();
+    scope_path.push("scope.rs");
+
+    let contents = crate::format_generated_code(generate_scope_enum(&collect_scopes(spec)));
+    std::fs::write(&scope_path, &contents)
+        .map_err(|e| format!("Failed to write scope.rs: {}", e))?;
+
+    Ok(())
+}
+
+fn generate_scope_enum(scopes: &[ScopeDefinition]) -> TokenStream {
+    let mut variants = Vec::new();
+    let mut as_str_arms = Vec::new();
+    for scope in scopes {
+        let variant = scope_variant_ident(&scope.name);
+        let name = &scope.name;
+        // Scopes only listed by operations have no description in the spec.
+        let doc_comment = crate::schema::generate_doc_comment(
+            &scope
+                .description
+                .clone()
+                .unwrap_or_else(|| format!("The `{name}` scope.")),
+        );
+        variants.push(quote! {
+            #doc_comment
+            #[serde(rename = #name)]
+            #variant,
+        });
+        as_str_arms.push(quote! { Self::#variant => #name, });
+    }
+
+    let module_doc_comment =
+        crate::schema::generate_module_doc_comment("OAuth 2.0 scopes of the SumUp API.");
+
+    quote! {
+        #module_doc_comment
+
+        /// OAuth 2.0 scopes supported by the SumUp API.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        pub enum Scope {
+            #(#variants)*
+            /// A scope unknown to this version of the SDK.
+            #[serde(untagged)]
+            Other(String),
+        }
+
+        impl Scope {
+            /// Returns the scope as sent to the authorization server.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    #(#as_str_arms)*
+                    Self::Other(scope) => scope,
+                }
+            }
+
+            /// Parses a space-separated scope list as returned by the token endpoint.
+            pub fn parse_list(scopes: &str) -> Vec<Self> {
+                scopes.split_whitespace().map(Self::from).collect()
+            }
+        }
+
+        impl From<&str> for Scope {
+            fn from(value: &str) -> Self {
+                serde_json::from_value(serde_json::Value::String(value.to_string()))
+                    .unwrap_or_else(|_| Self::Other(value.to_string()))
+            }
+        }
+
+        impl From<Scope> for String {
+            fn from(value: Scope) -> Self {
+                value.as_str().to_string()
+            }
+        }
+
+        impl std::fmt::Display for Scope {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn collects_flow_and_operation_scopes() {
+        let spec: OpenAPI = serde_json::from_value(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "components": {
+                "securitySchemes": {
+                    "apiKey": { "type": "http", "scheme": "Bearer" },
+                    "oauth2": {
+                        "type": "oauth2",
+                        "flows": {
+                            "clientCredentials": {
+                                "tokenUrl": "https://api.sumup.com/token",
+                                "scopes": { "payments": "Make payments." }
+                            }
+                        }
+                    }
+                }
+            },
+            "paths": {
+                "/readers": {
+                    "post": {
+                        "operationId": "CreateReader",
+                        "security": [
+                            { "apiKey": [] },
+                            { "oauth2": ["payments", "readers.write"] }
+                        ],
+                        "responses": { "200": { "description": "ok" } }
+                    }
+                }
+            }
+        }))
+        .expect("failed to parse OpenAPI fixture");
+
+        let scopes = collect_scopes(&spec);
+        let names: Vec<_> = scopes.iter().map(|scope| scope.name.as_str()).collect();
+        assert_eq!(names, ["payments", "readers.write"]);
+        assert_eq!(scopes[0].description.as_deref(), Some("Make payments."));
+        assert_eq!(scopes[1].description, None);
+
+        let (_, _, operation) = spec.operations().next().unwrap();
+        assert_eq!(
+            operation_scopes(&spec, operation),
+            ["payments", "readers.write"]
+        );
+        assert_eq!(scope_variant_ident("user.app-settings"), "UserAppSettings");
+
+        let code = crate::format_generated_code(generate_scope_enum(&scopes));
+        assert!(code.contains("    /// Make payments.\n    #[serde(rename = \"payments\")]"));
+        assert!(code.contains(
+            "    /// The `readers.write` scope.\n    #[serde(rename = \"readers.write\")]"
+        ));
+    }
+}
@@ -5,6 +5,9 @@ use std::collections::{HashMap, HashSet};
 pub struct TagSchemas {
     pub all_schemas: HashSet<String>,
     pub error_schemas: HashSet<String>,
+    /// Schemas reachable from request bodies and query or path parameters, which
+    /// implement `Validate`.
+    pub request_schemas: HashSet<String>,
 }
 
 /// Groups schemas by tag while tracking shared schema usage.
@@ -12,6 +15,7 @@ pub struct SchemasByTag {
     pub tag_schemas: HashMap<String, TagSchemas>,
     pub common_schemas: HashSet<String>,
     pub common_error_schemas: HashSet<String>,
+    pub common_request_schemas: HashSet<String>,
 }
 
 /// Collects schemas referenced by each tag and identifies shared/common schemas.
@@ -39,6 +43,7 @@ pub fn collect_schemas_by_tag(spec: &OpenAPI) -> Result<SchemasByTag, String> {
                     .or_insert_with(|| TagSchemas {
                         all_schemas: HashSet::new(),
                         error_schemas: HashSet::new(),
+                        request_schemas: HashSet::new(),
                     });
 
                 // Collect schemas from request body
@@ -52,6 +57,10 @@ pub fn collect_schemas_by_tag(spec: &OpenAPI) -> Result<SchemasByTag, String> {
                                 schema,
                                 &mut tag_data.all_schemas,
                             );
+                            collect_schema_references_from_schema(
+                                schema,
+                                &mut tag_data.request_schemas,
+                            );
                         }
                     }
                 }
@@ -109,6 +118,55 @@ pub fn collect_schemas_by_tag(spec: &OpenAPI) -> Result<SchemasByTag, String> {
                             }
                         }
                     }
+                    if let openapiv3::Parameter::Query { parameter_data, .. }
+                    | openapiv3::Parameter::Path { parameter_data, .. } = param
+                    {
+                        if let openapiv3::ParameterSchemaOrContent::Schema(schema_ref) =
+                            &parameter_data.format
+                        {
+                            collect_schema_references_unboxed(
+                                schema_ref,
+                                &mut tag_data.request_schemas,
+                            );
+                        }
+                    }
+                }
+
+                // Collect payload schemas of callbacks, which have no operation request
+                // struct and so keep their root schema
+                for callback in operation.callbacks.values() {
+                    for callback_item in callback.values() {
+                        for (_method, callback_operation) in
+                            crate::operations_for_path_item(callback_item)
+                        {
+                            if let Some(request_body_ref) = &callback_operation.request_body {
+                                let request_body =
+                                    crate::body::resolve_request_body(spec, request_body_ref)?;
+                                for media_type in request_body.content.values() {
+                                    if let Some(schema_ref) = &media_type.schema {
+                                        collect_schema_references_unboxed(
+                                            schema_ref,
+                                            &mut tag_data.all_schemas,
+                                        );
+                                    }
+                                }
+                            }
+                            for response_ref in callback_operation.responses.responses.values() {
+                                let openapiv3::ReferenceOr::Item(response) = response_ref else {
+                                    continue;
+                                };
+                                if let Some(schema_ref) =
+                                    crate::preferred_response_media_type(&response.content)
+                                        .and_then(|media_type| media_type.schema.as_ref())
+                                {
+                                    collect_schema_references_unboxed(
+                                        schema_ref,
+                                        &mut tag_data.all_schemas,
+                                    );
+                                }
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -122,37 +180,14 @@ pub fn collect_schemas_by_tag(spec: &OpenAPI) -> Result<SchemasByTag, String> {
                 tag_schemas,
                 common_schemas: HashSet::new(),
                 common_error_schemas: HashSet::new(),
+                common_request_schemas: HashSet::new(),
             })
         }
     };
 
     for tag_data in tag_schemas.values_mut() {
-        let mut to_process: Vec<String> = tag_data.all_schemas.iter().cloned().collect();
-        let mut processed = HashSet::new();
-
-        while let Some(schema_name) = to_process.pop() {
-            if processed.contains(&schema_name) {
-                continue;
-            }
-            processed.insert(schema_name.clone());
-
-            if let Some(schema_ref) = all_schemas.get(&schema_name) {
-                let mut referenced = HashSet::new();
-                match schema_ref {
-                    openapiv3::ReferenceOr::Item(schema) => {
-                        collect_schema_references_from_schema(schema, &mut referenced);
-                    }
-                    openapiv3::ReferenceOr::Reference { .. } => {}
-                }
-
-                for ref_schema in referenced {
-                    if !processed.contains(&ref_schema) {
-                        tag_data.all_schemas.insert(ref_schema.clone());
-                        to_process.push(ref_schema);
-                    }
-                }
-            }
-        }
+        collect_transitive_references(all_schemas, &mut tag_data.all_schemas);
+        collect_transitive_references(all_schemas, &mut tag_data.request_schemas);
     }
 
     // Identify schemas used by multiple tags (common schemas)
@@ -168,6 +203,13 @@ pub fn collect_schemas_by_tag(spec: &OpenAPI) -> Result<SchemasByTag, String> {
         }
     }
 
+    // A common schema validates its values when any tag sends it in a request
+    let common_request_schemas = tag_schemas
+        .values()
+        .flat_map(|tag_data| tag_data.request_schemas.intersection(&common_schemas))
+        .cloned()
+        .collect();
+
     // Remove common schemas from individual tags
     for tag_data in tag_schemas.values_mut() {
         tag_data.all_schemas = tag_data
@@ -180,15 +222,54 @@ pub fn collect_schemas_by_tag(spec: &OpenAPI) -> Result<SchemasByTag, String> {
             .difference(&common_schemas)
             .cloned()
             .collect();
+        tag_data.request_schemas = tag_data
+            .request_schemas
+            .difference(&common_schemas)
+            .cloned()
+            .collect();
     }
 
     Ok(SchemasByTag {
         tag_schemas,
         common_schemas,
         common_error_schemas,
+        common_request_schemas,
     })
 }
 
+/// Expands `schemas` with every schema they reference, directly or transitively.
+fn collect_transitive_references(
+    all_schemas: &indexmap::IndexMap<String, openapiv3::ReferenceOr<openapiv3::Schema>>,
+    schemas: &mut HashSet<String>,
+) {
+    let mut to_process: Vec<String> = schemas.iter().cloned().collect();
+    let mut processed = HashSet::new();
+
+    while let Some(schema_name) = to_process.pop() {
+        if processed.contains(&schema_name) {
+            continue;
+        }
+        processed.insert(schema_name.clone());
+
+        if let Some(schema_ref) = all_schemas.get(&schema_name) {
+            let mut referenced = HashSet::new();
+            match schema_ref {
+                openapiv3::ReferenceOr::Item(schema) => {
+                    collect_schema_references_from_schema(schema, &mut referenced);
+                }
+                openapiv3::ReferenceOr::Reference { .. } => {}
+            }
+
+            for ref_schema in referenced {
+                if !processed.contains(&ref_schema) {
+                    schemas.insert(ref_schema.clone());
+                    to_process.push(ref_schema);
+                }
+            }
+        }
+    }
+}
+
 /// Finds schemas that appear under more than one tag.
 fn identify_common_schemas(tag_schemas: &HashMap<String, TagSchemas>) -> HashSet<String> {
     let mut schema_tag_count: HashMap<String, usize> = HashMap::new();
@@ -455,4 +536,129 @@ mod tests {
         assert!(!demo.all_schemas.contains("LegacyRequest"));
         assert!(demo.all_schemas.contains("RequestValue"));
     }
+
+    #[test]
+    fn callback_payload_schemas_are_collected_for_the_operation_tag() {
+        let spec = parse_spec(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {
+                "/demo": {
+                    "post": {
+                        "operationId": "createDemo",
+                        "tags": ["Demo"],
+                        "responses": { "204": { "description": "ok" } },
+                        "callbacks": {
+                            "DemoChanged": {
+                                "{$request.body#/return_url}": {
+                                    "post": {
+                                        "requestBody": {
+                                            "required": true,
+                                            "content": {
+                                                "application/json": {
+                                                    "schema": { "$ref": "#/components/schemas/DemoChanged" }
+                                                }
+                                            }
+                                        },
+                                        "responses": { "200": { "description": "ok" } }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "DemoChanged": {
+                        "type": "object",
+                        "properties": {
+                            "status": { "$ref": "#/components/schemas/DemoStatus" }
+                        }
+                    },
+                    "DemoStatus": { "type": "string" }
+                }
+            }
+        }));
+
+        let grouped = collect_schemas_by_tag(&spec).expect("schema grouping should succeed");
+        let demo = grouped
+            .tag_schemas
+            .get("Demo")
+            .expect("demo operations should be grouped");
+
+        assert!(demo.all_schemas.contains("DemoChanged"));
+        assert!(demo.all_schemas.contains("DemoStatus"));
+    }
+
+    #[test]
+    fn request_schemas_are_reachable_from_request_bodies_and_parameters() {
+        let spec = parse_spec(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {
+                "/demo": {
+                    "post": {
+                        "operationId": "createDemo",
+                        "tags": ["Demo"],
+                        "parameters": [
+                            { "name": "kind", "in": "query", "schema": { "$ref": "#/components/schemas/DemoKind" } }
+                        ],
+                        "requestBody": {
+                            "required": true,
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "object",
+                                        "properties": {
+                                            "address": { "$ref": "#/components/schemas/Address" }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "#/components/schemas/Demo" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Address": {
+                        "type": "object",
+                        "properties": {
+                            "country": { "$ref": "#/components/schemas/Country" }
+                        }
+                    },
+                    "Country": { "type": "string", "enum": ["DE", "FR"] },
+                    "DemoKind": { "type": "string", "enum": ["a", "b"] },
+                    "Demo": {
+                        "type": "object",
+                        "properties": {
+                            "address": { "$ref": "#/components/schemas/Address" }
+                        }
+                    }
+                }
+            }
+        }));
+
+        let grouped = collect_schemas_by_tag(&spec).expect("schema grouping should succeed");
+        let demo = grouped
+            .tag_schemas
+            .get("Demo")
+            .expect("demo operations should be grouped");
+
+        let mut request_schemas: Vec<_> = demo.request_schemas.iter().cloned().collect();
+        request_schemas.sort();
+        assert_eq!(request_schemas, ["Address", "Country", "DemoKind"]);
+        assert!(demo.all_schemas.contains("Demo"));
+    }
 }
@@ -0,0 +1,570 @@
+use heck::ToSnakeCase;
+use openapiv3::OpenAPI;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+
+type Properties = indexmap::IndexMap<String, openapiv3::ReferenceOr<Box<openapiv3::Schema>>>;
+
+/// How a struct field holds its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Binding {
+    /// `T`
+    Required,
+    /// `Option<T>`
+    Optional,
+    /// `Option<Nullable<T>>`
+    Nullable,
+}
+
+/// Generates `Validate` for a struct generated by [`crate::schema::generate_struct_fields`].
+///
+/// Constraints of primitive fields are checked in place, including those of referenced
+/// primitive components which are plain type aliases; other fields delegate to the
+/// `Validate` implementation of their type.
+pub(crate) fn generate_struct_validation(
+    spec: &OpenAPI,
+    struct_ident: &Ident,
+    properties: &Properties,
+    required: &[String],
+) -> Result<TokenStream, String> {
+    let statements = properties
+        .iter()
+        .map(|(name, prop_ref)| {
+            let is_required = required.contains(name);
+            let (schema, binding) = match prop_ref {
+                openapiv3::ReferenceOr::Reference { reference } => (
+                    resolve_reference(spec, reference),
+                    if is_required {
+                        Binding::Required
+                    } else {
+                        Binding::Optional
+                    },
+                ),
+                openapiv3::ReferenceOr::Item(prop) => (
+                    Some(prop.as_ref()),
+                    match (is_required, prop.schema_data.nullable) {
+                        (true, _) => Binding::Required,
+                        (false, true) => Binding::Nullable,
+                        (false, false) => Binding::Optional,
+                    },
+                ),
+            };
+            let ident = crate::schema::make_rust_field_ident(name);
+            field_validation(spec, &ident, name, schema, binding, false)
+                .map_err(|err| format!("{err} in field `{name}` of `{struct_ident}`"))
+        })
+        .filter_map(Result::transpose)
+        .collect::<Result<_, _>>()?;
+
+    Ok(impl_validate(struct_ident, statements))
+}
+
+/// Generates `Validate` for a query parameters struct, additionally reporting required
+/// string parameters left empty since the struct implements `Default`.
+pub(crate) fn generate_params_validation(
+    spec: &OpenAPI,
+    struct_ident: &Ident,
+    params: &[&openapiv3::ParameterData],
+) -> Result<TokenStream, String> {
+    let statements = params
+        .iter()
+        .map(|param_data| {
+            let openapiv3::ParameterSchemaOrContent::Schema(schema_ref) = &param_data.format else {
+                return Ok(None);
+            };
+            let schema = match schema_ref {
+                openapiv3::ReferenceOr::Reference { reference } => {
+                    resolve_reference(spec, reference)
+                }
+                openapiv3::ReferenceOr::Item(schema) => Some(schema),
+            };
+            let nullable = matches!(
+                schema_ref,
+                openapiv3::ReferenceOr::Item(schema) if schema.schema_data.nullable
+            );
+            let binding = match (param_data.required, nullable) {
+                (true, _) => Binding::Required,
+                (false, true) => Binding::Nullable,
+                (false, false) => Binding::Optional,
+            };
+            let check_required = param_data.required && schema.is_some_and(is_text_schema);
+            let ident = crate::schema::make_rust_field_ident(&param_data.name.to_snake_case());
+            field_validation(
+                spec,
+                &ident,
+                &param_data.name,
+                schema,
+                binding,
+                check_required,
+            )
+            .map_err(|err| {
+                format!(
+                    "{err} in parameter `{}` of `{struct_ident}`",
+                    param_data.name
+                )
+            })
+        })
+        .filter_map(Result::transpose)
+        .collect::<Result<_, _>>()?;
+
+    Ok(impl_validate(struct_ident, statements))
+}
+
+/// Generates `Validate` for a string enum, reporting values outside the enumeration.
+pub(crate) fn generate_string_enum_validation(
+    type_ident: &Ident,
+    other_variant_ident: &Ident,
+) -> TokenStream {
+    quote! {
+        impl crate::validation::Validate for #type_ident {
+            fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+                if let Self::#other_variant_ident(value) = self {
+                    crate::validation::violation(
+                        path,
+                        crate::validation::ViolationKind::Enum { value: value.clone() },
+                        violations,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Generates `Validate` for a `oneOf`/`anyOf` enum, validating the value of the variant.
+pub(crate) fn generate_composite_validation(
+    type_ident: &Ident,
+    variant_idents: &[&Ident],
+) -> TokenStream {
+    quote! {
+        impl crate::validation::Validate for #type_ident {
+            fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+                match self {
+                    #(Self::#variant_idents(value) => {
+                        crate::validation::Validate::validate_at(value, path, violations)
+                    })*
+                }
+            }
+        }
+    }
+}
+
+fn impl_validate(type_ident: &Ident, statements: Vec<TokenStream>) -> TokenStream {
+    if statements.is_empty() {
+        return quote! {
+            impl crate::validation::Validate for #type_ident {}
+        };
+    }
+
+    quote! {
+        impl crate::validation::Validate for #type_ident {
+            fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+                #(#statements)*
+            }
+        }
+    }
+}
+
+/// Validates a single field, or returns `None` when nothing can be violated.
+///
+/// An unresolved `schema` means the field type is only known by name, so it is validated
+/// through its `Validate` implementation.
+fn field_validation(
+    spec: &OpenAPI,
+    ident: &Ident,
+    name: &str,
+    schema: Option<&openapiv3::Schema>,
+    binding: Binding,
+    check_required: bool,
+) -> Result<Option<TokenStream>, String> {
+    let mut checks = Vec::new();
+    if check_required {
+        checks.push(quote! {
+            crate::validation::check_required(value, &path, violations);
+        });
+    }
+    let is_leaf = match schema {
+        Some(schema) => {
+            checks.extend(value_checks(spec, schema)?);
+            is_leaf_schema(spec, schema)
+        }
+        None => false,
+    };
+    if !is_leaf {
+        checks.push(quote! {
+            crate::validation::Validate::validate_at(value, &path, violations);
+        });
+    }
+    if checks.is_empty() {
+        return Ok(None);
+    }
+
+    let body = quote! {
+        let path = crate::validation::field_path(path, #name);
+        #(#checks)*
+    };
+    Ok(Some(match binding {
+        Binding::Required => quote! {
+            {
+                let value = &self.#ident;
+                #body
+            }
+        },
+        Binding::Optional => quote! {
+            if let Some(value) = &self.#ident {
+                #body
+            }
+        },
+        Binding::Nullable => quote! {
+            if let Some(crate::Nullable::Value(value)) = &self.#ident {
+                #body
+            }
+        },
+    }))
+}
+
+/// Checks the constraints of a primitive or array schema against `value`, located at `path`.
+fn value_checks(spec: &OpenAPI, schema: &openapiv3::Schema) -> Result<Vec<TokenStream>, String> {
+    let mut checks = Vec::new();
+    match &schema.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::String(string_type))
+            if is_text_schema(schema) =>
+        {
+            if string_type.min_length.is_some() || string_type.max_length.is_some() {
+                let min = optional_usize(string_type.min_length);
+                let max = optional_usize(string_type.max_length);
+                checks.push(quote! {
+                    crate::validation::check_length(value, #min, #max, &path, violations);
+                });
+            }
+            if let Some(pattern) = &string_type.pattern {
+                checks.push(pattern_check(pattern)?);
+            }
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Number(number_type)) => {
+            checks.extend(range_check(
+                number_type
+                    .minimum
+                    .map(|min| (min, number_type.exclusive_minimum)),
+                number_type
+                    .maximum
+                    .map(|max| (max, number_type.exclusive_maximum)),
+            ));
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Integer(integer_type)) => {
+            checks.extend(range_check(
+                integer_type
+                    .minimum
+                    .map(|min| (min as f64, integer_type.exclusive_minimum)),
+                integer_type
+                    .maximum
+                    .map(|max| (max as f64, integer_type.exclusive_maximum)),
+            ));
+        }
+        openapiv3::SchemaKind::Type(openapiv3::Type::Array(array_type)) => {
+            if array_type.min_items.is_some() || array_type.max_items.is_some() {
+                let min = optional_usize(array_type.min_items);
+                let max = optional_usize(array_type.max_items);
+                checks.push(quote! {
+                    crate::validation::check_items(value.len(), #min, #max, &path, violations);
+                });
+            }
+            let item_checks = match array_type
+                .items
+                .as_ref()
+                .and_then(|items| resolve_boxed(spec, items))
+            {
+                Some(item_schema) => value_checks(spec, item_schema)?,
+                None => Vec::new(),
+            };
+            if !item_checks.is_empty() {
+                checks.push(quote! {
+                    for (index, value) in value.iter().enumerate() {
+                        let path = crate::validation::item_path(&path, index);
+                        #(#item_checks)*
+                    }
+                });
+            }
+        }
+        _ => {}
+    }
+    Ok(checks)
+}
+
+/// Checks `value` against `pattern`, compiled once into a static of the generated code.
+///
+/// Patterns are compiled here as well, so that the specification cannot contain a
+/// pattern the generated code fails to compile.
+fn pattern_check(pattern: &str) -> Result<TokenStream, String> {
+    regex_lite::Regex::new(pattern)
+        .map_err(|err| format!("Unsupported pattern `{pattern}`: {err}"))?;
+    Ok(quote! {
+        static PATTERN: std::sync::OnceLock<regex_lite::Regex> = std::sync::OnceLock::new();
+        let regex = PATTERN.get_or_init(|| {
+            regex_lite::Regex::new(#pattern).expect("patterns are compiled by the code generator")
+        });
+        if !regex.is_match(crate::validation::Text::text(value)) {
+            crate::validation::violation(
+                &path,
+                crate::validation::ViolationKind::Pattern { pattern: #pattern },
+                violations,
+            );
+        }
+    })
+}
+
+fn range_check(min: Option<(f64, bool)>, max: Option<(f64, bool)>) -> Option<TokenStream> {
+    if min.is_none() && max.is_none() {
+        return None;
+    }
+    let bound = |bound: Option<(f64, bool)>| match bound {
+        Some((value, exclusive)) => {
+            let value = Literal::f64_unsuffixed(value);
+            quote! { Some((#value, #exclusive)) }
+        }
+        None => quote! { None },
+    };
+    let min = bound(min);
+    let max = bound(max);
+    Some(quote! {
+        crate::validation::check_range(value, #min, #max, &path, violations);
+    })
+}
+
+fn optional_usize(value: Option<usize>) -> TokenStream {
+    match value {
+        Some(value) => {
+            let value = Literal::usize_unsuffixed(value);
+            quote! { Some(#value) }
+        }
+        None => quote! { None },
+    }
+}
+
+/// Reports whether the schema maps to a `String` or `Secret`, whose length and pattern
+/// can be checked.
+fn is_text_schema(schema: &openapiv3::Schema) -> bool {
+    let openapiv3::SchemaKind::Type(openapiv3::Type::String(string_type)) = &schema.schema_kind
+    else {
+        return false;
+    };
+    string_type.enumeration.is_empty()
+        && crate::schema::string_schema_numeric_kind(&schema.schema_kind).is_none()
+        && !matches!(
+            string_type.format,
+            openapiv3::VariantOrUnknownOrEmpty::Item(
+                openapiv3::StringFormat::DateTime
+                    | openapiv3::StringFormat::Date
+                    | openapiv3::StringFormat::Byte
+                    | openapiv3::StringFormat::Binary
+            )
+        )
+}
+
+/// Reports whether the schema maps to a type whose `Validate` implementation is empty, so
+/// that fields of it need no call.
+fn is_leaf_schema(spec: &OpenAPI, schema: &openapiv3::Schema) -> bool {
+    match &schema.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::String(string_type)) => {
+            string_type.enumeration.is_empty()
+        }
+        openapiv3::SchemaKind::Type(
+            openapiv3::Type::Number(_) | openapiv3::Type::Integer(_) | openapiv3::Type::Boolean(_),
+        ) => true,
+        openapiv3::SchemaKind::Type(openapiv3::Type::Array(array_type)) => {
+            match &array_type.items {
+                Some(items) => {
+                    resolve_boxed(spec, items).is_some_and(|item| is_leaf_schema(spec, item))
+                }
+                None => true,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn resolve_boxed<'a>(
+    spec: &'a OpenAPI,
+    schema_ref: &'a openapiv3::ReferenceOr<Box<openapiv3::Schema>>,
+) -> Option<&'a openapiv3::Schema> {
+    match schema_ref {
+        openapiv3::ReferenceOr::Reference { reference } => resolve_reference(spec, reference),
+        openapiv3::ReferenceOr::Item(schema) => Some(schema),
+    }
+}
+
+fn resolve_reference<'a>(spec: &'a OpenAPI, reference: &str) -> Option<&'a openapiv3::Schema> {
+    let schema_name = reference.strip_prefix("#/components/schemas/")?;
+    let target = spec.components.as_ref()?.schemas.get(schema_name)?;
+    crate::schema::dereference_schema(spec, target).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use serde_json::json;
+
+    fn parse_spec(schemas: serde_json::Value) -> OpenAPI {
+        serde_json::from_value(json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test", "version": "1.0.0" },
+            "paths": {},
+            "components": { "schemas": schemas }
+        }))
+        .expect("failed to parse OpenAPI fixture")
+    }
+
+    fn struct_validation(
+        spec: &OpenAPI,
+        properties: serde_json::Value,
+        required: &[&str],
+    ) -> Result<String, String> {
+        let properties: Properties =
+            serde_json::from_value(properties).expect("failed to parse properties fixture");
+        let required: Vec<String> = required.iter().map(|name| name.to_string()).collect();
+        let ident = Ident::new("Demo", Span::call_site());
+        generate_struct_validation(spec, &ident, &properties, &required)
+            .map(crate::format_generated_code)
+    }
+
+    #[test]
+    fn length_constraints_are_checked() {
+        let spec = parse_spec(json!({}));
+        let code = struct_validation(
+            &spec,
+            json!({
+                "name": { "type": "string", "minLength": 1, "maxLength": 32 },
+                "note": { "type": "string", "maxLength": 90 }
+            }),
+            &["name"],
+        )
+        .unwrap();
+
+        assert!(code.contains(
+            "{\n            let value = &self.name;\n            let path = crate::validation::field_path(path, \"name\");\n            crate::validation::check_length(value, Some(1), Some(32), &path, violations);\n        }"
+        ));
+        assert!(code.contains(
+            "if let Some(value) = &self.note {\n            let path = crate::validation::field_path(path, \"note\");\n            crate::validation::check_length(value, None, Some(90), &path, violations);"
+        ));
+    }
+
+    #[test]
+    fn range_constraints_are_checked() {
+        let spec = parse_spec(json!({}));
+        let code = struct_validation(
+            &spec,
+            json!({
+                "limit": { "type": "integer", "minimum": 1, "maximum": 100 },
+                "rate": { "type": "number", "minimum": 0, "exclusiveMinimum": true },
+                "count": { "type": "integer" }
+            }),
+            &[],
+        )
+        .unwrap();
+
+        assert!(code.contains(
+            "crate::validation::check_range(\n                value,\n                Some((1.0, false)),\n                Some((100.0, false)),"
+        ));
+        assert!(code.contains(
+            "crate::validation::check_range(value, Some((0.0, true)), None, &path, violations);"
+        ));
+        assert!(!code.contains("\"count\""));
+    }
+
+    #[test]
+    fn patterns_are_compiled_once_per_field() {
+        let spec = parse_spec(json!({
+            "CountryCode": { "type": "string", "pattern": "^[A-Z]{2}$" }
+        }));
+        let code = struct_validation(
+            &spec,
+            json!({
+                "country": { "$ref": "#/components/schemas/CountryCode" },
+                "codes": { "type": "array", "items": { "type": "string", "pattern": "^[0-9]+$" } }
+            }),
+            &["country"],
+        )
+        .unwrap();
+
+        assert_eq!(code.matches("static PATTERN").count(), 2);
+        assert!(code.contains(
+            "static PATTERN: std::sync::OnceLock<regex_lite::Regex> = std::sync::OnceLock::new();\n            let regex = PATTERN.get_or_init(|| {\n                regex_lite::Regex::new(\"^[A-Z]{2}$\")"
+        ));
+        assert!(code.contains("regex_lite::Regex::new(\"^[0-9]+$\")"));
+        assert!(code.contains("pattern: \"^[0-9]+$\""));
+    }
+
+    #[test]
+    fn unsupported_patterns_fail_generation() {
+        let spec = parse_spec(json!({}));
+        let err = struct_validation(
+            &spec,
+            json!({ "code": { "type": "string", "pattern": "^(?=[A-Z])" } }),
+            &[],
+        )
+        .expect_err("look-ahead is not supported");
+        assert!(err.starts_with("Unsupported pattern `^(?=[A-Z])`"));
+        assert!(err.ends_with("in field `code` of `Demo`"));
+    }
+
+    #[test]
+    fn required_string_parameters_are_checked() {
+        let spec = parse_spec(json!({}));
+        let params: Vec<openapiv3::Parameter> = serde_json::from_value(json!([
+            { "name": "merchant_code", "in": "query", "required": true, "schema": { "type": "string" } },
+            { "name": "limit", "in": "query", "required": true, "schema": { "type": "integer" } },
+            { "name": "search", "in": "query", "schema": { "type": "string" } }
+        ]))
+        .unwrap();
+        let params: Vec<&openapiv3::ParameterData> = params
+            .iter()
+            .map(openapiv3::Parameter::parameter_data_ref)
+            .collect();
+        let ident = Ident::new("ListParams", Span::call_site());
+        let code = crate::format_generated_code(
+            generate_params_validation(&spec, &ident, &params).unwrap(),
+        );
+
+        assert!(code.contains(
+            "let path = crate::validation::field_path(path, \"merchant_code\");\n            crate::validation::check_required(value, &path, violations);"
+        ));
+        assert_eq!(code.matches("check_required").count(), 1);
+    }
+
+    #[test]
+    fn nested_types_are_validated_recursively() {
+        let spec = parse_spec(json!({
+            "Address": { "type": "object", "properties": { "city": { "type": "string" } } },
+            "Tag": { "type": "string", "maxLength": 10 }
+        }));
+        let code = struct_validation(
+            &spec,
+            json!({
+                "address": { "$ref": "#/components/schemas/Address" },
+                "lines": { "type": "array", "items": { "$ref": "#/components/schemas/Address" } },
+                "tags": { "type": "array", "maxItems": 3, "items": { "$ref": "#/components/schemas/Tag" } },
+                "active": { "type": "boolean" }
+            }),
+            &["address"],
+        )
+        .unwrap();
+
+        assert!(code.contains(
+            "let value = &self.address;\n            let path = crate::validation::field_path(path, \"address\");\n            crate::validation::Validate::validate_at(value, &path, violations);"
+        ));
+        assert!(code.contains(
+            "let path = crate::validation::field_path(path, \"lines\");\n            crate::validation::Validate::validate_at(value, &path, violations);"
+        ));
+        assert!(code.contains(
+            "crate::validation::check_items(value.len(), None, Some(3), &path, violations);\n            for (index, value) in value.iter().enumerate() {\n                let path = crate::validation::item_path(&path, index);\n                crate::validation::check_length(value, None, Some(10), &path, violations);"
+        ));
+        assert!(!code.contains("\"active\""));
+    }
+
+    #[test]
+    fn structs_without_constraints_get_an_empty_impl() {
+        let spec = parse_spec(json!({}));
+        let code = struct_validation(&spec, json!({ "id": { "type": "string" } }), &[]).unwrap();
+        assert!(code.contains("impl crate::validation::Validate for Demo {}"));
+    }
+}
@@ -1,12 +1,16 @@
-/// Example: Create a checkout for a card reader payment
+/// Example: Take a card reader payment
 ///
-/// This example demonstrates how to create a checkout for 10 EUR
-/// that can be processed on a SumUp card reader.
+/// This example demonstrates how to take a payment of 10 EUR on a SumUp
+/// card reader and wait for its outcome.
 ///
 /// To run this example:
 /// 1. Set your API key: export SUMUP_API_KEY="your_api_key_here"
 /// 2. Set your merchant code: export SUMUP_MERCHANT_CODE="your_merchant_code"
 /// 3. Run: cargo run --example checkout_card_reader
+use std::time::Duration;
+
+use sumup::reader_payment::{ReaderPayment, ReaderPaymentEvent, ReaderPaymentOutcome};
+use sumup::wait::WaitOptions;
 use sumup::Client;
 
 #[tokio::main]
@@ -32,35 +36,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("Creating checkout with reference: {}", checkout_reference);
 
-    // Initiate the checkout on a card reader for a card-present payment
-    match client
-        .readers()
-        .create_checkout(
-            &merchant_code,
-            &reader.id,
-            sumup::readers::CreateCheckoutRequest {
-                total_amount: sumup::readers::CreateCheckoutRequestTotalAmount {
-                    currency: "EUR".into(),
-                    minor_unit: 2,
-                    value: 1000,
-                },
-                affiliate: None,
-                card_type: None,
-                description: Some("sumup-rs card reader checkout example".into()),
-                installments: None,
-                return_url: None,
-                tip_rates: None,
-                tip_timeout: None,
-                aade: None,
-            },
-        )
+    let request = sumup::readers::CreateCheckoutRequest::builder(sumup::Money::from_minor_units(
+        1000,
+        sumup::Currency::EUR,
+    ))
+    .description("sumup-rs card reader checkout example")
+    .build();
+
+    // Take the card-present payment on the reader: create the checkout, wait for the
+    // customer, terminate it if they walk away and fetch the resulting transaction.
+    match ReaderPayment::new(&client, &merchant_code, &reader.id, request)
+        .with_wait_options(WaitOptions::default().with_timeout(Duration::from_secs(120)))
+        .on_event(|event| match event {
+            ReaderPaymentEvent::CheckoutCreated { .. } => println!("✓ Checkout created"),
+            ReaderPaymentEvent::StatusChanged(status) => println!("  status: {:?}", status),
+            ReaderPaymentEvent::Terminating(reason) => println!("  terminating: {:?}", reason),
+        })
+        .run()
         .await
     {
-        Ok(_) => {
-            println!("✓ Checkout created successfully!");
-        }
+        Ok(completion) => match completion.outcome {
+            ReaderPaymentOutcome::Successful => {
+                let code = completion.transaction.and_then(|t| t.transaction_code);
+                println!("✓ Payment successful, transaction code: {:?}", code);
+            }
+            outcome => println!("✗ Payment not completed: {:?}", outcome),
+        },
         Err(e) => {
-            eprintln!("✗ Failed to create checkout: {}", e);
+            eprintln!("✗ Failed to take payment: {}", e);
         }
     }
 
@@ -21,25 +21,18 @@ use axum::{
     Json, Router,
 };
 use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
-use oauth2::reqwest;
-use oauth2::{basic::BasicClient, EndpointNotSet, EndpointSet};
-use oauth2::{
-    AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, PkceCodeChallenge,
-    PkceCodeVerifier, RedirectUrl, Scope, TokenResponse, TokenUrl,
-};
 use serde::Deserialize;
 use std::sync::Arc;
-use uuid::Uuid;
 
 use sumup::{
+    oauth::{verify_state, OAuthClient, Scope},
     resources::merchants::{GetParams, Merchant},
-    Authorization, Client,
+    Client,
 };
 
 #[derive(Clone)]
 struct AppState {
-    oauth_client:
-        Arc<BasicClient<EndpointSet, EndpointNotSet, EndpointNotSet, EndpointNotSet, EndpointSet>>,
+    oauth_client: Arc<OAuthClient>,
 }
 
 const STATE_COOKIE_NAME: &str = "oauth_state";
@@ -53,18 +46,7 @@ async fn main() {
     let redirect_uri =
         std::env::var("REDIRECT_URI").expect("REDIRECT_URI environment variable must be set");
 
-    let auth_url = AuthUrl::new("https://api.sumup.com/authorize".to_string())
-        .expect("invalid authorization endpoint URL");
-    let token_url = TokenUrl::new("https://api.sumup.com/token".to_string())
-        .expect("invalid token endpoint URL");
-
-    let oauth_client = Arc::new(
-        BasicClient::new(ClientId::new(client_id))
-            .set_client_secret(ClientSecret::new(client_secret))
-            .set_auth_uri(auth_url)
-            .set_token_uri(token_url)
-            .set_redirect_uri(RedirectUrl::new(redirect_uri).unwrap()),
-    );
+    let oauth_client = Arc::new(OAuthClient::new(client_id, client_secret, redirect_uri));
 
     let app_state = AppState { oauth_client };
 
@@ -80,17 +62,22 @@ async fn main() {
 }
 
 async fn handle_login(State(state): State<AppState>, jar: CookieJar) -> (CookieJar, Redirect) {
-    let state_token = Uuid::new_v4().to_string();
-    let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+    // Scope is a mechanism in OAuth 2.0 to limit an application's access to a user's account.
+    // You should always request the minimal set of scope that you need for your application to
+    // work. In this example we only need access to the user's profile.
+    let request = state
+        .oauth_client
+        .authorization_request([Scope::UserProfileReadonly])
+        .expect("build authorization request");
 
-    let state_cookie = Cookie::build((STATE_COOKIE_NAME, state_token.clone()))
+    let state_cookie = Cookie::build((STATE_COOKIE_NAME, request.state().to_owned()))
         .path("/")
         .http_only(true)
         .same_site(SameSite::Lax)
         // Set to true on production when running on https
         .secure(false);
 
-    let pkce_cookie = Cookie::build((PKCE_COOKIE_NAME, pkce_verifier.secret().to_owned()))
+    let pkce_cookie = Cookie::build((PKCE_COOKIE_NAME, request.pkce_verifier().to_owned()))
         .path("/")
         .http_only(true)
         .same_site(SameSite::Lax)
@@ -99,18 +86,7 @@ async fn handle_login(State(state): State<AppState>, jar: CookieJar) -> (CookieJ
 
     let jar = jar.add(state_cookie).add(pkce_cookie);
 
-    let (authorization_url, _csrf_token) = state
-        .oauth_client
-        .authorize_url(move || CsrfToken::new(state_token.clone()))
-        // Scope is a mechanism in OAuth 2.0 to limit an application's access to a user's account.
-        // You should always request the minimal set of scope that you need for your application to
-        // work. In this example we use "email profile" scope which gives you access to user's
-        // email address and their profile.
-        .add_scope(Scope::new("email profile".into()))
-        .set_pkce_challenge(pkce_challenge)
-        .url();
-
-    (jar, Redirect::temporary(authorization_url.as_str()))
+    (jar, Redirect::temporary(request.url()))
 }
 
 #[derive(Deserialize)]
@@ -136,20 +112,15 @@ async fn handle_callback(
     let jar = jar.remove(Cookie::build(PKCE_COOKIE_NAME));
 
     let state_cookie = state_cookie.expect("missing oauth state cookie");
+    verify_state(&state_cookie, &params.state).expect("invalid state cookie");
 
-    if params.state != state_cookie {
-        panic!("invalid state cookie")
-    }
-
-    let pkce_verifier = PkceCodeVerifier::new(pkce_cookie.expect("missing oauth pkce cookie"));
+    let pkce_verifier = pkce_cookie.expect("missing oauth pkce cookie");
 
-    let token_response = state
+    let tokens = state
         .oauth_client
-        .exchange_code(AuthorizationCode::new(params.code.clone()))
-        .set_pkce_verifier(pkce_verifier)
-        .request_async(&reqwest::Client::default())
+        .exchange_code(&params.code, &pkce_verifier)
         .await
-        .expect("retrieve token vie code exchange");
+        .expect("retrieve token via code exchange");
 
     // Users might have access to multiple merchant accounts, the `merchant_code` parameter
     // returned in the callback is the merchant code of their default merchant account.
@@ -159,14 +130,16 @@ async fn handle_callback(
 
     println!("merchant code: {merchant_code}");
 
-    let access_token = token_response.access_token().secret().to_owned();
-    let client = Client::default().with_authorization(Authorization::access_token(access_token));
+    // The access token is refreshed automatically using the refresh token.
+    let client = state
+        .oauth_client
+        .authorize_client(Client::default(), &tokens);
 
     let merchant = client
         .merchants()
         .get(merchant_code, GetParams::default())
         .await
-        .expect("get mercahnt");
+        .expect("get merchant");
 
     (jar, Json(merchant))
 }
@@ -2,32 +2,577 @@
 //!
 //! Use [`Authorization::api_key`] for API keys (from the developer portal) or
 //! [`Authorization::access_token`] for OAuth access tokens.
+//!
+//! Access tokens issued through OAuth 2.0 expire. Instead of managing the expiry yourself,
+//! use [`Authorization::token_provider`] with a [`TokenProvider`], such as the built-in
+//! [`RefreshTokenProvider`], which the client consults before every request:
+//!
+//! ```no_run
+//! use sumup::auth::{Authorization, RefreshTokenProvider};
+//!
+//! let provider = RefreshTokenProvider::new("client-id", "client-secret", "refresh-token");
+//! let client = sumup::Client::default().with_authorization(Authorization::token_provider(provider));
+//! ```
+//!
+//! Server-to-server integrations can use the client-credentials flow instead:
+//!
+//! ```no_run
+//! use sumup::auth::Authorization;
+//!
+//! let client = sumup::Client::default().with_authorization(Authorization::client_credentials(
+//!     "client-id",
+//!     "client-secret",
+//!     ["payments", "transactions.history"],
+//! ));
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::error::UnknownApiBody;
+use crate::secret::Secret;
+
+/// Default OAuth 2.0 token endpoint of the SumUp API.
+pub const TOKEN_URL: &str = "https://api.sumup.com/token";
+
+/// Future returned by [`TokenProvider`] methods.
+pub type TokenFuture<'a> = Pin<Box<dyn Future<Output = Result<Secret, AuthError>> + Send + 'a>>;
+
+/// Source of access tokens consulted by the [`Client`](crate::Client) before each request.
+///
+/// Implementations are shared between all clones of a client and must therefore be
+/// safe to call concurrently.
+pub trait TokenProvider: Send + Sync + 'static {
+    /// Returns a valid access token, obtaining a new one if necessary.
+    fn access_token(&self) -> TokenFuture<'_>;
+
+    /// Returns a new access token after the API rejected `rejected` with `401 Unauthorized`.
+    ///
+    /// The client retries the request once with the returned token, unless it is equal to
+    /// the rejected one. Defaults to [`TokenProvider::access_token`].
+    fn refresh_access_token<'a>(&'a self, rejected: &'a str) -> TokenFuture<'a> {
+        let _ = rejected;
+        self.access_token()
+    }
+}
 
-#[derive(Debug, Clone)]
 /// Supported authorization credentials for the SumUp API.
+#[derive(Clone)]
 pub enum Authorization {
     /// OAuth access token.
-    AccessToken(String),
+    AccessToken(Secret),
     /// API key provisioned in the SumUp developer portal.
-    APIKey(String),
+    APIKey(Secret),
+    /// Provider that supplies (and refreshes) access tokens on demand.
+    Provider(Arc<dyn TokenProvider>),
 }
 
 impl Authorization {
     /// Builds an API-key authorization credential.
     pub fn api_key(key: impl Into<String>) -> Self {
-        Self::APIKey(key.into())
+        Self::APIKey(Secret::new(key))
     }
 
     /// Builds an OAuth access-token authorization credential.
     pub fn access_token(token: impl Into<String>) -> Self {
-        Self::AccessToken(token.into())
+        Self::AccessToken(Secret::new(token))
+    }
+
+    /// Builds a credential that obtains access tokens with the OAuth 2.0 client-credentials
+    /// flow, caching and renewing them automatically.
+    ///
+    /// Use [`ClientCredentialsProvider`] directly to customize the token endpoint.
+    pub fn client_credentials<S>(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = S>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::token_provider(ClientCredentialsProvider::new(
+            client_id,
+            client_secret,
+            scopes,
+        ))
+    }
+
+    /// Builds an authorization credential backed by a [`TokenProvider`].
+    pub fn token_provider(provider: impl TokenProvider) -> Self {
+        Self::Provider(Arc::new(provider))
     }
 
     /// Returns the raw value that should be sent in the Authorization header.
-    pub fn get_header(&self) -> &str {
+    ///
+    /// Returns `None` for [`Authorization::Provider`], whose token is only known once
+    /// requested; the [`Client`](crate::Client) asks the provider for it.
+    pub fn get_header(&self) -> Option<&str> {
+        match self {
+            Authorization::AccessToken(token) => Some(token.secret()),
+            Authorization::APIKey(api_key) => Some(api_key.secret()),
+            Authorization::Provider(_) => None,
+        }
+    }
+
+    /// Returns the token provider, or `None` for a static access token or API key.
+    pub fn provider(&self) -> Option<&dyn TokenProvider> {
+        match self {
+            Authorization::Provider(provider) => Some(provider.as_ref()),
+            Authorization::AccessToken(_) | Authorization::APIKey(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for Authorization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AccessToken(token) => f.debug_tuple("AccessToken").field(token).finish(),
+            Self::APIKey(api_key) => f.debug_tuple("APIKey").field(api_key).finish(),
+            Self::Provider(_) => f.write_str("Provider(..)"),
+        }
+    }
+}
+
+/// Sets the bearer `Authorization` header of `request`.
+pub(crate) fn set_bearer(request: &mut reqwest::Request, token: &Secret) -> Result<(), AuthError> {
+    // Keep the formatted header in a `Secret` so that it is wiped like the token itself.
+    let header = Secret::new(format!("Bearer {}", token.secret()));
+    let mut value = reqwest::header::HeaderValue::from_str(header.secret())
+        .map_err(|err| AuthError::Other(Box::new(err)))?;
+    value.set_sensitive(true);
+    request
+        .headers_mut()
+        .insert(reqwest::header::AUTHORIZATION, value);
+    Ok(())
+}
+
+/// Errors raised while obtaining an access token.
+#[derive(Debug)]
+pub enum AuthError {
+    /// The token endpoint could not be reached.
+    Network(reqwest::Error),
+    /// The token endpoint rejected the request, e.g. because the refresh token was revoked.
+    Rejected(reqwest::StatusCode, UnknownApiBody),
+    /// The granted scopes include none of the scopes accepted by the operation.
+    MissingScopes(Vec<crate::scope::Scope>),
+    /// The `state` returned by the authorization server does not match the one sent.
+    StateMismatch,
+    /// Any other failure reported by a custom [`TokenProvider`].
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl std::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Network(err) => write!(f, "token request failed: {}", err),
+            Self::Rejected(status, body) => {
+                write!(f, "token request rejected ({}): {}", status, body)
+            }
+            Self::MissingScopes(scopes) => {
+                let scopes = scopes
+                    .iter()
+                    .map(|scope| scope.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "access token lacks the required scopes (any of: {})",
+                    scopes
+                )
+            }
+            Self::StateMismatch => write!(f, "OAuth state mismatch"),
+            Self::Other(err) => write!(f, "failed to obtain access token: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Authorization::AccessToken(token) => token,
-            Authorization::APIKey(api_key) => api_key,
+            Self::Network(err) => Some(err),
+            Self::Rejected(..) | Self::MissingScopes(_) | Self::StateMismatch => None,
+            Self::Other(err) => Some(err.as_ref()),
+        }
+    }
+}
+
+impl From<reqwest::Error> for AuthError {
+    fn from(value: reqwest::Error) -> Self {
+        Self::Network(value)
+    }
+}
+
+/// [`TokenProvider`] that keeps an access token fresh using an OAuth 2.0 refresh token.
+///
+/// The access token is refreshed shortly before it expires and whenever the API rejects
+/// it. Cloning the provider is cheap; all clones share the same token state, so
+/// concurrent requests trigger at most one refresh. Configuring a clone only changes the
+/// configuration of that clone.
+#[derive(Clone)]
+pub struct RefreshTokenProvider {
+    endpoint: TokenEndpoint,
+    state: Arc<tokio::sync::Mutex<RefreshTokenState>>,
+    on_rotated: Option<Arc<RotationCallback>>,
+}
+
+/// Callback receiving a rotated refresh token.
+type RotationCallback = dyn Fn(&Secret) + Send + Sync;
+
+struct RefreshTokenState {
+    token: Option<CachedToken>,
+    refresh_token: Secret,
+}
+
+impl RefreshTokenProvider {
+    /// Creates a provider that obtains access tokens with the given refresh token.
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> Self {
+        Self::seeded(
+            client_id.into(),
+            Secret::new(client_secret),
+            Secret::new(refresh_token),
+            None,
+        )
+    }
+
+    /// Creates a provider whose token state starts with `token`.
+    pub(crate) fn seeded(
+        client_id: String,
+        client_secret: Secret,
+        refresh_token: Secret,
+        token: Option<CachedToken>,
+    ) -> Self {
+        Self {
+            endpoint: TokenEndpoint::new(client_id, client_secret),
+            state: Arc::new(tokio::sync::Mutex::new(RefreshTokenState {
+                token,
+                refresh_token,
+            })),
+            on_rotated: None,
+        }
+    }
+
+    /// Seeds the provider with an access token that is still valid for `expires_in`,
+    /// avoiding a refresh on the first request.
+    ///
+    /// The token is shared with all clones. A refresh in progress is awaited first, and its
+    /// token is then replaced by the seeded one.
+    pub async fn with_access_token(
+        self,
+        access_token: impl Into<String>,
+        expires_in: Duration,
+    ) -> Self {
+        self.state.lock().await.token =
+            Some(CachedToken::new(Secret::new(access_token), expires_in));
+        self
+    }
+
+    /// Overrides the token endpoint, defaults to [`TOKEN_URL`].
+    pub fn with_token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.endpoint.token_url = token_url.into();
+        self
+    }
+
+    /// Overrides the HTTP client used to call the token endpoint.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.endpoint.http_client = http_client;
+        self
+    }
+
+    /// Sets how long before its expiry an access token is refreshed. Defaults to 60 seconds.
+    pub fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.endpoint.refresh_margin = refresh_margin;
+        self
+    }
+
+    /// Registers a callback invoked with the new refresh token whenever the token endpoint
+    /// rotates it, e.g. to persist it.
+    ///
+    /// The callback runs while the token state is locked and must not block.
+    pub fn on_refresh_token_rotated(
+        mut self,
+        callback: impl Fn(&Secret) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_rotated = Some(Arc::new(callback));
+        self
+    }
+
+    /// Returns the current refresh token.
+    ///
+    /// The token endpoint may rotate refresh tokens; persist this value to resume the
+    /// session later.
+    pub async fn refresh_token(&self) -> Secret {
+        self.state.lock().await.refresh_token.clone()
+    }
+
+    async fn refresh(&self, state: &mut RefreshTokenState) -> Result<Secret, AuthError> {
+        let response = self
+            .endpoint
+            .request(&[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", state.refresh_token.secret()),
+            ])
+            .await?;
+        if let Some(refresh_token) = &response.refresh_token {
+            if *refresh_token != state.refresh_token {
+                state.refresh_token = refresh_token.clone();
+                if let Some(on_rotated) = &self.on_rotated {
+                    on_rotated(refresh_token);
+                }
+            }
+        }
+        let token = CachedToken::from(response);
+        let access_token = token.access_token.clone();
+        state.token = Some(token);
+        Ok(access_token)
+    }
+}
+
+impl TokenProvider for RefreshTokenProvider {
+    fn access_token(&self) -> TokenFuture<'_> {
+        Box::pin(async move {
+            let mut state = self.state.lock().await;
+            match &state.token {
+                Some(token) if token.is_fresh(self.endpoint.refresh_margin) => {
+                    Ok(token.access_token.clone())
+                }
+                _ => self.refresh(&mut state).await,
+            }
+        })
+    }
+
+    fn refresh_access_token<'a>(&'a self, rejected: &'a str) -> TokenFuture<'a> {
+        Box::pin(async move {
+            let mut state = self.state.lock().await;
+            match &state.token {
+                // Another request already replaced the rejected token.
+                Some(token)
+                    if token.access_token.secret() != rejected
+                        && token.is_fresh(self.endpoint.refresh_margin) =>
+                {
+                    Ok(token.access_token.clone())
+                }
+                _ => self.refresh(&mut state).await,
+            }
+        })
+    }
+}
+
+impl std::fmt::Debug for RefreshTokenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshTokenProvider")
+            .field("token_url", &self.endpoint.token_url)
+            .field("client_id", &self.endpoint.client_id)
+            .field("refresh_margin", &self.endpoint.refresh_margin)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`TokenProvider`] implementing the OAuth 2.0 client-credentials flow.
+///
+/// Intended for server-to-server integrations: the provider requests an access token
+/// for the configured scopes on first use, caches it and requests a new one shortly
+/// before it expires or when the API rejects it. Cloning the provider is cheap; all
+/// clones share the cached token. Configuring a clone only changes the configuration of
+/// that clone.
+#[derive(Clone)]
+pub struct ClientCredentialsProvider {
+    endpoint: TokenEndpoint,
+    scope: String,
+    token: Arc<tokio::sync::Mutex<Option<CachedToken>>>,
+}
+
+impl ClientCredentialsProvider {
+    /// Creates a provider requesting access tokens for the given scopes.
+    pub fn new<S>(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = S>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        let scope = scopes
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>()
+            .join(" ");
+        Self {
+            endpoint: TokenEndpoint::new(client_id.into(), Secret::new(client_secret)),
+            scope,
+            token: Arc::new(tokio::sync::Mutex::new(None)),
+        }
+    }
+
+    /// Overrides the token endpoint, defaults to [`TOKEN_URL`].
+    pub fn with_token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.endpoint.token_url = token_url.into();
+        self
+    }
+
+    /// Overrides the HTTP client used to call the token endpoint.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.endpoint.http_client = http_client;
+        self
+    }
+
+    /// Sets how long before its expiry an access token is renewed. Defaults to 60 seconds.
+    pub fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.endpoint.refresh_margin = refresh_margin;
+        self
+    }
+
+    async fn request_token(&self, token: &mut Option<CachedToken>) -> Result<Secret, AuthError> {
+        let mut params = vec![("grant_type", "client_credentials")];
+        if !self.scope.is_empty() {
+            params.push(("scope", self.scope.as_str()));
+        }
+        let response = self.endpoint.request(&params).await?;
+        let cached = CachedToken::from(response);
+        let access_token = cached.access_token.clone();
+        *token = Some(cached);
+        Ok(access_token)
+    }
+}
+
+impl TokenProvider for ClientCredentialsProvider {
+    fn access_token(&self) -> TokenFuture<'_> {
+        Box::pin(async move {
+            let mut token = self.token.lock().await;
+            match &*token {
+                Some(cached) if cached.is_fresh(self.endpoint.refresh_margin) => {
+                    Ok(cached.access_token.clone())
+                }
+                _ => self.request_token(&mut token).await,
+            }
+        })
+    }
+
+    fn refresh_access_token<'a>(&'a self, rejected: &'a str) -> TokenFuture<'a> {
+        Box::pin(async move {
+            let mut token = self.token.lock().await;
+            match &*token {
+                // Another request already replaced the rejected token.
+                Some(cached)
+                    if cached.access_token.secret() != rejected
+                        && cached.is_fresh(self.endpoint.refresh_margin) =>
+                {
+                    Ok(cached.access_token.clone())
+                }
+                _ => self.request_token(&mut token).await,
+            }
+        })
+    }
+}
+
+impl std::fmt::Debug for ClientCredentialsProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientCredentialsProvider")
+            .field("token_url", &self.endpoint.token_url)
+            .field("client_id", &self.endpoint.client_id)
+            .field("scope", &self.scope)
+            .field("refresh_margin", &self.endpoint.refresh_margin)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Client configuration for calls to the OAuth 2.0 token endpoint.
+#[derive(Clone)]
+pub(crate) struct TokenEndpoint {
+    pub(crate) http_client: reqwest::Client,
+    pub(crate) token_url: String,
+    client_id: String,
+    client_secret: Secret,
+    refresh_margin: Duration,
+}
+
+impl TokenEndpoint {
+    pub(crate) fn new(client_id: String, client_secret: Secret) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            token_url: TOKEN_URL.to_string(),
+            client_id,
+            client_secret,
+            refresh_margin: Duration::from_secs(60),
+        }
+    }
+
+    /// Requests a token for the given grant, authenticating with the client credentials.
+    pub(crate) async fn request(&self, grant: &[(&str, &str)]) -> Result<TokenResponse, AuthError> {
+        let mut form = vec![
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.secret()),
+        ];
+        form.extend_from_slice(grant);
+
+        let response = self
+            .http_client
+            .post(&self.token_url)
+            .header("User-Agent", crate::version::user_agent())
+            .form(&form)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.bytes().await?;
+            return Err(AuthError::Rejected(
+                status,
+                UnknownApiBody::from_bytes(body.as_ref()),
+            ));
+        }
+
+        Ok(response.json().await?)
+    }
+}
+
+/// Access token together with its expiry.
+pub(crate) struct CachedToken {
+    access_token: Secret,
+    expires_at: Option<Instant>,
+}
+
+impl CachedToken {
+    /// Creates a token that is valid for `expires_in`.
+    pub(crate) fn new(access_token: Secret, expires_in: Duration) -> Self {
+        Self {
+            access_token,
+            expires_at: Instant::now().checked_add(expires_in),
+        }
+    }
+
+    /// Reports whether the token stays valid for at least `margin`.
+    fn is_fresh(&self, margin: Duration) -> bool {
+        match self.expires_at {
+            Some(expires_at) => Instant::now()
+                .checked_add(margin)
+                .is_some_and(|now| now < expires_at),
+            None => true,
+        }
+    }
+}
+
+impl From<TokenResponse> for CachedToken {
+    fn from(value: TokenResponse) -> Self {
+        Self {
+            expires_at: value
+                .expires_in
+                .and_then(|expires_in| Instant::now().checked_add(Duration::from_secs(expires_in))),
+            access_token: value.access_token,
         }
     }
 }
+
+/// Successful response of the OAuth 2.0 token endpoint.
+#[derive(serde::Deserialize)]
+pub(crate) struct TokenResponse {
+    pub(crate) access_token: Secret,
+    pub(crate) expires_in: Option<u64>,
+    pub(crate) refresh_token: Option<Secret>,
+    pub(crate) scope: Option<String>,
+}
@@ -0,0 +1,61 @@
+//! Descriptions of the requests SumUp sends to your server.
+//!
+//! Some operations make SumUp call back a URL given in their request, e.g. the
+//! `return_url` of [`ReadersClient::create_checkout`](crate::readers::ReadersClient::create_checkout).
+//! Every resource module with such operations has a generated `callbacks` module with one
+//! type per callback implementing [`Callback`], named after the callback with a `Callback`
+//! suffix. It names the payload SumUp sends and the responses it expects:
+//!
+//! ```
+//! use sumup::callback::Callback;
+//! use sumup::readers::callbacks::ReaderCheckoutStatusChangeCallback;
+//!
+//! let body = br#"{
+//!     "id": "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+//!     "event_type": "solo.transaction.updated",
+//!     "timestamp": "2023-10-05T14:48:00Z",
+//!     "payload": {
+//!         "client_transaction_id": "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+//!         "merchant_code": "M1234567",
+//!         "status": "successful"
+//!     }
+//! }"#;
+//! let event = ReaderCheckoutStatusChangeCallback::parse_request(body)?;
+//! assert_eq!(event.payload.merchant_code, "M1234567");
+//! assert_eq!(ReaderCheckoutStatusChangeCallback::RESPONSES[0].status, 200);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+/// A request SumUp sends to your server, as described by the API specification.
+pub trait Callback {
+    /// Name of the callback in the API specification.
+    const NAME: &'static str;
+    /// Name of the client method whose request configures the callback.
+    const OPERATION: &'static str;
+    /// HTTP method of the callback request.
+    const METHOD: reqwest::Method;
+    /// Runtime expression of the URL the request is sent to, e.g.
+    /// `{$request.body#/return_url}`.
+    const URL: &'static str;
+    /// Responses SumUp expects from your server.
+    const RESPONSES: &'static [CallbackResponse];
+
+    /// Body of the callback request.
+    type Request: serde::de::DeserializeOwned;
+    /// Body of a successful response, `()` when SumUp expects none.
+    type Response: serde::Serialize;
+
+    /// Parses the body of a callback request.
+    fn parse_request(body: &[u8]) -> Result<Self::Request, serde_json::Error> {
+        serde_json::from_slice(body)
+    }
+}
+
+/// A response SumUp expects to a [`Callback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallbackResponse {
+    /// HTTP status of the response.
+    pub status: u16,
+    /// What the status means to SumUp.
+    pub description: &'static str,
+}
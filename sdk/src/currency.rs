@@ -0,0 +1,247 @@
+//! Metadata of the currencies supported by the SumUp API.
+//!
+//! [`Currency`] knows its ISO 4217 code, the number of decimals of its minor unit and its
+//! symbol, and can be parsed from a code:
+//!
+//! ```
+//! use sumup::Currency;
+//!
+//! let currency: Currency = "clp".parse()?;
+//! assert_eq!(currency, Currency::CLP);
+//! assert_eq!(currency.minor_unit(), 0);
+//! assert_eq!(Currency::EUR.symbol(), "€");
+//! # Ok::<(), sumup::currency::ParseCurrencyError>(())
+//! ```
+//!
+//! Amounts are formatted for a locale with [`Money::format`](crate::Money::format).
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::money::Amount;
+pub use crate::resources::common::Currency;
+
+impl Currency {
+    /// Returns the ISO 4217 code, e.g. `EUR`.
+    pub fn code(&self) -> &str {
+        match self {
+            Currency::BGN => "BGN",
+            Currency::BRL => "BRL",
+            Currency::CHF => "CHF",
+            Currency::CLP => "CLP",
+            Currency::COP => "COP",
+            Currency::CZK => "CZK",
+            Currency::DKK => "DKK",
+            Currency::EUR => "EUR",
+            Currency::GBP => "GBP",
+            Currency::HRK => "HRK",
+            Currency::HUF => "HUF",
+            Currency::NOK => "NOK",
+            Currency::PLN => "PLN",
+            Currency::RON => "RON",
+            Currency::SEK => "SEK",
+            Currency::USD => "USD",
+            Currency::Other(code) => code,
+        }
+    }
+
+    /// Returns the number of decimals of the minor unit, e.g. `2` for EUR.
+    ///
+    /// Follows the SumUp API rather than ISO 4217 where they differ: CLP, COP and HUF have
+    /// no minor unit. Unknown currencies use their ISO 4217 exponent, or two if the SDK
+    /// does not know it.
+    pub fn minor_unit(&self) -> u32 {
+        match self {
+            Currency::CLP | Currency::COP | Currency::HUF => 0,
+            Currency::Other(code) => match code.as_str() {
+                "BIF" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+                | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+                "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+                "CLF" | "UYW" => 4,
+                _ => 2,
+            },
+            _ => 2,
+        }
+    }
+
+    /// Returns the symbol used when formatting amounts, e.g. `€` for EUR.
+    ///
+    /// Currencies without a well-known symbol return their code.
+    pub fn symbol(&self) -> &str {
+        match self {
+            Currency::BGN => "лв.",
+            Currency::BRL => "R$",
+            Currency::CLP | Currency::COP | Currency::USD => "$",
+            Currency::CZK => "Kč",
+            Currency::DKK => "kr.",
+            Currency::EUR => "€",
+            Currency::GBP => "£",
+            Currency::HRK => "kn",
+            Currency::HUF => "Ft",
+            Currency::NOK | Currency::SEK => "kr",
+            Currency::PLN => "zł",
+            Currency::RON => "lei",
+            Currency::CHF | Currency::Other(_) => self.code(),
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Error returned when a string is not a three-letter currency code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCurrencyError {
+    input: String,
+}
+
+impl fmt::Display for ParseCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid currency code `{}`", self.input)
+    }
+}
+
+impl std::error::Error for ParseCurrencyError {}
+
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    /// Parses an ISO 4217 code, ignoring case. Codes without a variant become
+    /// [`Currency::Other`].
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.len() != 3 || !input.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+            return Err(ParseCurrencyError {
+                input: input.to_string(),
+            });
+        }
+        let code = input.to_ascii_uppercase();
+        Ok(
+            serde_json::from_value(serde_json::Value::String(code.clone()))
+                .unwrap_or(Currency::Other(code)),
+        )
+    }
+}
+
+/// Separators and symbol placement of a locale.
+struct NumberStyle {
+    decimal: char,
+    group: &'static str,
+    symbol_first: bool,
+    symbol_spaced: bool,
+}
+
+impl NumberStyle {
+    /// Looks up the style of a BCP 47 locale such as `de-DE` or `pt_BR`, falling back to
+    /// English conventions for unknown locales.
+    fn for_locale(locale: &str) -> Self {
+        let mut parts = locale.split(['-', '_']);
+        let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let region = parts.next().unwrap_or_default().to_ascii_uppercase();
+        let style = |decimal, group, symbol_first, symbol_spaced| NumberStyle {
+            decimal,
+            group,
+            symbol_first,
+            symbol_spaced,
+        };
+        match (language.as_str(), region.as_str()) {
+            ("de" | "fr" | "it", "CH" | "LI") => style('.', "’", true, true),
+            ("de", "AT") => style(',', "\u{a0}", true, true),
+            ("pt", "BR") | ("es", "CO") | ("nl", _) => style(',', ".", true, true),
+            ("es", "CL") => style(',', ".", true, false),
+            ("fr", _) => style(',', "\u{202f}", false, true),
+            ("de" | "da" | "es" | "it" | "pt" | "ro" | "hr" | "el" | "sl", _) => {
+                style(',', ".", false, true)
+            }
+            (
+                "bg" | "cs" | "sk" | "hu" | "pl" | "sv" | "nb" | "no" | "fi" | "et" | "lv" | "lt",
+                _,
+            ) => style(',', "\u{a0}", false, true),
+            _ => style('.', ",", true, false),
+        }
+    }
+}
+
+/// Formats the amount with the separators and symbol placement of the locale.
+pub(crate) fn format_amount(amount: Amount, currency: &Currency, locale: &str) -> String {
+    let style = NumberStyle::for_locale(locale);
+    let digits = amount.to_string();
+    let (negative, digits) = match digits.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, digits.as_str()),
+    };
+    let (units, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+    let mut number = String::new();
+    for (index, digit) in units.chars().enumerate() {
+        if index > 0 && (units.len() - index) % 3 == 0 {
+            number.push_str(style.group);
+        }
+        number.push(digit);
+    }
+    if !fraction.is_empty() {
+        number.push(style.decimal);
+        number.push_str(fraction);
+    }
+
+    let sign = if negative { "-" } else { "" };
+    let space = if style.symbol_spaced { "\u{a0}" } else { "" };
+    let symbol = currency.symbol();
+    if style.symbol_first {
+        format!("{sign}{symbol}{space}{number}")
+    } else {
+        format!("{sign}{number}{space}{symbol}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(amount: &str, currency: Currency, locale: &str) -> String {
+        format_amount(amount.parse().unwrap(), &currency, locale)
+            .replace(['\u{a0}', '\u{202f}'], " ")
+    }
+
+    #[test]
+    fn parses_codes_ignoring_case() {
+        assert_eq!("EUR".parse::<Currency>(), Ok(Currency::EUR));
+        assert_eq!("huf".parse::<Currency>(), Ok(Currency::HUF));
+        assert_eq!(
+            "jpy".parse::<Currency>(),
+            Ok(Currency::Other("JPY".to_string()))
+        );
+        assert!("EURO".parse::<Currency>().is_err());
+        assert!("E1R".parse::<Currency>().is_err());
+    }
+
+    #[test]
+    fn minor_units_follow_the_sumup_api() {
+        assert_eq!(Currency::EUR.minor_unit(), 2);
+        assert_eq!(Currency::CLP.minor_unit(), 0);
+        assert_eq!(Currency::HUF.minor_unit(), 0);
+        assert_eq!(Currency::Other("JPY".to_string()).minor_unit(), 0);
+        assert_eq!(Currency::Other("KWD".to_string()).minor_unit(), 3);
+        assert_eq!(Currency::Other("XYZ".to_string()).minor_unit(), 2);
+    }
+
+    #[test]
+    fn formats_amounts_for_locales() {
+        assert_eq!(
+            format("1234567.89", Currency::EUR, "de-DE"),
+            "1.234.567,89 €"
+        );
+        assert_eq!(
+            format("1234567.89", Currency::EUR, "fr_FR"),
+            "1 234 567,89 €"
+        );
+        assert_eq!(format("1234.5", Currency::GBP, "en-GB"), "£1,234.5");
+        assert_eq!(format("-10.00", Currency::USD, "en"), "-$10.00");
+        assert_eq!(format("1234.56", Currency::BRL, "pt-BR"), "R$ 1.234,56");
+        assert_eq!(format("1234.56", Currency::CHF, "de-CH"), "CHF 1’234.56");
+        assert_eq!(format("99.90", Currency::SEK, "sv-SE"), "99,90 kr");
+        assert_eq!(format("5000", Currency::CLP, "es-CL"), "$5.000");
+    }
+}
@@ -33,3 +33,23 @@ pub use chrono;
 
 #[cfg(feature = "jiff")]
 pub use jiff;
+
+/// Converts a [`DateTime`] into a [`SystemTime`](std::time::SystemTime), e.g. to compare it
+/// with the current time.
+#[cfg(any(
+    all(feature = "chrono", not(feature = "jiff")),
+    all(feature = "jiff", not(feature = "chrono"))
+))]
+pub(crate) fn to_system_time(value: &DateTime) -> std::time::SystemTime {
+    #[cfg(all(feature = "chrono", not(feature = "jiff")))]
+    let millis = value.timestamp_millis();
+    #[cfg(all(feature = "jiff", not(feature = "chrono")))]
+    let millis = value.as_millisecond();
+
+    let offset = std::time::Duration::from_millis(millis.unsigned_abs());
+    if millis >= 0 {
+        std::time::UNIX_EPOCH + offset
+    } else {
+        std::time::UNIX_EPOCH - offset
+    }
+}
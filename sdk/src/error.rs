@@ -4,15 +4,24 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::response::ResponseMeta;
+
 /// Generic SDK error type for SumUp API operations.
 #[derive(Debug)]
 pub enum SdkError<E = UnknownApiBody> {
     /// Errors originating from the underlying HTTP client (network, TLS, etc.).
     Network(reqwest::Error),
+    /// No access token could be obtained for the request.
+    Auth(crate::auth::AuthError),
     /// The server returned an API response with an expected error payload.
-    Api(E),
+    Api(Box<E>, Box<ResponseMeta>),
     /// The server returned an unexpected status or payload.
-    Unexpected(reqwest::StatusCode, UnknownApiBody),
+    Unexpected(UnknownApiBody, Box<ResponseMeta>),
+    /// The response body did not match the expected schema.
+    Decode(DecodeError),
+    /// The request violated constraints of the API specification and was not sent, see
+    /// [`Client::with_request_validation`](crate::Client::with_request_validation).
+    Validation(crate::validation::ValidationError),
 }
 
 impl<E> SdkError<E> {
@@ -22,28 +31,37 @@ impl<E> SdkError<E> {
     }
 
     /// Creates a new API error using the supplied body payload.
-    pub fn api(body: E) -> Self {
-        Self::Api(body)
+    pub fn api(body: E, response: ResponseMeta) -> Self {
+        Self::Api(Box::new(body), Box::new(response))
     }
 
     /// Creates an unexpected API error preserving the raw payload.
-    pub fn unexpected(status: reqwest::StatusCode, body: UnknownApiBody) -> Self {
-        Self::Unexpected(status, body)
+    pub fn unexpected(body: UnknownApiBody, response: ResponseMeta) -> Self {
+        Self::Unexpected(body, Box::new(response))
     }
 
     /// Returns the HTTP status code associated with this error when available.
     pub fn status(&self) -> Option<reqwest::StatusCode> {
         match self {
             Self::Network(err) => err.status(),
-            Self::Unexpected(status, _) => Some(*status),
-            Self::Api(_) => None,
+            Self::Auth(_) | Self::Validation(_) => None,
+            _ => self.response().map(ResponseMeta::status),
+        }
+    }
+
+    /// Returns the metadata of the response the error was built from, when available.
+    pub fn response(&self) -> Option<&ResponseMeta> {
+        match self {
+            Self::Api(_, response) | Self::Unexpected(_, response) => Some(response),
+            Self::Decode(err) => Some(err.response()),
+            Self::Network(_) | Self::Auth(_) | Self::Validation(_) => None,
         }
     }
 
     /// Returns the captured error body when the server responded with an expected error payload.
     pub fn body(&self) -> Option<&E> {
         match self {
-            Self::Api(body) => Some(body),
+            Self::Api(body, _) => Some(body),
             _ => None,
         }
     }
@@ -51,7 +69,7 @@ impl<E> SdkError<E> {
     /// Returns the captured unexpected body, when available.
     pub fn unexpected_body(&self) -> Option<&UnknownApiBody> {
         match self {
-            Self::Unexpected(_, body) => Some(body),
+            Self::Unexpected(body, _) => Some(body),
             _ => None,
         }
     }
@@ -59,10 +77,72 @@ impl<E> SdkError<E> {
     /// Consumes the error, yielding the captured API body when available.
     pub fn into_body(self) -> Option<E> {
         match self {
-            Self::Api(body) => Some(body),
+            Self::Api(body, _) => Some(*body),
             _ => None,
         }
     }
+
+    /// Converts the API body with `f`, e.g. to combine the errors of several endpoints.
+    pub fn map_body<F>(self, f: impl FnOnce(E) -> F) -> SdkError<F> {
+        match self {
+            Self::Network(err) => SdkError::Network(err),
+            Self::Auth(err) => SdkError::Auth(err),
+            Self::Api(body, response) => SdkError::Api(Box::new(f(*body)), response),
+            Self::Unexpected(body, response) => SdkError::Unexpected(body, response),
+            Self::Decode(err) => SdkError::Decode(err),
+            Self::Validation(err) => SdkError::Validation(err),
+        }
+    }
+}
+
+impl<E: ErrorBody> SdkError<E> {
+    /// Reports whether repeating the request may succeed, e.g. after a connection error,
+    /// a rate-limited (`429`) or a gateway (`502`, `503`, `504`) response.
+    ///
+    /// Non-idempotent requests should only be repeated when the API deduplicates them, e.g.
+    /// a checkout with the same `checkout_reference`.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Network(err) | Self::Auth(crate::auth::AuthError::Network(err)) => {
+                crate::retry::is_retryable_error(err)
+            }
+            _ => self
+                .response_status()
+                .is_some_and(crate::retry::is_retryable_status),
+        }
+    }
+
+    /// Reports whether the request was rejected for missing or insufficient credentials.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            Self::Auth(crate::auth::AuthError::Network(_)) => false,
+            Self::Auth(_) => true,
+            _ => matches!(
+                self.response_status(),
+                Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+            ),
+        }
+    }
+
+    /// Reports whether the server responded with `404 Not Found`.
+    pub fn is_not_found(&self) -> bool {
+        self.response_status() == Some(reqwest::StatusCode::NOT_FOUND)
+    }
+
+    /// Reports whether the server responded with `409 Conflict`.
+    pub fn is_conflict(&self) -> bool {
+        self.response_status() == Some(reqwest::StatusCode::CONFLICT)
+    }
+
+    /// Reports whether the server responded with `429 Too Many Requests`.
+    pub fn is_rate_limited(&self) -> bool {
+        self.response_status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS)
+    }
+
+    fn response_status(&self) -> Option<reqwest::StatusCode> {
+        self.status()
+            .or_else(|| self.body().and_then(ErrorBody::status))
+    }
 }
 
 impl<E> From<reqwest::Error> for SdkError<E> {
@@ -71,6 +151,24 @@ impl<E> From<reqwest::Error> for SdkError<E> {
     }
 }
 
+impl<E> From<DecodeError> for SdkError<E> {
+    fn from(value: DecodeError) -> Self {
+        Self::Decode(value)
+    }
+}
+
+impl<E> From<crate::auth::AuthError> for SdkError<E> {
+    fn from(value: crate::auth::AuthError) -> Self {
+        Self::Auth(value)
+    }
+}
+
+impl<E> From<crate::validation::ValidationError> for SdkError<E> {
+    fn from(value: crate::validation::ValidationError) -> Self {
+        Self::Validation(value)
+    }
+}
+
 impl<E> std::fmt::Display for SdkError<E>
 where
     E: std::fmt::Debug,
@@ -78,10 +176,15 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Network(err) => write!(f, "network error: {}", err),
-            Self::Api(body) => write!(f, "API error: {:?}", body),
-            Self::Unexpected(status, body) => {
-                write!(f, "unexpected API error ({}): {}", status, body)
+            Self::Auth(err) => write!(f, "authorization error: {}", err),
+            Self::Api(body, response) => {
+                write!(f, "API error ({}): {:?}", response.status(), body)
             }
+            Self::Unexpected(body, response) => {
+                write!(f, "unexpected API error ({}): {}", response.status(), body)
+            }
+            Self::Decode(err) => write!(f, "{}", err),
+            Self::Validation(err) => write!(f, "{}", err),
         }
     }
 }
@@ -93,11 +196,117 @@ where
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Network(err) => Some(err),
+            Self::Auth(err) => Some(err),
+            Self::Decode(err) => Some(err),
+            Self::Validation(err) => Some(err),
             _ => None,
         }
     }
 }
 
+/// Common interface of the endpoint-specific error bodies carried by [`SdkError::Api`].
+///
+/// Implemented by every generated `*ErrorBody` enum, which allows classifying errors
+/// with [`SdkError::is_retryable`] and friends without matching on each operation's body.
+pub trait ErrorBody: std::fmt::Debug {
+    /// Returns the HTTP status the body was returned with, when known.
+    fn status(&self) -> Option<reqwest::StatusCode>;
+}
+
+impl ErrorBody for UnknownApiBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        None
+    }
+}
+
+/// A response body that could not be deserialized into the expected type.
+///
+/// Keeps the raw body and the path to the offending field so that schema drift can be
+/// diagnosed without reproducing the request.
+#[derive(Debug)]
+pub struct DecodeError {
+    response: Box<ResponseMeta>,
+    path: String,
+    source: serde_json::Error,
+}
+
+impl DecodeError {
+    /// Returns the HTTP status of the response.
+    pub fn status(&self) -> reqwest::StatusCode {
+        self.response.status()
+    }
+
+    /// Returns the metadata of the response.
+    pub fn response(&self) -> &ResponseMeta {
+        &self.response
+    }
+
+    /// Returns the raw response body.
+    pub fn body(&self) -> &[u8] {
+        self.response.body().unwrap_or_default()
+    }
+
+    /// Returns the path to the field that failed to deserialize, e.g. `transactions[0].amount`.
+    ///
+    /// The path is `.` when the error occurred at the top level of the body.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to decode response ({}) at `{}`: {}",
+            self.status(),
+            self.path,
+            self.source
+        )
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Deserializes the JSON body captured in `response`, recording the path of the first
+/// field that fails.
+pub(crate) fn decode_json<T>(response: ResponseMeta) -> Result<(T, ResponseMeta), DecodeError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let result = {
+        let body = response.body().unwrap_or_default();
+        let deserializer = &mut serde_json::Deserializer::from_slice(body);
+        serde_path_to_error::deserialize(deserializer)
+    };
+    match result {
+        Ok(data) => Ok((data, response)),
+        Err(err) => Err(DecodeError {
+            response: Box::new(response),
+            path: err.path().to_string(),
+            source: err.into_inner(),
+        }),
+    }
+}
+
+/// Reads the response body and deserializes it as JSON into `T`.
+///
+/// The returned metadata keeps the raw body, see [`ResponseMeta::body`].
+pub(crate) async fn read_json<T, E>(
+    response: reqwest::Response,
+) -> Result<(T, ResponseMeta), SdkError<E>>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let meta = ResponseMeta::from_response(&response);
+    let body = response.bytes().await?;
+    Ok(decode_json(meta.with_body(body))?)
+}
+
 /// Describes an unexpected SumUp API error payload.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -167,6 +376,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decode_json_reports_failing_field_path() {
+        #[derive(Debug, Deserialize)]
+        struct Item {
+            #[allow(dead_code)]
+            amount: f64,
+        }
+        #[derive(Debug, Deserialize)]
+        struct Page {
+            #[allow(dead_code)]
+            items: Vec<Item>,
+        }
+
+        let body = br#"{"items":[{"amount":1.5},{"amount":"2.00"}]}"#;
+        let meta = meta(reqwest::StatusCode::OK).with_body(bytes::Bytes::from_static(body));
+        let err = decode_json::<Page>(meta).unwrap_err();
+        assert_eq!(err.path(), "items[1].amount");
+        assert_eq!(err.status(), reqwest::StatusCode::OK);
+        assert_eq!(err.body(), body);
+        assert!(err.to_string().contains("`items[1].amount`"));
+    }
+
+    fn meta(status: reqwest::StatusCode) -> ResponseMeta {
+        ResponseMeta::new(reqwest::Method::GET, "/v0.1/checkouts", status)
+    }
+
+    #[derive(Debug)]
+    enum TestErrorBody {
+        NotFound,
+        TooManyRequests,
+    }
+
+    impl ErrorBody for TestErrorBody {
+        fn status(&self) -> Option<reqwest::StatusCode> {
+            match self {
+                Self::NotFound => Some(reqwest::StatusCode::NOT_FOUND),
+                Self::TooManyRequests => Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+            }
+        }
+    }
+
+    #[test]
+    fn sdk_error_classifies_api_bodies() {
+        let not_found = SdkError::api(
+            TestErrorBody::NotFound,
+            meta(reqwest::StatusCode::NOT_FOUND),
+        );
+        assert!(not_found.is_not_found());
+        assert!(!not_found.is_retryable());
+        assert!(!not_found.is_auth_error());
+
+        let rate_limited = SdkError::api(
+            TestErrorBody::TooManyRequests,
+            meta(reqwest::StatusCode::TOO_MANY_REQUESTS),
+        );
+        assert!(rate_limited.is_rate_limited());
+        assert!(rate_limited.is_retryable());
+        assert!(!rate_limited.is_conflict());
+    }
+
+    #[test]
+    fn sdk_error_classifies_unexpected_statuses() {
+        let unavailable: SdkError = SdkError::unexpected(
+            UnknownApiBody::Empty,
+            meta(reqwest::StatusCode::SERVICE_UNAVAILABLE),
+        );
+        assert!(unavailable.is_retryable());
+
+        let unauthorized: SdkError = SdkError::unexpected(
+            UnknownApiBody::Empty,
+            meta(reqwest::StatusCode::UNAUTHORIZED),
+        );
+        assert!(unauthorized.is_auth_error());
+        assert!(!unauthorized.is_retryable());
+
+        let missing_scopes: SdkError =
+            SdkError::Auth(crate::auth::AuthError::MissingScopes(Vec::new()));
+        assert!(missing_scopes.is_auth_error());
+    }
+
     #[test]
     fn unknown_api_body_handles_non_utf8_bytes() {
         let payload = [0xff, 0xfe];
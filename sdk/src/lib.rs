@@ -65,29 +65,54 @@
 //! # }
 //! ```
 //!
+//! ### Refreshing access tokens
+//!
+//! OAuth access tokens expire. A [`auth::RefreshTokenProvider`] refreshes them shortly
+//! before expiry and whenever the API rejects them:
+//!
+//! ```no_run
+//! use sumup::auth::{Authorization, RefreshTokenProvider};
+//!
+//! let provider = RefreshTokenProvider::new("client-id", "client-secret", "refresh-token");
+//! let client = sumup::Client::default().with_authorization(Authorization::token_provider(provider));
+//! ```
+//!
+//! ### Retries
+//!
+//! Rate-limited responses, gateway failures and connection errors can be
+//! retried with jittered exponential backoff, see [`retry`] for details:
+//!
+//! ```no_run
+//! # use sumup::{Client, RetryPolicy};
+//! use std::time::Duration;
+//!
+//! let client = Client::default().with_retry_policy(
+//!     RetryPolicy::new(4).with_initial_backoff(Duration::from_millis(500)),
+//! );
+//! ```
+//!
 //! ## Making API Calls
 //!
-//! The SDK organizes endpoints by tags:
+//! The SDK organizes endpoints by tags. Request bodies and query parameters are built
+//! with builders taking the required fields:
 //!
 //! ```no_run
 //! # use sumup::{Client, Currency, checkouts};
 //! # async fn example(client: Client) {
 //! // Create a checkout
-//! let checkout = client.checkouts().create(checkouts::CreateRequest {
-//!     checkout_reference: "unique-ref".to_string(),
-//!     amount: 10.0,
-//!     currency: Currency::EUR,
-//!     merchant_code: "MCODE".to_string(),
-//!     description: None,
-//!     return_url: None,
-//!     customer_id: None,
-//!     purpose: None,
-//!     valid_until: None,
-//!     redirect_url: None,
-//!     hosted_checkout: None,
-//! })
-//! .await
-//! .expect("create checkout");
+//! let request = checkouts::CreateRequest::builder(
+//!     "unique-ref",
+//!     "10.00".parse::<sumup::Amount>().expect("valid amount"),
+//!     Currency::EUR,
+//!     "MCODE",
+//! )
+//! .description("Order #42")
+//! .build();
+//! let checkout = client
+//!     .checkouts()
+//!     .create(request)
+//!     .await
+//!     .expect("create checkout");
 //! println!("created checkout {}", checkout.id.unwrap_or_default());
 //!
 //! // Transactions with query parameters
@@ -96,10 +121,7 @@
 //!     .transactions()
 //!     .list(
 //!         "MERCHANT_CODE",
-//!         ListParams {
-//!             limit: Some(10),
-//!             ..Default::default()
-//!         },
+//!         ListParams::builder().limit(10).build(),
 //!     )
 //!     .await
 //!     .expect("list transactions");
@@ -108,6 +130,10 @@
 //! # }
 //! ```
 //!
+//! [`Client::with_request_validation`] checks requests against the length, pattern, range
+//! and allowed values documented by the API before sending them, see the [`validation`]
+//! module.
+//!
 //! ## DateTime Support
 //!
 //! The SDK supports both [`chrono`](https://docs.rs/chrono) (default) and
@@ -127,14 +153,26 @@
 //! sumup = { version = "0.5", default-features = false, features = ["chrono", "reqwest-rustls-tls"] }
 //! ```
 //!
+//! ## Amounts
+//!
+//! Monetary amounts, such as the `amount` of a checkout or a transaction, are exact
+//! [`Amount`] decimals in major units rather than floats. [`Money`] pairs an integer
+//! number of minor units with its [`Currency`] for exact arithmetic, converts from and
+//! to the `{value, minor_unit}` amounts of reader checkouts and formats amounts for a
+//! locale; see the [`money`] and [`currency`] modules. Types with an amount next to a
+//! `currency` return both as [`Money`] from accessors such as `Checkout::amount_money`.
+//!
 //! ## Error Handling
 //!
 //! All SDK calls return a [`SdkResult`] whose error side is a [`SdkError`]. When the
 //! SumUp API responds with a non-success status, the SDK builds an
 //! `SdkError::Api` containing an endpoint-specific payload (e.g. a `Unauthorized`
 //! enum variant). Any undocumented status codes fall back to
-//! `SdkError::Unexpected`, which preserves the HTTP status and best-effort body
-//! parsing. You can inspect failures like this:
+//! `SdkError::Unexpected`, which preserves the best-effort parsed body. Both carry a
+//! [`ResponseMeta`] with the status, headers, request ID and timing of the response.
+//! Bodies that do not match the expected schema produce `SdkError::Decode`, which
+//! carries the status, the raw body and the path to the failing field. You can inspect
+//! failures like this:
 //!
 //! ```no_run
 //! # use sumup::{Client, error::SdkError};
@@ -143,23 +181,35 @@
 //! let client = Client::default();
 //! match client.checkouts().list(Default::default()).await {
 //!     Ok(checkouts) => println!("retrieved {} checkouts", checkouts.len()),
-//!     Err(SdkError::Api(body)) => match body {
+//!     Err(SdkError::Api(body, _)) => match *body {
 //!         ListErrorBody::Unauthorized(details) => eprintln!("unauthorized: {:?}", details),
 //!     },
-//!     Err(SdkError::Unexpected(status, body)) => {
-//!         eprintln!("unexpected {} response: {}", status, body);
+//!     Err(SdkError::Unexpected(body, response)) => {
+//!         eprintln!("unexpected {} response: {}", response.status(), body);
 //!     }
 //!     Err(SdkError::Network(err)) => panic!("network error: {}", err),
+//!     Err(SdkError::Auth(err)) => panic!("authorization error: {}", err),
+//!     Err(SdkError::Decode(err)) => eprintln!("unexpected body at `{}`", err.path()),
+//!     Err(SdkError::Validation(err)) => eprintln!("{}", err),
 //! }
 //! # }
 //! ```
 //!
+//! Generic code can classify failures without matching on each operation's error body
+//! using [`SdkError::is_retryable`], [`SdkError::is_auth_error`],
+//! [`SdkError::is_not_found`], [`SdkError::is_conflict`] and
+//! [`SdkError::is_rate_limited`]. They are available for every error body implementing
+//! [`ErrorBody`], which all generated `*ErrorBody` enums do.
+//!
 //! ## Features
 //!
 //! - **chrono** (default): Use chrono for datetime types
 //! - **jiff**: Use jiff for datetime types (mutually exclusive with chrono)
 //! - **reqwest-default-tls** (default): Use reqwest's default TLS backend
 //! - **reqwest-rustls-tls**: Use reqwest's rustls TLS backend
+//! - **rust_decimal**: Convert [`Amount`] from and to `rust_decimal::Decimal`
+//! - **webhooks**: Parse SumUp callbacks and receive them with a tower service, see `webhooks`
+//! - **zeroize**: Wipe credentials held in [`Secret`] from memory on drop
 //!
 //! ## Resources
 //!
@@ -172,12 +222,28 @@ mod string_or_number;
 
 pub mod api_version;
 pub mod auth;
+pub mod callback;
 pub mod client;
+pub mod currency;
 pub mod datetime;
 pub mod error;
+pub mod middleware;
+pub mod money;
 pub mod nullable;
+pub mod oauth;
+pub mod pagination;
+pub mod reader_payment;
+pub mod reader_status;
+pub mod request_options;
+pub mod response;
+pub mod retry;
+pub mod scope;
 pub mod secret;
+pub mod validation;
 pub mod version;
+pub mod wait;
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
 
 #[allow(deprecated)]
 #[allow(clippy::large_enum_variant)]
@@ -186,7 +252,16 @@ pub mod resources;
 pub use crate::resources::*;
 pub use auth::Authorization;
 pub use client::Client;
-pub use error::{SdkError, SdkResult, UnknownApiBody};
+pub use error::{DecodeError, ErrorBody, SdkError, SdkResult, UnknownApiBody};
+pub use middleware::Middleware;
+pub use money::{Amount, Money};
 pub use nullable::Nullable;
+pub use pagination::Paginator;
+pub use reader_payment::ReaderPayment;
+pub use request_options::RequestOptions;
+pub use response::ResponseMeta;
+pub use retry::RetryPolicy;
+pub use scope::Scope;
 pub use secret::Secret;
 pub use version::VERSION;
+pub use wait::WaitOptions;
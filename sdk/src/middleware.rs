@@ -0,0 +1,95 @@
+//! Hooks for inspecting and modifying HTTP traffic.
+//!
+//! Implement [`Middleware`] and register it with
+//! [`Client::with_middleware`](crate::Client::with_middleware) to add correlation IDs,
+//! logging, metrics or custom headers to every request the SDK sends.
+//!
+//! Middleware runs in registration order for outgoing requests and in reverse order for
+//! responses and errors. When a [`RetryPolicy`](crate::RetryPolicy) is configured, the hooks
+//! run once per attempt.
+//!
+//! ```
+//! use sumup::middleware::Middleware;
+//!
+//! struct CorrelationId;
+//!
+//! impl Middleware for CorrelationId {
+//!     fn on_request(&self, request: &mut reqwest::Request) {
+//!         request.headers_mut().insert(
+//!             "X-Correlation-Id",
+//!             reqwest::header::HeaderValue::from_static("3f1c8a52"),
+//!         );
+//!     }
+//! }
+//!
+//! let client = sumup::Client::default().with_middleware(CorrelationId);
+//! ```
+
+use std::sync::Arc;
+
+/// A hook invoked around every HTTP request sent by the [`Client`](crate::Client).
+///
+/// All methods have empty default implementations, so implementors only override the
+/// stages they are interested in.
+pub trait Middleware: Send + Sync + 'static {
+    /// Called before the request is sent. The request can be modified in place.
+    fn on_request(&self, request: &mut reqwest::Request) {
+        let _ = request;
+    }
+
+    /// Called after a response has been received, before its body is read.
+    fn on_response(&self, response: &mut reqwest::Response) {
+        let _ = response;
+    }
+
+    /// Called when the request failed without a response, for example on a connection error.
+    fn on_error(&self, error: &reqwest::Error) {
+        let _ = error;
+    }
+}
+
+/// Ordered list of middleware registered on a client.
+#[derive(Clone, Default)]
+pub(crate) struct Pipeline {
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl Pipeline {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middleware.push(middleware);
+    }
+
+    /// Runs the request through the middleware and sends it with `http_client`.
+    pub(crate) async fn execute(
+        &self,
+        http_client: &reqwest::Client,
+        mut request: reqwest::Request,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        for middleware in &self.middleware {
+            middleware.on_request(&mut request);
+        }
+
+        match http_client.execute(request).await {
+            Ok(mut response) => {
+                for middleware in self.middleware.iter().rev() {
+                    middleware.on_response(&mut response);
+                }
+                Ok(response)
+            }
+            Err(error) => {
+                for middleware in self.middleware.iter().rev() {
+                    middleware.on_error(&error);
+                }
+                Err(error)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("len", &self.middleware.len())
+            .finish()
+    }
+}
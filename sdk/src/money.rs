@@ -0,0 +1,954 @@
+//! Exact monetary amounts.
+//!
+//! The SumUp API expresses amounts as decimal numbers in major units, e.g. `10.1` for
+//! 10.10 EUR. Binary floating point cannot represent most of these values exactly, so the
+//! SDK decodes them into [`Amount`], an exact decimal, and offers [`Money`], an integer
+//! number of minor units tagged with its [`Currency`]:
+//!
+//! ```
+//! use sumup::money::{Amount, Money};
+//! use sumup::Currency;
+//!
+//! let amount: Amount = "1234567.89".parse()?;
+//! let price = Money::from_amount(amount, Currency::EUR)?;
+//! assert_eq!(price.minor_units(), 123_456_789);
+//!
+//! let total = price.checked_add(&Money::from_minor_units(11, Currency::EUR))?;
+//! assert_eq!(total.amount().to_string(), "1234568.00");
+//! assert_eq!(total.to_string(), "1234568.00 EUR");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Reader checkouts describe amounts as an integer `value` with a `minor_unit` instead.
+//! [`Money`] converts from and to them, so both checkout flows share one amount model:
+//!
+//! ```
+//! use sumup::readers::CreateCheckoutRequestTotalAmount;
+//! use sumup::{Currency, Money};
+//!
+//! let total: CreateCheckoutRequestTotalAmount =
+//!     Money::from_minor_units(1000, Currency::EUR).into();
+//! assert_eq!((total.value, total.minor_unit), (1000, 2));
+//! ```
+//!
+//! Enable the `rust_decimal` feature to convert amounts from and to
+//! [`rust_decimal::Decimal`].
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::resources::common::Currency;
+
+/// Largest number of decimal places an [`Amount`] can hold.
+pub const MAX_SCALE: u32 = 18;
+
+/// An exact decimal amount, such as the `amount` of a checkout in major units.
+///
+/// An amount is an integer `mantissa` scaled by `10^-scale`, so `10.10` is stored as
+/// `1010` with scale `2`. Amounts compare by value: `10.1 == 10.10`. The scale is kept
+/// for display and serialization.
+///
+/// Amounts serialize as JSON numbers and deserialize from JSON numbers or numeric strings.
+/// JSON numbers are exact up to 15 significant digits, which covers every amount the API
+/// accepts.
+#[derive(Clone, Copy, Default)]
+pub struct Amount {
+    mantissa: i64,
+    scale: u32,
+}
+
+impl Amount {
+    /// The amount zero.
+    pub const ZERO: Amount = Amount {
+        mantissa: 0,
+        scale: 0,
+    };
+
+    /// Creates the amount `mantissa * 10^-scale`, e.g. `Amount::new(1010, 2)` for `10.10`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` exceeds [`MAX_SCALE`].
+    pub const fn new(mantissa: i64, scale: u32) -> Self {
+        assert!(scale <= MAX_SCALE, "amount scale exceeds MAX_SCALE");
+        Self { mantissa, scale }
+    }
+
+    /// Returns the integer mantissa, e.g. `1010` for `10.10`.
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    /// Returns the number of decimal places, e.g. `2` for `10.10`.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Returns whether the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Returns whether the amount is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Returns the same value with `scale` decimal places, or `None` if that would drop
+    /// non-zero digits or overflow.
+    pub fn rescale(&self, scale: u32) -> Option<Amount> {
+        if scale > MAX_SCALE {
+            return None;
+        }
+        let mantissa = if scale >= self.scale {
+            self.mantissa
+                .checked_mul(10i64.checked_pow(scale - self.scale)?)?
+        } else {
+            let divisor = 10i64.pow(self.scale - scale);
+            if self.mantissa % divisor != 0 {
+                return None;
+            }
+            self.mantissa / divisor
+        };
+        Some(Amount { mantissa, scale })
+    }
+
+    /// Returns the same value without trailing zero decimal places, e.g. `10.1` for `10.10`.
+    pub fn normalize(&self) -> Amount {
+        let mut amount = *self;
+        while amount.scale > 0 && amount.mantissa % 10 == 0 {
+            amount.mantissa /= 10;
+            amount.scale -= 1;
+        }
+        amount
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    pub fn checked_add(self, other: Amount) -> Option<Amount> {
+        let (left, right, scale) = align(self, other)?;
+        Some(Amount {
+            mantissa: left.checked_add(right)?,
+            scale,
+        })
+    }
+
+    /// Subtracts `other`, returning `None` on overflow.
+    pub fn checked_sub(self, other: Amount) -> Option<Amount> {
+        let (left, right, scale) = align(self, other)?;
+        Some(Amount {
+            mantissa: left.checked_sub(right)?,
+            scale,
+        })
+    }
+
+    /// Multiplies the amount by an integer, e.g. a quantity, returning `None` on overflow.
+    pub fn checked_mul(self, factor: i64) -> Option<Amount> {
+        Some(Amount {
+            mantissa: self.mantissa.checked_mul(factor)?,
+            scale: self.scale,
+        })
+    }
+
+    /// Negates the amount, returning `None` on overflow.
+    pub fn checked_neg(self) -> Option<Amount> {
+        Some(Amount {
+            mantissa: self.mantissa.checked_neg()?,
+            scale: self.scale,
+        })
+    }
+
+    /// Sums the amounts, returning `None` on overflow.
+    pub fn checked_sum<I: IntoIterator<Item = Amount>>(amounts: I) -> Option<Amount> {
+        amounts
+            .into_iter()
+            .try_fold(Amount::ZERO, |total, amount| total.checked_add(amount))
+    }
+
+    /// Returns the nearest `f64`, for display or statistics only.
+    pub fn to_f64(&self) -> f64 {
+        // Formatting and parsing yields the closest `f64`, unlike dividing by 10^scale.
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+}
+
+/// Brings both amounts to the larger of their scales.
+fn align(left: Amount, right: Amount) -> Option<(i64, i64, u32)> {
+    let scale = left.scale.max(right.scale);
+    Some((
+        left.rescale(scale)?.mantissa,
+        right.rescale(scale)?.mantissa,
+        scale,
+    ))
+}
+
+impl PartialEq for Amount {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Amount {}
+
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Amount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Every mantissa rescaled to MAX_SCALE fits into an i128.
+        let widen =
+            |amount: &Amount| i128::from(amount.mantissa) * 10i128.pow(MAX_SCALE - amount.scale);
+        widen(self).cmp(&widen(other))
+    }
+}
+
+impl Hash for Amount {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+        normalized.mantissa.hash(state);
+        normalized.scale.hash(state);
+    }
+}
+
+impl fmt::Debug for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Amount({self})")
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        if scale == 0 {
+            return write!(f, "{sign}{digits}");
+        }
+        let digits = format!("{digits:0>width$}", width = scale + 1);
+        let (units, fraction) = digits.split_at(digits.len() - scale);
+        write!(f, "{sign}{units}.{fraction}")
+    }
+}
+
+/// Error returned when a string is not a valid [`Amount`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAmountError {
+    input: String,
+}
+
+impl fmt::Display for ParseAmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid amount `{}`", self.input)
+    }
+}
+
+impl std::error::Error for ParseAmountError {}
+
+impl FromStr for Amount {
+    type Err = ParseAmountError;
+
+    /// Parses a plain decimal such as `10`, `-0.5` or `1234567.89`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseAmountError {
+            input: input.to_string(),
+        };
+        let (negative, unsigned) = match input.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let (units, fraction) = match unsigned.split_once('.') {
+            Some((_, "")) => return Err(error()),
+            Some(parts) => parts,
+            None => (unsigned, ""),
+        };
+        let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if units.is_empty() || !is_digits(units) || !is_digits(fraction) {
+            return Err(error());
+        }
+        let scale = u32::try_from(fraction.len()).map_err(|_| error())?;
+        if scale > MAX_SCALE {
+            return Err(error());
+        }
+        let magnitude: i64 = format!("{units}{fraction}").parse().map_err(|_| error())?;
+        Ok(Amount {
+            mantissa: if negative { -magnitude } else { magnitude },
+            scale,
+        })
+    }
+}
+
+impl From<i64> for Amount {
+    fn from(value: i64) -> Self {
+        Amount::new(value, 0)
+    }
+}
+
+impl From<i32> for Amount {
+    fn from(value: i32) -> Self {
+        Amount::from(i64::from(value))
+    }
+}
+
+impl From<u32> for Amount {
+    fn from(value: u32) -> Self {
+        Amount::from(i64::from(value))
+    }
+}
+
+impl TryFrom<f64> for Amount {
+    type Error = ParseAmountError;
+
+    /// Converts the shortest decimal representation of `value`, so `10.1_f64` becomes
+    /// exactly `10.1`.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        // `Display` for `f64` prints the shortest decimal that parses back to the same
+        // value, without an exponent.
+        value.to_string().parse()
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result does not fit; use [`Amount::checked_add`] to handle overflow.
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, other: Amount) -> Amount {
+        self.checked_add(other).expect("amount overflow")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result does not fit; use [`Amount::checked_add`] to handle overflow.
+impl AddAssign for Amount {
+    fn add_assign(&mut self, other: Amount) {
+        *self = *self + other;
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result does not fit; use [`Amount::checked_sub`] to handle overflow.
+impl Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, other: Amount) -> Amount {
+        self.checked_sub(other).expect("amount overflow")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result does not fit; use [`Amount::checked_sub`] to handle overflow.
+impl SubAssign for Amount {
+    fn sub_assign(&mut self, other: Amount) {
+        *self = *self - other;
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result does not fit; use [`Amount::checked_mul`] to handle overflow.
+impl Mul<i64> for Amount {
+    type Output = Amount;
+
+    fn mul(self, factor: i64) -> Amount {
+        self.checked_mul(factor).expect("amount overflow")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result does not fit; use [`Amount::checked_neg`] to handle overflow.
+impl Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Amount {
+        self.checked_neg().expect("amount overflow")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the total does not fit; use [`Amount::checked_sum`] to handle overflow.
+impl Sum for Amount {
+    fn sum<I: Iterator<Item = Amount>>(iter: I) -> Amount {
+        iter.fold(Amount::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Amount> for Amount {
+    fn sum<I: Iterator<Item = &'a Amount>>(iter: I) -> Amount {
+        iter.copied().sum()
+    }
+}
+
+impl serde::Serialize for Amount {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.scale == 0 {
+            serializer.serialize_i64(self.mantissa)
+        } else {
+            // Serializers print the shortest representation of the closest `f64`, which is
+            // the decimal itself for up to 15 significant digits.
+            serializer.serialize_f64(self.to_f64())
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Amount {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AmountVisitor;
+
+        impl serde::de::Visitor<'_> for AmountVisitor {
+            type Value = Amount;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a decimal number or a string containing one")
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Amount, E> {
+                Ok(Amount::from(value))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Amount, E> {
+                i64::try_from(value)
+                    .map(Amount::from)
+                    .map_err(|_| E::custom("amount out of range"))
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Amount, E> {
+                Amount::try_from(value).map_err(E::custom)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Amount, E> {
+                value.trim().parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<Amount> for rust_decimal::Decimal {
+    fn from(amount: Amount) -> Self {
+        rust_decimal::Decimal::new(amount.mantissa, amount.scale)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<rust_decimal::Decimal> for Amount {
+    type Error = ParseAmountError;
+
+    /// Keeps the scale of the decimal where possible and drops trailing zeros otherwise.
+    /// Fails if the value needs more than [`MAX_SCALE`] decimal places or its mantissa does
+    /// not fit into an `i64`.
+    fn try_from(value: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        let convert = |decimal: rust_decimal::Decimal| {
+            if decimal.scale() > MAX_SCALE {
+                return None;
+            }
+            let mantissa = i64::try_from(decimal.mantissa()).ok()?;
+            Some(Amount {
+                mantissa,
+                scale: decimal.scale(),
+            })
+        };
+        convert(value)
+            .or_else(|| convert(value.normalize()))
+            .ok_or_else(|| ParseAmountError {
+                input: value.to_string(),
+            })
+    }
+}
+
+/// An amount of money as an integer number of minor units of its currency.
+///
+/// `Money::from_minor_units(1010, Currency::EUR)` is 10.10 EUR. Arithmetic is exact and
+/// only defined between amounts of the same currency.
+///
+/// Money serializes as `{"amount": 10.1, "currency": "EUR"}`, the shape used throughout
+/// the SumUp API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Money {
+    minor_units: i64,
+    currency: Currency,
+}
+
+impl Money {
+    /// Creates money from an integer number of minor units, e.g. cents.
+    pub fn from_minor_units(minor_units: i64, currency: Currency) -> Self {
+        Self {
+            minor_units,
+            currency,
+        }
+    }
+
+    /// Creates money from an amount in major units, as used by the API.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows, e.g. `10.001`
+    /// EUR.
+    pub fn from_amount(amount: Amount, currency: Currency) -> Result<Self, MoneyError> {
+        let minor_units = amount
+            .rescale(currency.minor_unit())
+            .ok_or_else(|| MoneyError::Precision {
+                amount,
+                currency: currency.clone(),
+            })?
+            .mantissa;
+        Ok(Self {
+            minor_units,
+            currency,
+        })
+    }
+
+    /// Creates money from an integer `value` with `minor_unit` decimal places, the
+    /// representation used by reader checkouts: EUR 10.10 is value `1010` with minor unit
+    /// `2`.
+    ///
+    /// The value is converted to the minor unit of the currency and fails like
+    /// [`Money::from_amount`] if that would lose precision.
+    pub fn from_scaled_value(
+        value: i64,
+        minor_unit: u32,
+        currency: Currency,
+    ) -> Result<Self, MoneyError> {
+        if minor_unit > MAX_SCALE {
+            return Err(MoneyError::InvalidMinorUnit(i64::from(minor_unit)));
+        }
+        Money::from_amount(Amount::new(value, minor_unit), currency)
+    }
+
+    /// Returns the zero amount of the currency.
+    pub fn zero(currency: Currency) -> Self {
+        Self::from_minor_units(0, currency)
+    }
+
+    /// Returns the number of minor units, e.g. `1010` for 10.10 EUR.
+    pub fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    /// Returns the currency.
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// Returns the amount in major units with the decimal places of the currency, e.g.
+    /// `10.10` for 10.10 EUR.
+    pub fn amount(&self) -> Amount {
+        Amount::new(self.minor_units, self.currency.minor_unit())
+    }
+
+    /// Formats the money for a BCP 47 locale such as `de-DE`, e.g. `1.234,56 €`.
+    ///
+    /// Covers the separators and symbol placement of the languages of the countries SumUp
+    /// operates in; other locales use English conventions such as `€1,234.56`.
+    pub fn format(&self, locale: &str) -> String {
+        crate::currency::format_amount(self.amount(), &self.currency, locale)
+    }
+
+    /// Returns whether the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.minor_units == 0
+    }
+
+    /// Returns whether the amount is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.minor_units < 0
+    }
+
+    /// Adds money of the same currency.
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.same_currency(other)?;
+        self.with_minor_units(self.minor_units.checked_add(other.minor_units))
+    }
+
+    /// Subtracts money of the same currency.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.same_currency(other)?;
+        self.with_minor_units(self.minor_units.checked_sub(other.minor_units))
+    }
+
+    /// Multiplies the money by an integer, e.g. a quantity.
+    pub fn checked_mul(&self, factor: i64) -> Result<Money, MoneyError> {
+        self.with_minor_units(self.minor_units.checked_mul(factor))
+    }
+
+    /// Negates the money, e.g. to turn a payment into a refund.
+    pub fn checked_neg(&self) -> Result<Money, MoneyError> {
+        self.with_minor_units(self.minor_units.checked_neg())
+    }
+
+    fn same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch {
+                expected: self.currency.clone(),
+                found: other.currency.clone(),
+            })
+        }
+    }
+
+    fn with_minor_units(&self, minor_units: Option<i64>) -> Result<Money, MoneyError> {
+        minor_units
+            .map(|minor_units| Money::from_minor_units(minor_units, self.currency.clone()))
+            .ok_or(MoneyError::Overflow)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount(), self.currency)
+    }
+}
+
+/// Error returned when a string is not a valid [`Money`] value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMoneyError {
+    input: String,
+}
+
+impl fmt::Display for ParseMoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid money `{}`", self.input)
+    }
+}
+
+impl std::error::Error for ParseMoneyError {}
+
+impl FromStr for Money {
+    type Err = ParseMoneyError;
+
+    /// Parses an amount and a currency code separated by whitespace, in either order, e.g.
+    /// `10.10 EUR` or `EUR 10.10`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseMoneyError {
+            input: input.to_string(),
+        };
+        let mut parts = input.split_whitespace();
+        let (Some(first), Some(second), None) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(error());
+        };
+        let (amount, currency) = match first.parse::<Currency>() {
+            Ok(currency) => (second, currency),
+            Err(_) => (first, second.parse().map_err(|_| error())?),
+        };
+        let amount = amount.parse().map_err(|_| error())?;
+        Money::from_amount(amount, currency).map_err(|_| error())
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result does not fit; use [`Money::checked_neg`] to handle overflow.
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        self.checked_neg().expect("money overflow")
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MoneyRepr {
+    amount: Amount,
+    currency: Currency,
+}
+
+impl serde::Serialize for Money {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MoneyRepr {
+            amount: self.amount(),
+            currency: self.currency.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Money {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = MoneyRepr::deserialize(deserializer)?;
+        Money::from_amount(repr.amount, repr.currency).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Error returned by [`Money`] conversions and arithmetic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoneyError {
+    /// The amount has more decimal places than the currency allows.
+    Precision { amount: Amount, currency: Currency },
+    /// The operands have different currencies.
+    CurrencyMismatch { expected: Currency, found: Currency },
+    /// The currency code of a `{value, minor_unit}` amount is not a valid ISO 4217 code.
+    InvalidCurrency(String),
+    /// The minor unit of a `{value, minor_unit}` amount is negative or too large.
+    InvalidMinorUnit(i64),
+    /// The result does not fit into 64-bit minor units.
+    Overflow,
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoneyError::Precision { amount, currency } => {
+                write!(f, "{amount} has more decimal places than {currency} allows")
+            }
+            MoneyError::CurrencyMismatch { expected, found } => {
+                write!(f, "currency mismatch: expected {expected}, found {found}")
+            }
+            MoneyError::InvalidCurrency(code) => write!(f, "invalid currency code `{code}`"),
+            MoneyError::InvalidMinorUnit(minor_unit) => {
+                write!(f, "invalid minor unit {minor_unit}")
+            }
+            MoneyError::Overflow => f.write_str("money overflow"),
+        }
+    }
+}
+
+impl std::error::Error for MoneyError {}
+
+macro_rules! impl_minor_unit_amount {
+    ($($amount:ty),*) => {$(
+        impl From<Money> for $amount {
+            fn from(money: Money) -> Self {
+                Self {
+                    value: money.minor_units,
+                    minor_unit: i64::from(money.currency.minor_unit()),
+                    currency: money.currency.code().to_string(),
+                }
+            }
+        }
+
+        impl TryFrom<$amount> for Money {
+            type Error = MoneyError;
+
+            fn try_from(amount: $amount) -> Result<Self, Self::Error> {
+                let currency = amount
+                    .currency
+                    .parse()
+                    .map_err(|_| MoneyError::InvalidCurrency(amount.currency.clone()))?;
+                let minor_unit = u32::try_from(amount.minor_unit)
+                    .map_err(|_| MoneyError::InvalidMinorUnit(amount.minor_unit))?;
+                Money::from_scaled_value(amount.value, minor_unit, currency)
+            }
+        }
+    )*};
+}
+
+// Reader checkouts describe amounts as `{currency, minor_unit, value}`.
+impl_minor_unit_amount!(
+    crate::resources::readers::CreateCheckoutRequestTotalAmount,
+    crate::resources::readers::GetReaderCheckoutResponseDataTotalAmount
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(value: &str) -> Amount {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays_exact_decimals() {
+        assert_eq!(amount("1234567.89"), Amount::new(123_456_789, 2));
+        assert_eq!(amount("1234567.89").to_string(), "1234567.89");
+        assert_eq!(amount("-0.05").to_string(), "-0.05");
+        assert_eq!(amount("+7").to_string(), "7");
+        for invalid in [
+            "",
+            "-",
+            ".5",
+            "1.",
+            "1,5",
+            "1e3",
+            "1.2.3",
+            "0.0000000000000000001",
+        ] {
+            assert!(invalid.parse::<Amount>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn compares_by_value() {
+        assert_eq!(amount("10.1"), amount("10.10"));
+        assert!(amount("10.09") < amount("10.1"));
+        assert_eq!(amount("10.10").normalize().scale(), 1);
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(amount("10.1"));
+        assert!(set.contains(&amount("10.100")));
+    }
+
+    #[test]
+    fn arithmetic_is_exact() {
+        // 0.1 + 0.2 != 0.3 with floats.
+        assert_eq!(amount("0.1") + amount("0.2"), amount("0.3"));
+        assert_eq!(amount("10") - amount("0.01"), amount("9.99"));
+        assert_eq!(amount("19.99") * 3, amount("59.97"));
+        assert_eq!(
+            [amount("1.10"), amount("2.2")].iter().sum::<Amount>(),
+            amount("3.3")
+        );
+        assert_eq!(Amount::new(i64::MAX, 0).checked_add(Amount::from(1)), None);
+        assert_eq!(Amount::new(i64::MIN, 0).checked_neg(), None);
+        assert_eq!(
+            Amount::checked_sum([amount("1.10"), amount("2.2")]),
+            Some(amount("3.3"))
+        );
+        assert_eq!(
+            Amount::checked_sum([Amount::new(i64::MAX, 0), Amount::from(1)]),
+            None
+        );
+    }
+
+    #[test]
+    fn serde_round_trips_exactly() {
+        let decoded: Vec<Amount> =
+            serde_json::from_str(r#"[1234567.89, 10, "0.30", 0.1]"#).unwrap();
+        assert_eq!(
+            decoded,
+            [
+                amount("1234567.89"),
+                amount("10"),
+                amount("0.3"),
+                amount("0.1")
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&decoded).unwrap(),
+            "[1234567.89,10,0.3,0.1]"
+        );
+        assert!(serde_json::from_str::<Amount>(r#""ten""#).is_err());
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn converts_from_and_to_rust_decimal() {
+        let decimal = rust_decimal::Decimal::from(amount("1234567.89"));
+        assert_eq!(decimal.to_string(), "1234567.89");
+        assert_eq!(Amount::try_from(decimal), Ok(amount("1234567.89")));
+
+        // Trailing zeros beyond `MAX_SCALE` are dropped instead of panicking.
+        let padded = rust_decimal::Decimal::new(1_500_000_000_000_000_000, 20);
+        assert_eq!(Amount::try_from(padded), Ok(amount("0.015")));
+        assert!(Amount::try_from(rust_decimal::Decimal::new(1, 20)).is_err());
+        assert!(Amount::try_from(rust_decimal::Decimal::MAX).is_err());
+    }
+
+    #[test]
+    fn money_converts_between_major_and_minor_units() {
+        let money = Money::from_amount(amount("10.1"), Currency::EUR).unwrap();
+        assert_eq!(money.minor_units(), 1010);
+        assert_eq!(money.amount().to_string(), "10.10");
+        assert_eq!(money.to_string(), "10.10 EUR");
+
+        let pesos = Money::from_minor_units(5000, Currency::CLP);
+        assert_eq!(pesos.amount(), amount("5000"));
+        assert!(matches!(
+            Money::from_amount(amount("1.5"), Currency::CLP),
+            Err(MoneyError::Precision { .. })
+        ));
+    }
+
+    #[test]
+    fn money_parses_and_formats() {
+        let money: Money = "1234.5 eur".parse().unwrap();
+        assert_eq!(money, Money::from_minor_units(123_450, Currency::EUR));
+        assert_eq!("EUR 1234.50".parse::<Money>(), Ok(money.clone()));
+        assert!("1234.567 EUR".parse::<Money>().is_err());
+        assert!("1234.5".parse::<Money>().is_err());
+        assert_eq!(money.format("de-DE").replace('\u{a0}', " "), "1.234,50 €");
+        assert_eq!(money.format("en-IE"), "€1,234.50");
+    }
+
+    #[test]
+    fn money_converts_from_and_to_reader_amounts() {
+        use crate::resources::readers::CreateCheckoutRequestTotalAmount;
+
+        let total =
+            CreateCheckoutRequestTotalAmount::from(Money::from_minor_units(1000, Currency::EUR));
+        assert_eq!(total.currency, "EUR");
+        assert_eq!((total.value, total.minor_unit), (1000, 2));
+        assert_eq!(
+            Money::try_from(total),
+            Ok(Money::from_minor_units(1000, Currency::EUR))
+        );
+
+        let pesos = CreateCheckoutRequestTotalAmount {
+            currency: "COP".to_string(),
+            minor_unit: 2,
+            value: 500_000,
+        };
+        assert_eq!(
+            Money::try_from(pesos),
+            Ok(Money::from_minor_units(5000, Currency::COP))
+        );
+        assert!(matches!(
+            Money::from_scaled_value(1, 2, Currency::HUF),
+            Err(MoneyError::Precision { .. })
+        ));
+    }
+
+    #[test]
+    fn money_arithmetic_requires_same_currency() {
+        let euros = Money::from_minor_units(1000, Currency::EUR);
+        assert_eq!(
+            euros.checked_sub(&Money::from_minor_units(1, Currency::EUR)),
+            Ok(Money::from_minor_units(999, Currency::EUR))
+        );
+        assert_eq!(
+            euros.checked_add(&Money::from_minor_units(1, Currency::GBP)),
+            Err(MoneyError::CurrencyMismatch {
+                expected: Currency::EUR,
+                found: Currency::GBP,
+            })
+        );
+        assert_eq!(euros.checked_mul(i64::MAX), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn money_negation_reports_overflow() {
+        assert_eq!(
+            -Money::from_minor_units(1000, Currency::EUR),
+            Money::from_minor_units(-1000, Currency::EUR)
+        );
+        let minimum = Money::from_minor_units(i64::MIN, Currency::EUR);
+        assert_eq!(minimum.checked_neg(), Err(MoneyError::Overflow));
+        assert!(std::panic::catch_unwind(|| -minimum).is_err());
+    }
+
+    #[test]
+    fn generated_amounts_pair_with_their_currency() {
+        let checkout: crate::resources::checkouts::Checkout =
+            serde_json::from_str(r#"{"amount": 10.1, "currency": "EUR"}"#).unwrap();
+        assert_eq!(
+            checkout.amount_money(),
+            Some(Ok(Money::from_minor_units(1010, Currency::EUR)))
+        );
+        let checkout: crate::resources::checkouts::Checkout =
+            serde_json::from_str(r#"{"amount": 10.1}"#).unwrap();
+        assert_eq!(checkout.amount_money(), None);
+
+        let event: crate::resources::receipts::ReceiptEvent =
+            serde_json::from_str(r#"{"amount": "10.10"}"#).unwrap();
+        assert_eq!(event.amount, Some(amount("10.10")));
+    }
+
+    #[test]
+    fn money_serializes_as_amount_and_currency() {
+        let money = Money::from_minor_units(123_456_789, Currency::EUR);
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":1234567.89,"currency":"EUR"}"#);
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), money);
+    }
+}
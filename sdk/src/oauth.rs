@@ -0,0 +1,348 @@
+//! OAuth 2.0 authorization-code flow with PKCE.
+//!
+//! [`OAuthClient`] covers the steps needed to act on behalf of a SumUp user:
+//!
+//! 1. [`OAuthClient::authorization_request`] builds the authorize URL together with a
+//!    random `state` and PKCE verifier. Redirect the user to the URL and keep the state
+//!    and verifier, e.g. in a cookie.
+//! 2. When the user is redirected back, check the returned state with [`verify_state`] and
+//!    exchange the code with [`OAuthClient::exchange_code`].
+//! 3. Authorize a [`Client`](crate::Client) with the resulting [`TokenSet`] using
+//!    [`OAuthClient::authorize_client`]. Access tokens are refreshed automatically.
+//!
+//! ```no_run
+//! use sumup::oauth::{verify_state, OAuthClient, Scope};
+//!
+//! # async fn run(callback_code: &str, callback_state: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! let oauth = OAuthClient::new("client-id", "client-secret", "https://example.com/callback");
+//!
+//! let request = oauth.authorization_request([Scope::Payments, Scope::TransactionsHistory])?;
+//! // Redirect the user to `request.url()` and store `request.state()` and
+//! // `request.pkce_verifier()` until they come back.
+//!
+//! verify_state(request.state(), callback_state)?;
+//! let tokens = oauth
+//!     .exchange_code(callback_code, request.pkce_verifier())
+//!     .await?;
+//! let client = oauth.authorize_client(sumup::Client::default(), &tokens);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, SystemTime};
+
+use base64::Engine as _;
+use sha2::Digest as _;
+
+use crate::auth::{
+    AuthError, Authorization, CachedToken, RefreshTokenProvider, TokenEndpoint, TOKEN_URL,
+};
+pub use crate::scope::Scope;
+use crate::secret::Secret;
+
+/// Default OAuth 2.0 authorization endpoint of the SumUp API.
+pub const AUTHORIZE_URL: &str = "https://api.sumup.com/authorize";
+
+/// Tokens returned by a successful code exchange.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TokenSet {
+    /// Access token used to authorize API requests.
+    pub access_token: Secret,
+    /// Refresh token used to obtain new access tokens, if issued.
+    pub refresh_token: Option<Secret>,
+    /// Point in time at which the access token expires, if known.
+    pub expires_at: Option<SystemTime>,
+    /// Scopes granted by the user.
+    pub scopes: Vec<Scope>,
+}
+
+impl TokenSet {
+    /// Returns the remaining lifetime of the access token, if known.
+    pub fn expires_in(&self) -> Option<Duration> {
+        self.expires_at.map(|expires_at| {
+            expires_at
+                .duration_since(SystemTime::now())
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// Authorize URL together with the secrets needed to complete the flow.
+#[derive(Debug, Clone)]
+pub struct AuthorizationRequest {
+    url: String,
+    state: Secret,
+    pkce_verifier: Secret,
+}
+
+impl AuthorizationRequest {
+    /// Returns the URL to redirect the user to.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the random state to compare against the one returned in the callback.
+    pub fn state(&self) -> &str {
+        self.state.secret()
+    }
+
+    /// Returns the PKCE verifier to send with the code exchange.
+    pub fn pkce_verifier(&self) -> &str {
+        self.pkce_verifier.secret()
+    }
+}
+
+/// Client for the SumUp OAuth 2.0 authorization-code flow.
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    client_id: String,
+    client_secret: Secret,
+    redirect_uri: String,
+    authorize_url: reqwest::Url,
+    token_url: String,
+    http_client: reqwest::Client,
+}
+
+impl OAuthClient {
+    /// Creates a client for the application with the given credentials and redirect URI.
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: Secret::new(client_secret),
+            redirect_uri: redirect_uri.into(),
+            authorize_url: reqwest::Url::parse(AUTHORIZE_URL).expect("valid default authorize URL"),
+            token_url: TOKEN_URL.to_string(),
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Overrides the authorization endpoint, defaults to [`AUTHORIZE_URL`].
+    ///
+    /// Fails with [`AuthError::Other`] when `authorize_url` is not a valid URL.
+    pub fn with_authorize_url(mut self, authorize_url: &str) -> Result<Self, AuthError> {
+        self.authorize_url =
+            reqwest::Url::parse(authorize_url).map_err(|err| AuthError::Other(Box::new(err)))?;
+        Ok(self)
+    }
+
+    /// Overrides the token endpoint, defaults to [`TOKEN_URL`].
+    pub fn with_token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.token_url = token_url.into();
+        self
+    }
+
+    /// Overrides the HTTP client used to call the token endpoint.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Builds the authorize URL for the given scopes with a fresh state and PKCE verifier.
+    ///
+    /// Fails with [`AuthError::Other`] when the OS cannot provide random bytes.
+    pub fn authorization_request(
+        &self,
+        scopes: impl IntoIterator<Item = Scope>,
+    ) -> Result<AuthorizationRequest, AuthError> {
+        let state = random_token()?;
+        let pkce_verifier = random_token()?;
+        let code_challenge = pkce_challenge(&pkce_verifier);
+        let scope = scopes
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut url = self.authorize_url.clone();
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", &self.redirect_uri)
+            .append_pair("scope", &scope)
+            .append_pair("state", &state)
+            .append_pair("code_challenge", &code_challenge)
+            .append_pair("code_challenge_method", "S256");
+
+        Ok(AuthorizationRequest {
+            url: url.into(),
+            state: Secret::new(state),
+            pkce_verifier: Secret::new(pkce_verifier),
+        })
+    }
+
+    /// Exchanges the authorization code returned in the callback for tokens.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        pkce_verifier: &str,
+    ) -> Result<TokenSet, AuthError> {
+        let response = self
+            .token_endpoint()
+            .request(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", self.redirect_uri.as_str()),
+                ("code_verifier", pkce_verifier),
+            ])
+            .await?;
+
+        Ok(TokenSet {
+            expires_at: response.expires_in.and_then(|expires_in| {
+                SystemTime::now().checked_add(Duration::from_secs(expires_in))
+            }),
+            scopes: response
+                .scope
+                .as_deref()
+                .map(Scope::parse_list)
+                .unwrap_or_default(),
+            access_token: response.access_token,
+            refresh_token: response.refresh_token,
+        })
+    }
+
+    /// Authorizes `client` with the tokens and restricts it to the granted scopes, if the
+    /// token endpoint reported them.
+    pub fn authorize_client(&self, client: crate::Client, tokens: &TokenSet) -> crate::Client {
+        let client = client.with_authorization(self.authorization(tokens));
+        if tokens.scopes.is_empty() {
+            client
+        } else {
+            client.with_granted_scopes(tokens.scopes.iter().cloned())
+        }
+    }
+
+    /// Builds an [`Authorization`] for the tokens.
+    ///
+    /// When a refresh token was issued, the access token is refreshed automatically with a
+    /// [`RefreshTokenProvider`]; otherwise the access token is used as-is.
+    pub fn authorization(&self, tokens: &TokenSet) -> Authorization {
+        match self.refresh_token_provider(tokens) {
+            Some(provider) => Authorization::token_provider(provider),
+            None => Authorization::AccessToken(tokens.access_token.clone()),
+        }
+    }
+
+    /// Builds the [`RefreshTokenProvider`] used by [`OAuthClient::authorization`], or `None`
+    /// when no refresh token was issued.
+    ///
+    /// Keep a clone of the provider, or register
+    /// [`RefreshTokenProvider::on_refresh_token_rotated`], to persist rotated refresh tokens.
+    pub fn refresh_token_provider(&self, tokens: &TokenSet) -> Option<RefreshTokenProvider> {
+        let refresh_token = tokens.refresh_token.as_ref()?;
+        let token = tokens
+            .expires_in()
+            .map(|expires_in| CachedToken::new(tokens.access_token.clone(), expires_in));
+        let provider = RefreshTokenProvider::seeded(
+            self.client_id.clone(),
+            self.client_secret.clone(),
+            refresh_token.clone(),
+            token,
+        )
+        .with_token_url(&self.token_url)
+        .with_http_client(self.http_client.clone());
+        Some(provider)
+    }
+
+    fn token_endpoint(&self) -> TokenEndpoint {
+        let mut endpoint = TokenEndpoint::new(self.client_id.clone(), self.client_secret.clone());
+        endpoint.token_url = self.token_url.clone();
+        endpoint.http_client = self.http_client.clone();
+        endpoint
+    }
+}
+
+/// Checks that the `state` returned in the callback matches the one that was sent.
+pub fn verify_state(expected: &str, received: &str) -> Result<(), AuthError> {
+    // Compare in constant time to not leak the expected state through timing.
+    let matches = expected.len() == received.len()
+        && expected
+            .bytes()
+            .zip(received.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0;
+    if matches {
+        Ok(())
+    } else {
+        Err(AuthError::StateMismatch)
+    }
+}
+
+/// Returns 32 random bytes encoded as URL-safe base64, as required for PKCE verifiers.
+fn random_token() -> Result<String, AuthError> {
+    let mut bytes = [0u8; 32];
+    getrandom::fill(&mut bytes).map_err(|err| AuthError::Other(Box::new(err)))?;
+    Ok(base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Derives the `S256` PKCE code challenge for the verifier.
+fn pkce_challenge(verifier: &str) -> String {
+    let digest = sha2::Sha256::digest(verifier.as_bytes());
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pkce_challenge_matches_rfc_7636_example() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn authorization_request_contains_pkce_parameters() {
+        let oauth = OAuthClient::new("client-id", "secret", "https://example.com/callback");
+        let request = oauth
+            .authorization_request([Scope::Payments, Scope::CheckoutsRead])
+            .unwrap();
+
+        let url = reqwest::Url::parse(request.url()).unwrap();
+        let query: std::collections::HashMap<_, _> = url.query_pairs().into_owned().collect();
+        assert_eq!(query["response_type"], "code");
+        assert_eq!(query["client_id"], "client-id");
+        assert_eq!(query["redirect_uri"], "https://example.com/callback");
+        assert_eq!(query["scope"], "payments checkouts.read");
+        assert_eq!(query["state"], request.state());
+        assert_eq!(
+            query["code_challenge"],
+            pkce_challenge(request.pkce_verifier())
+        );
+        assert_eq!(query["code_challenge_method"], "S256");
+        assert_eq!(request.pkce_verifier().len(), 43);
+    }
+
+    #[test]
+    fn verify_state_rejects_mismatches() {
+        assert!(verify_state("abc", "abc").is_ok());
+        assert!(matches!(
+            verify_state("abc", "abd"),
+            Err(AuthError::StateMismatch)
+        ));
+        assert!(verify_state("abc", "abcd").is_err());
+    }
+
+    #[test]
+    fn with_authorize_url_rejects_invalid_urls() {
+        let oauth = OAuthClient::new("client-id", "secret", "https://example.com/callback");
+        assert!(matches!(
+            oauth.clone().with_authorize_url("not a url"),
+            Err(AuthError::Other(_))
+        ));
+
+        let request = oauth
+            .with_authorize_url("https://auth.example.com/authorize")
+            .expect("valid URL")
+            .authorization_request([Scope::Payments])
+            .unwrap();
+        assert!(request
+            .url()
+            .starts_with("https://auth.example.com/authorize?"));
+    }
+}
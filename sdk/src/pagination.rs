@@ -0,0 +1,358 @@
+//! Auto-pagination of list operations.
+//!
+//! Paginated operations offer a `list_all` method returning a [`Paginator`], a
+//! [`Stream`] over the items of all pages. Pages are fetched lazily, one request at a
+//! time, as the stream is polled. Link-based operations such as the transaction history
+//! follow the `next` links; offset-based operations such as members and memberships
+//! advance the offset until `total_count` items have been returned.
+//!
+//! ```no_run
+//! use futures_util::TryStreamExt;
+//!
+//! # async fn run(client: sumup::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut transactions = client
+//!     .transactions()
+//!     .list_all("MH4H92C7", Default::default())
+//!     .with_max_items(500);
+//! while let Some(transaction) = transactions.try_next().await? {
+//!     println!("{:?}", transaction.transaction_code);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! To load everything into memory at once, use [`Paginator::collect_vec`]:
+//!
+//! ```no_run
+//! # async fn run(client: sumup::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let members = client
+//!     .members()
+//!     .list_all("MH4H92C7", Default::default())
+//!     .with_max_items(10_000)
+//!     .collect_vec()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::error::SdkResult;
+use crate::validation::{ValidationError, ViolationKind};
+
+/// A single page of items together with the cursor of the following page.
+pub(crate) struct Page<T, C> {
+    pub(crate) items: Vec<T>,
+    pub(crate) next: Option<C>,
+}
+
+type PageFuture<'a, T, E, C> = Pin<Box<dyn Future<Output = SdkResult<Page<T, C>, E>> + Send + 'a>>;
+type FetchPage<'a, T, E, C> = Box<dyn FnMut(Option<C>) -> PageFuture<'a, T, E, C> + Send + 'a>;
+
+/// A [`Stream`] over the items of a paginated list operation.
+///
+/// The stream ends after the last page, after [`Paginator::with_max_items`] items or after
+/// the first error.
+pub struct Paginator<'a, T, E, C = String> {
+    fetch: FetchPage<'a, T, E, C>,
+    pending: Option<PageFuture<'a, T, E, C>>,
+    buffer: VecDeque<T>,
+    cursor: Option<C>,
+    finished: bool,
+    remaining: Option<usize>,
+}
+
+impl<'a, T, E, C> Paginator<'a, T, E, C>
+where
+    C: Clone,
+{
+    /// Creates a paginator that starts at `cursor`, or at the first page when `None`.
+    pub(crate) fn new<F, Fut>(cursor: Option<C>, mut fetch: F) -> Self
+    where
+        F: FnMut(Option<C>) -> Fut + Send + 'a,
+        Fut: Future<Output = SdkResult<Page<T, C>, E>> + Send + 'a,
+    {
+        Self {
+            fetch: Box::new(move |cursor| Box::pin(fetch(cursor))),
+            pending: None,
+            buffer: VecDeque::new(),
+            cursor,
+            finished: false,
+            remaining: None,
+        }
+    }
+
+    /// Starts the stream at `cursor` instead of the initial cursor.
+    pub(crate) fn with_cursor(mut self, cursor: C) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Stops the stream after `max_items` items, without fetching further pages.
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.remaining = Some(max_items);
+        self
+    }
+
+    /// Returns the cursor of the next page to fetch, if there is one.
+    ///
+    /// Once all items of the current page have been consumed, the cursor can be stored to
+    /// resume later: link-based operations accept it in their `list_all_from` variant,
+    /// offset-based operations return the offset to set in the parameters.
+    pub fn cursor(&self) -> Option<&C> {
+        self.cursor.as_ref()
+    }
+
+    /// Collects the items of all pages into a vector, stopping at the first error.
+    ///
+    /// Combine with [`Paginator::with_max_items`] to cap the number of items loaded.
+    pub async fn collect_vec(mut self) -> SdkResult<Vec<T>, E> {
+        let mut items = Vec::new();
+        loop {
+            match std::future::poll_fn(|cx| Pin::new(&mut self).poll_next(cx)).await {
+                Some(item) => items.push(item?),
+                None => return Ok(items),
+            }
+        }
+    }
+}
+
+impl<'a, T, E> Paginator<'a, T, E, i64> {
+    /// Creates a paginator for an offset-based operation starting at `offset`.
+    ///
+    /// `fetch` loads the page at the given offset and returns its items together with the
+    /// total number of items, when the server reports it. Pagination stops once the total
+    /// is reached, on an empty page, or on a page with fewer than `limit` items.
+    pub(crate) fn offset<F, Fut>(offset: i64, limit: Option<i64>, mut fetch: F) -> Self
+    where
+        F: FnMut(i64) -> Fut + Send + 'a,
+        Fut: Future<Output = SdkResult<(Vec<T>, Option<i64>), E>> + Send + 'a,
+    {
+        Self::new(Some(offset), move |cursor: Option<i64>| {
+            let offset = cursor.unwrap_or_default();
+            let page = fetch(offset);
+            async move {
+                let (items, total_count) = page.await?;
+                let next = offset + items.len() as i64;
+                let exhausted = total_count.is_some_and(|total| next >= total)
+                    || limit.is_some_and(|limit| (items.len() as i64) < limit);
+                Ok(Page {
+                    next: (!exhausted).then_some(next),
+                    items,
+                })
+            }
+        })
+    }
+}
+
+impl<T, E, C> Unpin for Paginator<'_, T, E, C> {}
+
+impl<T, E, C> Stream for Paginator<'_, T, E, C>
+where
+    C: Clone,
+{
+    type Item = SdkResult<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.remaining == Some(0) {
+                return Poll::Ready(None);
+            }
+            if let Some(item) = this.buffer.pop_front() {
+                if let Some(remaining) = &mut this.remaining {
+                    *remaining -= 1;
+                }
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            let pending = match &mut this.pending {
+                Some(pending) => pending,
+                None if this.finished => return Poll::Ready(None),
+                None => this.pending.insert((this.fetch)(this.cursor.clone())),
+            };
+            let result = match pending.as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.pending = None;
+
+            match result {
+                Ok(page) => {
+                    // An empty page ends the stream even if the server sent a cursor, so
+                    // that a misbehaving cursor cannot cause an endless loop.
+                    this.finished = page.next.is_none() || page.items.is_empty();
+                    this.cursor = page.next;
+                    this.buffer.extend(page.items);
+                }
+                Err(err) => {
+                    this.finished = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.len();
+        match self.remaining {
+            Some(remaining) => (buffered.min(remaining), Some(remaining)),
+            None if self.finished && self.pending.is_none() => (buffered, Some(buffered)),
+            None => (buffered, None),
+        }
+    }
+}
+
+impl<T, E, C> std::fmt::Debug for Paginator<'_, T, E, C>
+where
+    C: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Paginator")
+            .field("buffered", &self.buffer.len())
+            .field("cursor", &self.cursor)
+            .field("finished", &self.finished)
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Splits a cursor such as `limit=10&oldest_ref=...`, or a URL ending in such a query
+/// string, into decoded key-value pairs.
+pub(crate) fn cursor_query_pairs(cursor: &str) -> Vec<(String, String)> {
+    let query = cursor.split_once('?').map_or(cursor, |(_, query)| query);
+    reqwest::Url::parse(&format!("https://api.sumup.com/?{query}"))
+        .map(|url| url.query_pairs().into_owned().collect())
+        .unwrap_or_default()
+}
+
+/// Builds the query parameters of the page a cursor points at.
+///
+/// Links may leave out the caller's filters, so the pairs of the cursor are applied on top
+/// of `params`. `clear` empties a list parameter before the first pair of its key, so that
+/// the values of the link replace the caller's instead of adding to them. `set` assigns
+/// one query pair and returns `None` when the value cannot be parsed; every such pair is
+/// reported as a violation.
+pub(crate) fn params_from_cursor<P>(
+    cursor: &str,
+    mut params: P,
+    mut clear: impl FnMut(&mut P, &str),
+    mut set: impl FnMut(&mut P, &str, &str) -> Option<()>,
+) -> Result<P, ValidationError> {
+    let mut cleared = std::collections::HashSet::new();
+    let mut violations = Vec::new();
+    for (key, value) in cursor_query_pairs(cursor) {
+        if !cleared.contains(&key) {
+            clear(&mut params, &key);
+            cleared.insert(key.clone());
+        }
+        if set(&mut params, &key, &value).is_none() {
+            crate::validation::violation(&key, ViolationKind::Invalid { value }, &mut violations);
+        }
+    }
+    ValidationError::check(violations).map(|()| params)
+}
+
+/// Parses a query string value into the type of a query parameter.
+pub(crate) fn parse_query_value<T: serde::de::DeserializeOwned>(value: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .ok()
+        .or_else(|| serde_json::from_str(value).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::transactions::ListParamsOrder;
+
+    #[test]
+    fn cursor_query_pairs_decodes_links() {
+        let pairs = cursor_query_pairs("limit=10&oldest_time=2019-08-28T09%3A00%3A00Z");
+        assert_eq!(
+            pairs,
+            [
+                ("limit".to_string(), "10".to_string()),
+                (
+                    "oldest_time".to_string(),
+                    "2019-08-28T09:00:00Z".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            cursor_query_pairs("/v2.1/merchants/MH4H92C7/transactions/history?limit=5"),
+            [("limit".to_string(), "5".to_string())]
+        );
+    }
+
+    #[test]
+    fn parse_query_value_handles_strings_numbers_and_enums() {
+        assert_eq!(parse_query_value::<i64>("10"), Some(10));
+        assert_eq!(parse_query_value::<bool>("true"), Some(true));
+        assert_eq!(
+            parse_query_value::<String>("090df9bf"),
+            Some("090df9bf".to_string())
+        );
+        assert_eq!(
+            parse_query_value::<ListParamsOrder>("ascending"),
+            Some(ListParamsOrder::Ascending)
+        );
+        assert_eq!(parse_query_value::<i64>("ten"), None);
+    }
+
+    #[test]
+    fn params_from_cursor_applies_the_link_on_top_of_params() {
+        let clear = |params: &mut (Vec<i64>, Option<String>), key: &str| {
+            if key == "ids[]" {
+                params.0.clear();
+            }
+        };
+        let set = |params: &mut (Vec<i64>, Option<String>), key: &str, value: &str| {
+            match key {
+                "ids[]" => params.0.push(parse_query_value(value)?),
+                "status" => params.1 = Some(parse_query_value(value)?),
+                _ => {}
+            }
+            Some(())
+        };
+        let params = (vec![1, 2], Some("PAID".to_string()));
+        assert_eq!(
+            params_from_cursor("ids[]=3&ids[]=4&page=2", params.clone(), clear, set),
+            Ok((vec![3, 4], Some("PAID".to_string())))
+        );
+        assert_eq!(
+            params_from_cursor("status=FAILED", params, clear, set),
+            Ok((vec![1, 2], Some("FAILED".to_string())))
+        );
+    }
+
+    #[test]
+    fn params_from_cursor_reports_invalid_values() {
+        let clear = |_: &mut Vec<i64>, _: &str| {};
+        let set = |params: &mut Vec<i64>, key: &str, value: &str| {
+            if key == "limit" {
+                params.push(parse_query_value(value)?);
+            }
+            Some(())
+        };
+        assert_eq!(
+            params_from_cursor("limit=10&page=2", Vec::new(), clear, set),
+            Ok(vec![10])
+        );
+
+        let error = params_from_cursor("limit=ten&limit=5", Vec::new(), clear, set)
+            .expect_err("invalid limit");
+        assert_eq!(error.violations().len(), 1);
+        assert_eq!(error.violations()[0].path(), "limit");
+        assert_eq!(
+            error.violations()[0].kind(),
+            &ViolationKind::Invalid {
+                value: "ten".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,437 @@
+//! Card-present payments on a SumUp card reader.
+//!
+//! A reader checkout is driven by a small state machine: the checkout is created on the
+//! reader, polled until the payer completes or aborts it, terminated if it takes too long,
+//! and finally resolved to a transaction. [`ReaderPayment`] runs all of these steps and
+//! reports progress along the way:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use sumup::reader_payment::{ReaderPayment, ReaderPaymentEvent, ReaderPaymentOutcome};
+//! use sumup::wait::WaitOptions;
+//!
+//! # async fn run(client: sumup::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let request = sumup::readers::CreateCheckoutRequest::builder(
+//!     sumup::Money::from_minor_units(1000, sumup::Currency::EUR),
+//! )
+//! .build();
+//! let completion = ReaderPayment::new(&client, "MH4H92C7", "rdr_3MSAFM23CK82VSTT4BN6RWSQ65", request)
+//!     .with_wait_options(WaitOptions::default().with_timeout(Duration::from_secs(120)))
+//!     .on_event(|event| {
+//!         if let ReaderPaymentEvent::StatusChanged(status) = event {
+//!             println!("reader checkout is {status:?}");
+//!         }
+//!     })
+//!     .run()
+//!     .await?;
+//! match completion.outcome {
+//!     ReaderPaymentOutcome::Successful => println!("paid: {:?}", completion.transaction),
+//!     outcome => println!("payment not completed: {outcome:?}"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::Duration;
+
+use crate::client::Client;
+use crate::error::{ErrorBody, SdkResult};
+use crate::resources::common::TransactionStatus;
+use crate::resources::readers::{
+    CreateCheckoutErrorBody, CreateCheckoutRequest, CreateReaderCheckoutResponseData,
+    GetCheckoutErrorBody, GetReaderCheckoutResponseDataStatus, TerminateCheckoutErrorBody,
+};
+use crate::resources::transactions::{self, TransactionFull};
+use crate::wait::{Deadline, WaitOptions};
+
+type Cancellation<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+type EventHandler<'a> = Box<dyn FnMut(&ReaderPaymentEvent) + Send + 'a>;
+
+/// Progress reported by [`ReaderPayment::run`] through [`ReaderPayment::on_event`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReaderPaymentEvent {
+    /// The checkout was created on the reader and is waiting for the payer.
+    CheckoutCreated {
+        /// ID of the reader checkout, if returned by the API.
+        checkout_id: Option<String>,
+        /// Client transaction ID of the transaction the checkout creates.
+        client_transaction_id: String,
+    },
+    /// A poll returned a status different from the previous one.
+    StatusChanged(GetReaderCheckoutResponseDataStatus),
+    /// The checkout is being terminated on the reader.
+    Terminating(TerminationReason),
+}
+
+/// Why [`ReaderPayment::run`] terminated the checkout on the reader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminationReason {
+    /// The checkout was still pending when [`WaitOptions::timeout`] elapsed.
+    Timeout,
+    /// The cancellation future passed to [`ReaderPayment::with_cancellation`] completed.
+    Cancelled,
+    /// Polling the checkout failed with an error that is not retryable.
+    /// [`ReaderPayment::run`] returns the error once the termination was attempted.
+    PollFailed,
+}
+
+/// How a reader payment ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReaderPaymentOutcome {
+    /// The payment succeeded.
+    Successful,
+    /// The payment failed on the reader.
+    Failed,
+    /// The checkout was cancelled, either on the reader or by a termination.
+    Cancelled,
+    /// The checkout did not reach a final status within the termination grace period.
+    Unknown,
+}
+
+/// Result of [`ReaderPayment::run`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReaderPaymentCompletion {
+    /// How the payment ended.
+    pub outcome: ReaderPaymentOutcome,
+    /// Why the checkout was terminated, if it was.
+    pub terminated: Option<TerminationReason>,
+    /// ID of the reader checkout, if returned by the API.
+    pub checkout_id: Option<String>,
+    /// Client transaction ID of the transaction the checkout created.
+    pub client_transaction_id: String,
+    /// The transaction created by a successful or failed payment, if it could be found.
+    pub transaction: Option<TransactionFull>,
+}
+
+/// Error body of the endpoint call that ended a [`ReaderPayment`].
+#[derive(Debug, PartialEq)]
+pub enum ReaderPaymentErrorBody {
+    /// Creating the checkout failed.
+    CreateCheckout(CreateCheckoutErrorBody),
+    /// Polling the checkout failed.
+    GetCheckout(GetCheckoutErrorBody),
+    /// Terminating the checkout failed.
+    TerminateCheckout(TerminateCheckoutErrorBody),
+    /// Fetching the transaction failed.
+    GetTransaction(transactions::GetErrorBody),
+}
+
+impl ErrorBody for ReaderPaymentErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::CreateCheckout(body) => body.status(),
+            Self::GetCheckout(body) => body.status(),
+            Self::TerminateCheckout(body) => body.status(),
+            Self::GetTransaction(body) => body.status(),
+        }
+    }
+}
+
+/// Workflow taking a card-present payment on a reader from checkout to transaction.
+///
+/// [`ReaderPayment::run`] creates the checkout, polls it with the schedule of
+/// [`WaitOptions`] until it is successful, failed or cancelled, and fetches the resulting
+/// transaction. When the timeout elapses or the cancellation future completes, the
+/// checkout is terminated on the reader and polled for up to the termination grace
+/// period until the reader confirms its final status. Transient poll failures are retried;
+/// when polling fails otherwise, the checkout is terminated as well, so that it does not
+/// stay pending on the reader.
+pub struct ReaderPayment<'a> {
+    client: &'a Client,
+    merchant_code: String,
+    reader_id: String,
+    request: CreateCheckoutRequest,
+    wait_options: WaitOptions,
+    termination_grace: Duration,
+    cancellation: Option<Cancellation<'a>>,
+    on_event: Option<EventHandler<'a>>,
+}
+
+impl<'a> ReaderPayment<'a> {
+    /// Prepares a payment of `request` on the given reader.
+    pub fn new(
+        client: &'a Client,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        request: CreateCheckoutRequest,
+    ) -> Self {
+        Self {
+            client,
+            merchant_code: merchant_code.into(),
+            reader_id: reader_id.into(),
+            request,
+            wait_options: WaitOptions::default(),
+            termination_grace: Duration::from_secs(30),
+            cancellation: None,
+            on_event: None,
+        }
+    }
+
+    /// Sets the polling schedule and the timeout after which the checkout is terminated.
+    pub fn with_wait_options(mut self, wait_options: WaitOptions) -> Self {
+        self.wait_options = wait_options;
+        self
+    }
+
+    /// Sets how long to keep polling after terminating the checkout. Defaults to 30 seconds.
+    pub fn with_termination_grace(mut self, termination_grace: Duration) -> Self {
+        self.termination_grace = termination_grace;
+        self
+    }
+
+    /// Terminates the checkout once `cancellation` completes, e.g. when the cashier aborts
+    /// the sale.
+    ///
+    /// A poll still in flight at that moment is abandoned, so the termination is not
+    /// delayed by a slow API. Creating the checkout is always awaited instead: a checkout
+    /// created after the termination would stay payable on the reader.
+    pub fn with_cancellation(mut self, cancellation: impl Future<Output = ()> + Send + 'a) -> Self {
+        self.cancellation = Some(Box::pin(cancellation));
+        self
+    }
+
+    /// Calls `handler` for every [`ReaderPaymentEvent`].
+    pub fn on_event(mut self, handler: impl FnMut(&ReaderPaymentEvent) + Send + 'a) -> Self {
+        self.on_event = Some(Box::new(handler));
+        self
+    }
+
+    /// Runs the payment to completion.
+    ///
+    /// Errors returned by the API end the workflow, except a `404 Not Found` when
+    /// terminating the checkout: the checkout completed in the meantime, which the next
+    /// poll reveals. Polls failing with a transient error such as a connection failure or
+    /// a `503 Service Unavailable` are repeated on the [`WaitOptions`] schedule until the
+    /// timeout. Any other failed poll is returned after attempting to terminate the
+    /// checkout within the termination grace period.
+    pub async fn run(mut self) -> SdkResult<ReaderPaymentCompletion, ReaderPaymentErrorBody> {
+        let mut cancellation = self.cancellation.take();
+        let readers = self.client.readers();
+        let create = readers.create_checkout(
+            self.merchant_code.as_str(),
+            self.reader_id.as_str(),
+            self.request.clone(),
+        );
+        // A cancellation completing meanwhile is noticed by the first poll, which then
+        // terminates the created checkout.
+        let created = create
+            .await
+            .map_err(|err| err.map_body(ReaderPaymentErrorBody::CreateCheckout))?
+            .data;
+        self.emit(ReaderPaymentEvent::CheckoutCreated {
+            checkout_id: created.checkout_id.clone(),
+            client_transaction_id: created.client_transaction_id.clone(),
+        });
+
+        let mut deadline = Deadline::new(self.wait_options.timeout());
+        let mut terminated = None;
+        let mut last_status = None;
+        let mut poll = 0;
+        let status = loop {
+            poll += 1;
+            let status = match or_cancel(&mut cancellation, self.poll_status(&created)).await {
+                None => {
+                    terminated = Some(TerminationReason::Cancelled);
+                    self.terminate(TerminationReason::Cancelled).await?;
+                    deadline = Deadline::new(Some(self.termination_grace));
+                    continue;
+                }
+                Some(Ok(status)) => Some(status),
+                // A transient failure must not abort a payment the customer is making;
+                // the next poll follows the usual schedule until the deadline.
+                Some(Err(err)) if err.is_retryable() => None,
+                Some(Err(err)) => {
+                    if terminated.is_none() {
+                        // The poll error is what the caller needs to see, whatever the
+                        // termination attempt returns.
+                        let _ = tokio::time::timeout(
+                            self.termination_grace,
+                            self.terminate(TerminationReason::PollFailed),
+                        )
+                        .await;
+                    }
+                    return Err(err);
+                }
+            };
+            if let Some(status) = status {
+                if last_status.as_ref() != Some(&status) {
+                    self.emit(ReaderPaymentEvent::StatusChanged(status.clone()));
+                    last_status = Some(status.clone());
+                }
+                if is_final(&status) {
+                    break Some(status);
+                }
+            }
+
+            let Some(sleep) = deadline.next_sleep(self.wait_options.interval(poll)) else {
+                if terminated.is_some() {
+                    break None;
+                }
+                terminated = Some(TerminationReason::Timeout);
+                cancellation = None;
+                self.terminate(TerminationReason::Timeout).await?;
+                deadline = Deadline::new(Some(self.termination_grace));
+                continue;
+            };
+            if or_cancel(&mut cancellation, tokio::time::sleep(sleep))
+                .await
+                .is_none()
+            {
+                terminated = Some(TerminationReason::Cancelled);
+                self.terminate(TerminationReason::Cancelled).await?;
+                deadline = Deadline::new(Some(self.termination_grace));
+            }
+        };
+
+        let outcome = match status {
+            Some(GetReaderCheckoutResponseDataStatus::Successful) => {
+                ReaderPaymentOutcome::Successful
+            }
+            Some(GetReaderCheckoutResponseDataStatus::Failed) => ReaderPaymentOutcome::Failed,
+            Some(_) => ReaderPaymentOutcome::Cancelled,
+            None => ReaderPaymentOutcome::Unknown,
+        };
+        let transaction = match outcome {
+            ReaderPaymentOutcome::Successful | ReaderPaymentOutcome::Failed => {
+                self.fetch_transaction(&created.client_transaction_id)
+                    .await?
+            }
+            _ => None,
+        };
+        Ok(ReaderPaymentCompletion {
+            outcome,
+            terminated,
+            checkout_id: created.checkout_id,
+            client_transaction_id: created.client_transaction_id,
+            transaction,
+        })
+    }
+
+    fn emit(&mut self, event: ReaderPaymentEvent) {
+        if let Some(handler) = self.on_event.as_mut() {
+            handler(&event);
+        }
+    }
+
+    async fn poll_status(
+        &self,
+        created: &CreateReaderCheckoutResponseData,
+    ) -> SdkResult<GetReaderCheckoutResponseDataStatus, ReaderPaymentErrorBody> {
+        let Some(checkout_id) = &created.checkout_id else {
+            // Without a checkout ID, follow the transaction the checkout creates instead.
+            let status = self
+                .fetch_transaction(&created.client_transaction_id)
+                .await?
+                .and_then(|transaction| transaction.status);
+            return Ok(match status {
+                Some(TransactionStatus::Successful) => {
+                    GetReaderCheckoutResponseDataStatus::Successful
+                }
+                Some(TransactionStatus::Failed) => GetReaderCheckoutResponseDataStatus::Failed,
+                Some(TransactionStatus::Cancelled) => {
+                    GetReaderCheckoutResponseDataStatus::Cancelled
+                }
+                _ => GetReaderCheckoutResponseDataStatus::Pending,
+            });
+        };
+        let checkout = self
+            .client
+            .readers()
+            .get_checkout(
+                self.merchant_code.as_str(),
+                self.reader_id.as_str(),
+                checkout_id.as_str(),
+            )
+            .await
+            .map_err(|err| err.map_body(ReaderPaymentErrorBody::GetCheckout))?;
+        Ok(checkout.data.status)
+    }
+
+    /// Looks up the transaction by its client transaction ID. Returns `None` while the
+    /// transaction does not exist yet.
+    async fn fetch_transaction(
+        &self,
+        client_transaction_id: &str,
+    ) -> SdkResult<Option<TransactionFull>, ReaderPaymentErrorBody> {
+        let params = transactions::GetParams::builder()
+            .client_transaction_id(client_transaction_id)
+            .build();
+        match self
+            .client
+            .transactions()
+            .get(self.merchant_code.as_str(), params)
+            .await
+        {
+            Ok(transaction) => Ok(Some(transaction)),
+            Err(err) if err.is_not_found() => Ok(None),
+            Err(err) => Err(err.map_body(ReaderPaymentErrorBody::GetTransaction)),
+        }
+    }
+
+    async fn terminate(
+        &mut self,
+        reason: TerminationReason,
+    ) -> SdkResult<(), ReaderPaymentErrorBody> {
+        self.emit(ReaderPaymentEvent::Terminating(reason));
+        match self
+            .client
+            .readers()
+            .terminate_checkout(self.merchant_code.as_str(), self.reader_id.as_str())
+            .await
+        {
+            Ok(()) => Ok(()),
+            // No checkout is in progress on the reader anymore.
+            Err(err) if err.is_not_found() => Ok(()),
+            Err(err) => Err(err.map_body(ReaderPaymentErrorBody::TerminateCheckout)),
+        }
+    }
+}
+
+impl fmt::Debug for ReaderPayment<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReaderPayment")
+            .field("merchant_code", &self.merchant_code)
+            .field("reader_id", &self.reader_id)
+            .field("request", &self.request)
+            .field("wait_options", &self.wait_options)
+            .field("termination_grace", &self.termination_grace)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Runs `future` to completion, or returns `None` as soon as `cancellation` completes.
+///
+/// A completed cancellation is cleared, so that later calls only run their future.
+async fn or_cancel<F: Future>(
+    cancellation: &mut Option<Cancellation<'_>>,
+    future: F,
+) -> Option<F::Output> {
+    let Some(cancel) = cancellation.as_mut() else {
+        return Some(future.await);
+    };
+    let mut future = std::pin::pin!(future);
+    let output = std::future::poll_fn(|cx| {
+        if cancel.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(None);
+        }
+        future.as_mut().poll(cx).map(Some)
+    })
+    .await;
+    if output.is_none() {
+        *cancellation = None;
+    }
+    output
+}
+
+fn is_final(status: &GetReaderCheckoutResponseDataStatus) -> bool {
+    matches!(
+        status,
+        GetReaderCheckoutResponseDataStatus::Successful
+            | GetReaderCheckoutResponseDataStatus::Failed
+            | GetReaderCheckoutResponseDataStatus::Cancelled
+    )
+}
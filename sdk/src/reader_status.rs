@@ -0,0 +1,321 @@
+//! Live updates of a card reader's status.
+//!
+//! [`ReadersClient::watch_status`] polls the status of a reader and returns a
+//! [`StatusWatch`], a [`Stream`] that yields an update only when something worth
+//! reacting to changed: the reader went online or offline, its battery crossed the
+//! low-battery threshold, or its firmware changed.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use futures_util::TryStreamExt;
+//! use sumup::reader_status::ReaderStatusChange;
+//!
+//! # async fn run(client: sumup::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let readers = client.readers();
+//! let mut updates = readers
+//!     .watch_status("MH4H92C7", "rdr_3MSAFM23CK82VSTT4BN6RWSQ65", Duration::from_secs(15))
+//!     .with_low_battery_threshold(15.0);
+//! while let Some(update) = updates.try_next().await? {
+//!     if update.changes.contains(&ReaderStatusChange::Offline) {
+//!         println!("reader went offline");
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+
+use crate::error::SdkResult;
+use crate::resources::readers::{
+    GetStatusErrorBody, ReadersClient, StatusResponse, StatusResponseData, StatusResponseDataStatus,
+};
+
+type StatusFuture<'a> =
+    Pin<Box<dyn Future<Output = SdkResult<StatusResponse, GetStatusErrorBody>> + Send + 'a>>;
+type FetchStatus<'a> = Box<dyn FnMut() -> StatusFuture<'a> + Send + 'a>;
+
+/// A change reported by [`StatusWatch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReaderStatusChange {
+    /// The first status observed by the watch.
+    Initial,
+    /// The reader came online.
+    Online,
+    /// The reader went offline.
+    Offline,
+    /// The battery level dropped to or below the low-battery threshold.
+    BatteryLow,
+    /// The battery level rose above the low-battery threshold again.
+    BatteryRecovered,
+    /// The firmware version changed, e.g. after an update.
+    FirmwareChanged {
+        /// Firmware version reported before the change.
+        previous: Option<String>,
+    },
+}
+
+/// Status of a reader together with what changed since the previous update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReaderStatusUpdate {
+    /// What changed since the previous update. Never empty.
+    pub changes: Vec<ReaderStatusChange>,
+    /// The status as returned by the API.
+    pub status: StatusResponseData,
+}
+
+/// A [`Stream`] of reader status updates, returned by [`ReadersClient::watch_status`].
+///
+/// Transient errors such as connection failures, rate limiting or gateway errors do not
+/// end the stream: the status is polled again with a growing delay of up to one minute.
+/// Any other error is yielded and ends the stream.
+pub struct StatusWatch<'a> {
+    fetch: FetchStatus<'a>,
+    pending: Option<StatusFuture<'a>>,
+    sleep: Option<Pin<Box<tokio::time::Sleep>>>,
+    interval: Duration,
+    low_battery_threshold: f32,
+    failures: u32,
+    last: Option<StatusResponseData>,
+    finished: bool,
+}
+
+impl<'a> StatusWatch<'a> {
+    fn new<F, Fut>(interval: Duration, mut fetch: F) -> Self
+    where
+        F: FnMut() -> Fut + Send + 'a,
+        Fut: Future<Output = SdkResult<StatusResponse, GetStatusErrorBody>> + Send + 'a,
+    {
+        Self {
+            fetch: Box::new(move || Box::pin(fetch())),
+            pending: None,
+            sleep: None,
+            interval,
+            low_battery_threshold: 20.0,
+            failures: 0,
+            last: None,
+            finished: false,
+        }
+    }
+
+    /// Sets the battery percentage at or below which [`ReaderStatusChange::BatteryLow`] is
+    /// reported. Defaults to 20.
+    pub fn with_low_battery_threshold(mut self, threshold: f32) -> Self {
+        self.low_battery_threshold = threshold;
+        self
+    }
+
+    /// Returns the last status received, if any, with the battery level and firmware
+    /// version of earlier polls when the reader stopped reporting them.
+    pub fn last_status(&self) -> Option<&StatusResponseData> {
+        self.last.as_ref()
+    }
+
+    fn is_battery_low(&self, status: &StatusResponseData) -> bool {
+        status
+            .battery_level
+            .is_some_and(|level| level <= self.low_battery_threshold)
+    }
+
+    fn changes(&self, status: &StatusResponseData) -> Vec<ReaderStatusChange> {
+        let Some(last) = &self.last else {
+            let mut changes = vec![ReaderStatusChange::Initial];
+            if self.is_battery_low(status) {
+                changes.push(ReaderStatusChange::BatteryLow);
+            }
+            return changes;
+        };
+
+        let mut changes = Vec::new();
+        if status.status != last.status {
+            match status.status {
+                StatusResponseDataStatus::Online => changes.push(ReaderStatusChange::Online),
+                StatusResponseDataStatus::Offline => changes.push(ReaderStatusChange::Offline),
+                StatusResponseDataStatus::Other(_) => {}
+            }
+        }
+        // A reader that stops reporting its battery level keeps its previous state.
+        if status.battery_level.is_some() {
+            match (self.is_battery_low(last), self.is_battery_low(status)) {
+                (false, true) => changes.push(ReaderStatusChange::BatteryLow),
+                (true, false) => changes.push(ReaderStatusChange::BatteryRecovered),
+                _ => {}
+            }
+        }
+        if status.firmware_version.is_some() && status.firmware_version != last.firmware_version {
+            changes.push(ReaderStatusChange::FirmwareChanged {
+                previous: last.firmware_version.clone(),
+            });
+        }
+        changes
+    }
+
+    /// Fills the fields missing from `status` with those of the previous status, so that a
+    /// reader that stops reporting them keeps its previous state.
+    fn carry_over(&self, mut status: StatusResponseData) -> StatusResponseData {
+        if let Some(last) = &self.last {
+            if status.battery_level.is_none() {
+                status.battery_level = last.battery_level;
+            }
+            if status.firmware_version.is_none() {
+                status.firmware_version = last.firmware_version.clone();
+            }
+        }
+        status
+    }
+
+    /// Returns the delay after consecutive failures, growing up to a minute or the poll
+    /// interval, whichever is longer.
+    fn backoff(&self) -> Duration {
+        let max = Duration::from_secs(60).max(self.interval);
+        crate::retry::exponential_backoff(self.interval, self.failures, max)
+    }
+}
+
+impl Stream for StatusWatch<'_> {
+    type Item = SdkResult<ReaderStatusUpdate, GetStatusErrorBody>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.finished {
+                return Poll::Ready(None);
+            }
+            if let Some(sleep) = &mut this.sleep {
+                if sleep.as_mut().poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                this.sleep = None;
+            }
+
+            let pending = this.pending.get_or_insert_with(|| (this.fetch)());
+            let result = match pending.as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.pending = None;
+
+            match result {
+                Ok(response) => {
+                    this.failures = 0;
+                    this.sleep = Some(Box::pin(tokio::time::sleep(this.interval)));
+                    let status = response.data;
+                    let changes = this.changes(&status);
+                    this.last = Some(this.carry_over(status.clone()));
+                    if !changes.is_empty() {
+                        return Poll::Ready(Some(Ok(ReaderStatusUpdate { changes, status })));
+                    }
+                }
+                Err(err) if err.is_retryable() => {
+                    this.failures += 1;
+                    this.sleep = Some(Box::pin(tokio::time::sleep(this.backoff())));
+                }
+                Err(err) => {
+                    this.finished = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for StatusWatch<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatusWatch")
+            .field("interval", &self.interval)
+            .field("low_battery_threshold", &self.low_battery_threshold)
+            .field("failures", &self.failures)
+            .field("last", &self.last)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> ReadersClient<'a> {
+    /// Polls [`Self::get_status`] every `interval` and yields the changes of the reader's
+    /// status, see [`StatusWatch`].
+    ///
+    /// The first update is yielded right away and carries [`ReaderStatusChange::Initial`].
+    pub fn watch_status(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        interval: Duration,
+    ) -> StatusWatch<'a> {
+        let readers = self.clone();
+        let merchant_code = merchant_code.into();
+        let reader_id = reader_id.into();
+        StatusWatch::new(interval, move || {
+            let readers = readers.clone();
+            let merchant_code = merchant_code.clone();
+            let reader_id = reader_id.clone();
+            async move { readers.get_status(merchant_code, reader_id).await }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(online: bool, battery_level: f32, firmware_version: &str) -> StatusResponseData {
+        StatusResponseData {
+            battery_level: Some(battery_level),
+            battery_temperature: None,
+            connection_type: None,
+            firmware_version: Some(firmware_version.to_string()),
+            last_activity: None,
+            state: None,
+            status: if online {
+                StatusResponseDataStatus::Online
+            } else {
+                StatusResponseDataStatus::Offline
+            },
+        }
+    }
+
+    fn watch() -> StatusWatch<'static> {
+        StatusWatch::new(Duration::from_secs(1), || async {
+            unreachable!("the tests do not poll the stream")
+        })
+    }
+
+    #[test]
+    fn changes_report_transitions_only() {
+        let mut watch = watch();
+        assert_eq!(
+            watch.changes(&status(true, 15.0, "3.3.3.21")),
+            [ReaderStatusChange::Initial, ReaderStatusChange::BatteryLow]
+        );
+
+        watch.last = Some(status(true, 15.0, "3.3.3.21"));
+        assert_eq!(watch.changes(&status(true, 12.5, "3.3.3.21")), []);
+        assert_eq!(
+            watch.changes(&status(false, 80.0, "3.3.4.0")),
+            [
+                ReaderStatusChange::Offline,
+                ReaderStatusChange::BatteryRecovered,
+                ReaderStatusChange::FirmwareChanged {
+                    previous: Some("3.3.3.21".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_a_minute() {
+        let mut watch = watch();
+        let delays: Vec<_> = (1..=8)
+            .map(|failures| {
+                watch.failures = failures;
+                watch.backoff().as_secs()
+            })
+            .collect();
+        assert_eq!(delays, [1, 2, 4, 8, 16, 32, 60, 60]);
+    }
+}
@@ -0,0 +1,50 @@
+//! Per-call options applied on top of the [`Client`](crate::Client) configuration.
+//!
+//! Every operation has a `*_with_options` variant taking the options for that call only,
+//! e.g. [`CheckoutsClient::create_with_options`](crate::checkouts::CheckoutsClient::create_with_options),
+//! and a `*_with_response_and_options` variant additionally returning the
+//! [`ResponseMeta`](crate::ResponseMeta).
+//!
+//! ```no_run
+//! # async fn run(client: sumup::Client, body: sumup::customers::CreateRequest) -> Result<(), Box<dyn std::error::Error>> {
+//! use sumup::RequestOptions;
+//!
+//! let customer = client
+//!     .customers()
+//!     .create_with_options(body, RequestOptions::new().with_idempotency_key("customer-create-1"))
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+/// Name of the header carrying the idempotency key.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Options for individual API calls.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestOptions {
+    idempotency_key: Option<String>,
+}
+
+impl RequestOptions {
+    /// Creates empty request options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the idempotency key sent in the `Idempotency-Key` header.
+    ///
+    /// The API specification does not document this header, so the SDK does not rely on
+    /// it: a `POST` request carrying a key is still not retried automatically. The API
+    /// deduplicates checkouts by their `checkout_reference` and reader payments by their
+    /// `client_transaction_id` instead, see [`crate::retry`].
+    pub fn with_idempotency_key(mut self, idempotency_key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(idempotency_key.into());
+        self
+    }
+
+    /// Returns the idempotency key if set.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        self.idempotency_key.as_deref()
+    }
+}
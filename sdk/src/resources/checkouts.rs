@@ -30,7 +30,7 @@ pub struct Checkout {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     /// Short unique identifier for the merchant that receives the payment.
@@ -101,6 +101,17 @@ pub struct Checkout {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transactions: Option<Vec<CheckoutTransactionsItem>>,
 }
+impl Checkout {
+    /// Returns `amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+}
 /// Checkout resource returned after a synchronous processing attempt. In addition to the base checkout fields, it can include the resulting transaction identifiers and any newly created payment instrument token.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct CheckoutSuccess {
@@ -116,7 +127,7 @@ pub struct CheckoutSuccess {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     /// Short unique identifier for the merchant that receives the payment.
@@ -216,6 +227,17 @@ pub struct CheckoutSuccess {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_instrument: Option<CheckoutSuccessPaymentInstrument>,
 }
+impl CheckoutSuccess {
+    /// Returns `amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+}
 /// Details of a request validation error.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct DetailsError {
@@ -251,12 +273,36 @@ impl std::fmt::Display for DetailsError {
 impl std::error::Error for DetailsError {}
 /// Hosted Checkout configuration. Enable it to receive a SumUp-hosted payment page URL in the checkout response.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct HostedCheckout {
     /// Whether the checkout should include a SumUp-hosted payment page.
     ///
     /// Example: `true`
     pub enabled: bool,
 }
+impl HostedCheckout {
+    /// Returns a builder for [`HostedCheckout`], taking its required fields.
+    pub fn builder(enabled: impl Into<bool>) -> HostedCheckoutBuilder {
+        HostedCheckoutBuilder {
+            inner: Self {
+                enabled: enabled.into(),
+            },
+        }
+    }
+}
+/// Builder for [`HostedCheckout`], see [`HostedCheckout::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct HostedCheckoutBuilder {
+    inner: HostedCheckout,
+}
+impl HostedCheckoutBuilder {
+    /// Returns the built [`HostedCheckout`].
+    pub fn build(self) -> HostedCheckout {
+        self.inner
+    }
+}
+impl crate::validation::Validate for HostedCheckout {}
 /// Current high-level state of the checkout. `PENDING` means the checkout exists but is not yet completed, `PAID` means a payment succeeded, `FAILED` means the latest processing attempt failed, and `EXPIRED` means the checkout can no longer be processed.
 ///
 /// Example: `PENDING`
@@ -289,7 +335,7 @@ pub struct CheckoutTransactionsItem {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     /// The timestamp of when the transaction was created.
@@ -318,12 +364,12 @@ pub struct CheckoutTransactionsItem {
     ///
     /// Example: `6`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_amount: Option<f32>,
+    pub vat_amount: Option<crate::money::Amount>,
     /// Amount of the tip (out of the total transaction amount).
     ///
     /// Example: `3`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tip_amount: Option<f32>,
+    pub tip_amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entry_mode: Option<EntryMode>,
     /// Authorization code for the transaction sent by the payment card issuer or bank. Applicable only to card payments.
@@ -332,6 +378,35 @@ pub struct CheckoutTransactionsItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auth_code: Option<String>,
 }
+impl CheckoutTransactionsItem {
+    /// Returns `amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+    /// Returns `vat_amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn vat_amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.vat_amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+    /// Returns `tip_amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn tip_amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.tip_amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+}
 /// Current high-level state of the checkout. `PENDING` means the checkout exists but is not yet completed, `PAID` means a payment succeeded, `FAILED` means the latest processing attempt failed, and `EXPIRED` means the checkout can no longer be processed.
 ///
 /// Example: `PENDING`
@@ -364,7 +439,7 @@ pub struct CheckoutSuccessTransactionsItem {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     /// The timestamp of when the transaction was created.
@@ -393,12 +468,12 @@ pub struct CheckoutSuccessTransactionsItem {
     ///
     /// Example: `6`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_amount: Option<f32>,
+    pub vat_amount: Option<crate::money::Amount>,
     /// Amount of the tip (out of the total transaction amount).
     ///
     /// Example: `3`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tip_amount: Option<f32>,
+    pub tip_amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entry_mode: Option<EntryMode>,
     /// Authorization code for the transaction sent by the payment card issuer or bank. Applicable only to card payments.
@@ -407,6 +482,35 @@ pub struct CheckoutSuccessTransactionsItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auth_code: Option<String>,
 }
+impl CheckoutSuccessTransactionsItem {
+    /// Returns `amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+    /// Returns `vat_amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn vat_amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.vat_amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+    /// Returns `tip_amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn tip_amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.tip_amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+}
 /// Details of the saved payment instrument created or reused during checkout processing.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct CheckoutSuccessPaymentInstrument {
@@ -439,6 +543,19 @@ pub enum CreateRequestPurpose {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for CreateRequestPurpose {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ListAvailablePaymentMethodsResponseAvailablePaymentMethodsItem {
     /// Unique identifier of the payment method.
@@ -447,6 +564,7 @@ pub struct ListAvailablePaymentMethodsResponseAvailablePaymentMethodsItem {
     pub id: String,
 }
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct ListParams {
     /// Filters the list of checkout resources by the unique reference of the checkout.
     ///
@@ -454,10 +572,41 @@ pub struct ListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checkout_reference: Option<String>,
 }
+impl ListParams {
+    /// Returns a builder for [`ListParams`], taking its required fields.
+    pub fn builder() -> ListParamsBuilder {
+        ListParamsBuilder {
+            inner: Self {
+                checkout_reference: None,
+            },
+        }
+    }
+}
+/// Builder for [`ListParams`], see [`ListParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ListParamsBuilder {
+    inner: ListParams,
+}
+impl ListParamsBuilder {
+    /// Filters the list of checkout resources by the unique reference of the checkout.
+    ///
+    /// Example: `f00a8f74-b05d-4605-bd73-2a901bae5802`
+    pub fn checkout_reference(mut self, checkout_reference: impl Into<String>) -> Self {
+        self.inner.checkout_reference = Some(checkout_reference.into());
+        self
+    }
+    /// Returns the built [`ListParams`].
+    pub fn build(self) -> ListParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for ListParams {}
 /// Returns a list of checkout resources.
 pub type ListResponse = Vec<CheckoutSuccess>;
 /// Details for creating a checkout resource.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateRequest {
     /// Merchant-defined reference for the new checkout. It should be unique enough for you to identify the payment attempt in your own systems.
     ///
@@ -469,7 +618,7 @@ pub struct CreateRequest {
     /// Amount to be charged to the payer, expressed in major units.
     ///
     /// Example: `10.1`
-    pub amount: f32,
+    pub amount: crate::money::Amount,
     pub currency: Currency,
     /// Short unique identifier for the merchant that should receive the payment.
     ///
@@ -513,14 +662,133 @@ pub struct CreateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hosted_checkout: Option<HostedCheckout>,
 }
+impl CreateRequest {
+    /// Returns a builder for [`CreateRequest`], taking its required fields.
+    pub fn builder(
+        checkout_reference: impl Into<String>,
+        amount: impl Into<crate::money::Amount>,
+        currency: impl Into<Currency>,
+        merchant_code: impl Into<String>,
+    ) -> CreateRequestBuilder {
+        CreateRequestBuilder {
+            inner: Self {
+                checkout_reference: checkout_reference.into(),
+                amount: amount.into(),
+                currency: currency.into(),
+                merchant_code: merchant_code.into(),
+                description: None,
+                return_url: None,
+                customer_id: None,
+                purpose: None,
+                valid_until: None,
+                redirect_url: None,
+                hosted_checkout: None,
+            },
+        }
+    }
+}
+/// Builder for [`CreateRequest`], see [`CreateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateRequestBuilder {
+    inner: CreateRequest,
+}
+impl CreateRequestBuilder {
+    /// Short merchant-defined description shown in SumUp tools and reporting for easier identification of the checkout.
+    ///
+    /// Example: `Purchase`
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.inner.description = Some(description.into());
+        self
+    }
+    /// Optional backend callback URL used by SumUp to notify your platform about processing updates for the checkout.
+    ///
+    /// Constraints:
+    /// - format: `uri`
+    ///
+    /// Example: `http://example.com/`
+    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
+        self.inner.return_url = Some(return_url.into());
+        self
+    }
+    /// Merchant-scoped customer identifier. Required when setting up recurring payments and useful when the checkout should be linked to a returning payer.
+    ///
+    /// Example: `831ff8d4cd5958ab5670`
+    pub fn customer_id(mut self, customer_id: impl Into<String>) -> Self {
+        self.inner.customer_id = Some(customer_id.into());
+        self
+    }
+    /// Business purpose of the checkout. Use `CHECKOUT` for a standard payment and `SETUP_RECURRING_PAYMENT` when collecting consent and payment details for future recurring charges.
+    pub fn purpose(mut self, purpose: impl Into<CreateRequestPurpose>) -> Self {
+        self.inner.purpose = Some(purpose.into());
+        self
+    }
+    /// Optional expiration timestamp. The checkout must be processed before this moment, otherwise it becomes unusable. If omitted, the checkout does not have an explicit expiry time.
+    ///
+    /// Example: `2020-02-29T10:56:56+00:00`
+    pub fn valid_until(
+        mut self,
+        valid_until: impl Into<crate::Nullable<crate::datetime::DateTime>>,
+    ) -> Self {
+        self.inner.valid_until = Some(valid_until.into());
+        self
+    }
+    /// URL where the payer should be sent after a redirect-based payment or SCA flow completes. This is required for [APMs](https://developer.sumup.com/online-payments/apm/introduction) and recommended for card checkouts that may require [3DS](https://developer.sumup.com/online-payments/features/3ds). If it is omitted, the [Payment Widget](https://developer.sumup.com/online-payments/checkouts) can render the challenge in an iframe instead of using a full-page redirect.
+    ///
+    /// Example: `https://mysite.com/completed_purchase`
+    pub fn redirect_url(mut self, redirect_url: impl Into<String>) -> Self {
+        self.inner.redirect_url = Some(redirect_url.into());
+        self
+    }
+    /// Hosted Checkout configuration. Enable it to receive a SumUp-hosted payment page URL in the checkout response.
+    pub fn hosted_checkout(mut self, hosted_checkout: impl Into<HostedCheckout>) -> Self {
+        self.inner.hosted_checkout = Some(hosted_checkout.into());
+        self
+    }
+    /// Returns the built [`CreateRequest`].
+    pub fn build(self) -> CreateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        {
+            let value = &self.checkout_reference;
+            let path = crate::validation::field_path(path, "checkout_reference");
+            crate::validation::check_length(value, None, Some(90), &path, violations);
+        }
+        {
+            let value = &self.currency;
+            let path = crate::validation::field_path(path, "currency");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.purpose {
+            let path = crate::validation::field_path(path, "purpose");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.hosted_checkout {
+            let path = crate::validation::field_path(path, "hosted_checkout");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
+impl CreateRequest {
+    /// Returns `amount` in `currency` as [`Money`](crate::Money).
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn amount_money(&self) -> Result<crate::Money, crate::money::MoneyError> {
+        crate::Money::from_amount(self.amount, self.currency.clone())
+    }
+}
 /// Details for updating a checkout resource.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct UpdateRequest {
     /// Updated amount to be charged to the payer, expressed in major units.
     ///
     /// Example: `12.5`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     /// Updated short merchant-defined description shown in SumUp tools and reporting.
@@ -551,19 +819,154 @@ pub struct UpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer_id: Option<String>,
 }
+impl UpdateRequest {
+    /// Returns a builder for [`UpdateRequest`], taking its required fields.
+    pub fn builder() -> UpdateRequestBuilder {
+        UpdateRequestBuilder {
+            inner: Self {
+                amount: None,
+                currency: None,
+                description: None,
+                checkout_reference: None,
+                valid_until: None,
+                customer_id: None,
+            },
+        }
+    }
+}
+/// Builder for [`UpdateRequest`], see [`UpdateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct UpdateRequestBuilder {
+    inner: UpdateRequest,
+}
+impl UpdateRequestBuilder {
+    /// Updated amount to be charged to the payer, expressed in major units.
+    ///
+    /// Example: `12.5`
+    pub fn amount(mut self, amount: impl Into<crate::money::Amount>) -> Self {
+        self.inner.amount = Some(amount.into());
+        self
+    }
+    /// Three-letter [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) currency code of the amount.
+    pub fn currency(mut self, currency: impl Into<Currency>) -> Self {
+        self.inner.currency = Some(currency.into());
+        self
+    }
+    /// Updated short merchant-defined description shown in SumUp tools and reporting.
+    ///
+    /// Example: `Updated purchase`
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.inner.description = Some(description.into());
+        self
+    }
+    /// Updated merchant-defined reference for the checkout.
+    ///
+    /// Constraints:
+    /// - max length: 90
+    ///
+    /// Example: `f00a8f74-b05d-4605-bd73-2a901bae5802`
+    pub fn checkout_reference(mut self, checkout_reference: impl Into<String>) -> Self {
+        self.inner.checkout_reference = Some(checkout_reference.into());
+        self
+    }
+    /// Updated expiration timestamp. The checkout must be processed before this moment, otherwise it becomes unusable.
+    ///
+    /// Example: `2020-02-29T10:56:56+00:00`
+    pub fn valid_until(
+        mut self,
+        valid_until: impl Into<crate::Nullable<crate::datetime::DateTime>>,
+    ) -> Self {
+        self.inner.valid_until = Some(valid_until.into());
+        self
+    }
+    /// Updated merchant-scoped customer identifier associated with the checkout.
+    ///
+    /// Example: `831ff8d4cd5958ab5670`
+    pub fn customer_id(mut self, customer_id: impl Into<String>) -> Self {
+        self.inner.customer_id = Some(customer_id.into());
+        self
+    }
+    /// Returns the built [`UpdateRequest`].
+    pub fn build(self) -> UpdateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for UpdateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.currency {
+            let path = crate::validation::field_path(path, "currency");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.checkout_reference {
+            let path = crate::validation::field_path(path, "checkout_reference");
+            crate::validation::check_length(value, None, Some(90), &path, violations);
+        }
+    }
+}
+impl UpdateRequest {
+    /// Returns `amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+}
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct ListAvailablePaymentMethodsParams {
     /// The amount for which the payment methods should be eligible, in major units.
     ///
     /// Example: `9.99`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f64>,
+    pub amount: Option<crate::money::Amount>,
     /// The currency for which the payment methods should be eligible.
     ///
     /// Example: `EUR`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<String>,
 }
+impl ListAvailablePaymentMethodsParams {
+    /// Returns a builder for [`ListAvailablePaymentMethodsParams`], taking its required fields.
+    pub fn builder() -> ListAvailablePaymentMethodsParamsBuilder {
+        ListAvailablePaymentMethodsParamsBuilder {
+            inner: Self {
+                amount: None,
+                currency: None,
+            },
+        }
+    }
+}
+/// Builder for [`ListAvailablePaymentMethodsParams`], see [`ListAvailablePaymentMethodsParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ListAvailablePaymentMethodsParamsBuilder {
+    inner: ListAvailablePaymentMethodsParams,
+}
+impl ListAvailablePaymentMethodsParamsBuilder {
+    /// The amount for which the payment methods should be eligible, in major units.
+    ///
+    /// Example: `9.99`
+    pub fn amount(mut self, amount: impl Into<crate::money::Amount>) -> Self {
+        self.inner.amount = Some(amount.into());
+        self
+    }
+    /// The currency for which the payment methods should be eligible.
+    ///
+    /// Example: `EUR`
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.inner.currency = Some(currency.into());
+        self
+    }
+    /// Returns the built [`ListAvailablePaymentMethodsParams`].
+    pub fn build(self) -> ListAvailablePaymentMethodsParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for ListAvailablePaymentMethodsParams {}
 /// Available payment methods
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ListAvailablePaymentMethodsResponse {
@@ -574,6 +977,7 @@ pub struct ListAvailablePaymentMethodsResponse {
 }
 /// The data needed to create an apple pay session for a checkout.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateApplePaySessionRequest {
     /// the context to create this apple pay session.
     ///
@@ -590,6 +994,33 @@ pub struct CreateApplePaySessionRequest {
     /// Example: `https://apple-pay-gateway-cert.apple.com/paymentservices/startSession`
     pub target: String,
 }
+impl CreateApplePaySessionRequest {
+    /// Returns a builder for [`CreateApplePaySessionRequest`], taking its required fields.
+    pub fn builder(
+        context: impl Into<String>,
+        target: impl Into<String>,
+    ) -> CreateApplePaySessionRequestBuilder {
+        CreateApplePaySessionRequestBuilder {
+            inner: Self {
+                context: context.into(),
+                target: target.into(),
+            },
+        }
+    }
+}
+/// Builder for [`CreateApplePaySessionRequest`], see [`CreateApplePaySessionRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateApplePaySessionRequestBuilder {
+    inner: CreateApplePaySessionRequest,
+}
+impl CreateApplePaySessionRequestBuilder {
+    /// Returns the built [`CreateApplePaySessionRequest`].
+    pub fn build(self) -> CreateApplePaySessionRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateApplePaySessionRequest {}
 /// Successful request. Returns the Apple Pay merchant session object
 /// that should be forwarded to the Apple Pay JS SDK to complete merchant
 /// validation and continue the payment flow.
@@ -599,6 +1030,13 @@ use crate::client::Client;
 pub enum ListErrorBody {
     Unauthorized(Problem),
 }
+impl crate::error::ErrorBody for ListErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum CreateErrorBody {
     BadRequest(ErrorExtended),
@@ -606,33 +1044,91 @@ pub enum CreateErrorBody {
     Forbidden(ErrorForbidden),
     Conflict(Error),
 }
+impl crate::error::ErrorBody for CreateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::Conflict(_) => Some(reqwest::StatusCode::CONFLICT),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum DeactivateErrorBody {
     Unauthorized(Problem),
     NotFound(Error),
     Conflict(Error),
 }
+impl crate::error::ErrorBody for DeactivateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+            Self::Conflict(_) => Some(reqwest::StatusCode::CONFLICT),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum GetErrorBody {
     Unauthorized(Problem),
     NotFound(Error),
 }
+impl crate::error::ErrorBody for GetErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum UpdateErrorBody {
     Unauthorized(Problem),
     NotFound(Error),
 }
+impl crate::error::ErrorBody for UpdateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum ListAvailablePaymentMethodsErrorBody {
     BadRequest(DetailsError),
 }
+impl crate::error::ErrorBody for ListAvailablePaymentMethodsErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+        }
+    }
+}
+/// Bad Request
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum CreateApplePaySessionBadRequestBody {
+    Error(Error),
+    ErrorList(Vec<Error>),
+    Other(serde_json::Value),
+}
 #[derive(Debug, PartialEq)]
 pub enum CreateApplePaySessionErrorBody {
-    BadRequest(crate::error::UnknownApiBody),
+    BadRequest(CreateApplePaySessionBadRequestBody),
     NotFound(Error),
 }
+impl crate::error::ErrorBody for CreateApplePaySessionErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 /// Client for the Checkouts API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CheckoutsClient<'a> {
     client: &'a Client,
 }
@@ -644,6 +1140,11 @@ impl<'a> CheckoutsClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list`]; any one of them grants access.
+    pub const LIST_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::Payments,
+        crate::scope::Scope::CheckoutsRead,
+    ];
     /// List checkouts
     ///
     /// Lists created checkout resources according to the applied `checkout_reference`.
@@ -651,47 +1152,79 @@ impl<'a> CheckoutsClient<'a> {
     /// Responses:
     /// - 200: Returns a list of checkout resources.
     /// - 401: The request is not authorized.
+    ///
+    /// OAuth 2.0 scopes (any of): `payments`, `checkouts.read`
     pub async fn list(
         &self,
         params: ListParams,
     ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_with_options(
+        &self,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(params, &options).await.map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], additionally returning the metadata of the response.
+    pub async fn list_with_response(
+        &self,
+        params: ListParams,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(params, &Default::default()).await
+    }
+    /// Same as [`Self::list_with_response`], sending the request with the given options.
+    pub async fn list_with_response_and_options(
+        &self,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(params, &options).await
+    }
+    async fn send_list(
+        &self,
+        params: ListParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.client.check_scopes(Self::LIST_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = "/v0.1/checkouts";
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         if let Some(ref value) = params.checkout_reference {
             request = request.query(&[("checkout_reference", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ListResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (ListResponse, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(ListErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::create`]; any one of them grants access.
+    pub const CREATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::Payments,
+        crate::scope::Scope::CheckoutsWrite,
+    ];
     /// Create a checkout
     ///
     /// Creates a new payment checkout resource. The unique `checkout_reference` created by this request, is used for further manipulation of the checkout.
@@ -707,61 +1240,100 @@ impl<'a> CheckoutsClient<'a> {
     /// - 401: The request is not authorized.
     /// - 403: The request isn't sufficiently authorized to create a checkout.
     /// - 409: A checkout already exists for the provided unique parameters.
+    ///
+    /// OAuth 2.0 scopes (any of): `payments`, `checkouts.write`
     pub async fn create(
         &self,
         body: CreateRequest,
     ) -> crate::error::SdkResult<Checkout, CreateErrorBody> {
+        self.send_create(body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn create_with_options(
+        &self,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Checkout, CreateErrorBody> {
+        self.send_create(body, &options).await.map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], additionally returning the metadata of the response.
+    pub async fn create_with_response(
+        &self,
+        body: CreateRequest,
+    ) -> crate::error::SdkResult<(Checkout, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(body, &Default::default()).await
+    }
+    /// Same as [`Self::create_with_response`], sending the request with the given options.
+    pub async fn create_with_response_and_options(
+        &self,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Checkout, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(body, &options).await
+    }
+    async fn send_create(
+        &self,
+        body: CreateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Checkout, crate::response::ResponseMeta), CreateErrorBody> {
+        self.client.check_scopes(Self::CREATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = "/v0.1/checkouts";
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .post(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::POST, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send_deduplicated(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::CREATED => {
-                let data: Checkout = response.json().await?;
-                Ok(data)
+                let (data, meta): (Checkout, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: ErrorExtended = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (ErrorExtended, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: ErrorForbidden = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::Forbidden(
-                    body,
-                )))
+                let (body, meta): (ErrorForbidden, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::Forbidden(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::CONFLICT => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::Conflict(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::Conflict(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::deactivate`]; any one of them grants access.
+    pub const DEACTIVATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::Payments,
+        crate::scope::Scope::CheckoutsWrite,
+    ];
     /// Deactivate a checkout
     ///
     /// Deactivates an identified checkout resource. If the checkout has already been processed it can not be deactivated.
@@ -771,56 +1343,94 @@ impl<'a> CheckoutsClient<'a> {
     /// - 401: The request is not authorized.
     /// - 404: The requested resource does not exist.
     /// - 409: The request conflicts with the current state of the resource.
+    ///
+    /// OAuth 2.0 scopes (any of): `payments`, `checkouts.write`
     pub async fn deactivate(
         &self,
         checkout_id: impl Into<String>,
     ) -> crate::error::SdkResult<Checkout, DeactivateErrorBody> {
+        self.send_deactivate(checkout_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::deactivate`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn deactivate_with_options(
+        &self,
+        checkout_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Checkout, DeactivateErrorBody> {
+        self.send_deactivate(checkout_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::deactivate`], additionally returning the metadata of the response.
+    pub async fn deactivate_with_response(
+        &self,
+        checkout_id: impl Into<String>,
+    ) -> crate::error::SdkResult<(Checkout, crate::response::ResponseMeta), DeactivateErrorBody>
+    {
+        self.send_deactivate(checkout_id, &Default::default()).await
+    }
+    /// Same as [`Self::deactivate_with_response`], sending the request with the given options.
+    pub async fn deactivate_with_response_and_options(
+        &self,
+        checkout_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Checkout, crate::response::ResponseMeta), DeactivateErrorBody>
+    {
+        self.send_deactivate(checkout_id, &options).await
+    }
+    async fn send_deactivate(
+        &self,
+        checkout_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Checkout, crate::response::ResponseMeta), DeactivateErrorBody>
+    {
+        self.client.check_scopes(Self::DEACTIVATE_SCOPES)?;
         let path = format!("/v0.1/checkouts/{}", checkout_id.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .delete(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::DELETE, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Checkout = response.json().await?;
-                Ok(data)
+                let (data, meta): (Checkout, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     DeactivateErrorBody::Unauthorized(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(DeactivateErrorBody::NotFound(
-                    body,
-                )))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    DeactivateErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::CONFLICT => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(DeactivateErrorBody::Conflict(
-                    body,
-                )))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    DeactivateErrorBody::Conflict(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get`]; any one of them grants access.
+    pub const GET_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::Payments,
+        crate::scope::Scope::CheckoutsRead,
+    ];
     /// Retrieve a checkout
     ///
     /// Retrieves an identified checkout resource. Use this request after processing a checkout to confirm its status and inform the end user respectively.
@@ -829,48 +1439,87 @@ impl<'a> CheckoutsClient<'a> {
     /// - 200: Returns the requested checkout resource.
     /// - 401: The request is not authorized.
     /// - 404: The requested resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `payments`, `checkouts.read`
     pub async fn get(
         &self,
         checkout_id: impl Into<String>,
     ) -> crate::error::SdkResult<CheckoutSuccess, GetErrorBody> {
+        self.send_get(checkout_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_with_options(
+        &self,
+        checkout_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<CheckoutSuccess, GetErrorBody> {
+        self.send_get(checkout_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], additionally returning the metadata of the response.
+    pub async fn get_with_response(
+        &self,
+        checkout_id: impl Into<String>,
+    ) -> crate::error::SdkResult<(CheckoutSuccess, crate::response::ResponseMeta), GetErrorBody>
+    {
+        self.send_get(checkout_id, &Default::default()).await
+    }
+    /// Same as [`Self::get_with_response`], sending the request with the given options.
+    pub async fn get_with_response_and_options(
+        &self,
+        checkout_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(CheckoutSuccess, crate::response::ResponseMeta), GetErrorBody>
+    {
+        self.send_get(checkout_id, &options).await
+    }
+    async fn send_get(
+        &self,
+        checkout_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(CheckoutSuccess, crate::response::ResponseMeta), GetErrorBody>
+    {
+        self.client.check_scopes(Self::GET_SCOPES)?;
         let path = format!("/v0.1/checkouts/{}", checkout_id.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: CheckoutSuccess = response.json().await?;
-                Ok(data)
+                let (data, meta): (CheckoutSuccess, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::NotFound(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::update`]; any one of them grants access.
+    pub const UPDATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::Payments,
+        crate::scope::Scope::CheckoutsWrite,
+    ];
     /// Update a checkout
     ///
     /// Updates an identified checkout resource.
@@ -879,50 +1528,91 @@ impl<'a> CheckoutsClient<'a> {
     /// - 200: Returns the updated checkout resource.
     /// - 401: The request is not authorized.
     /// - 404: The requested resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `payments`, `checkouts.write`
     pub async fn update(
         &self,
         checkout_id: impl Into<String>,
         body: UpdateRequest,
     ) -> crate::error::SdkResult<Checkout, UpdateErrorBody> {
+        self.send_update(checkout_id, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn update_with_options(
+        &self,
+        checkout_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Checkout, UpdateErrorBody> {
+        self.send_update(checkout_id, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], additionally returning the metadata of the response.
+    pub async fn update_with_response(
+        &self,
+        checkout_id: impl Into<String>,
+        body: UpdateRequest,
+    ) -> crate::error::SdkResult<(Checkout, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(checkout_id, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::update_with_response`], sending the request with the given options.
+    pub async fn update_with_response_and_options(
+        &self,
+        checkout_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Checkout, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(checkout_id, body, &options).await
+    }
+    async fn send_update(
+        &self,
+        checkout_id: impl Into<String>,
+        body: UpdateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Checkout, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.client.check_scopes(Self::UPDATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!("/v0.1/checkouts/{}", checkout_id.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .patch(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::PATCH, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Checkout = response.json().await?;
-                Ok(data)
+                let (data, meta): (Checkout, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::NotFound(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list_available_payment_methods`]; any one of them grants access.
+    pub const LIST_AVAILABLE_PAYMENT_METHODS_SCOPES: &[crate::scope::Scope] = &[];
     /// Get available payment methods
     ///
     /// Get payment methods available for the given merchant to use with a checkout.
@@ -938,46 +1628,104 @@ impl<'a> CheckoutsClient<'a> {
         ListAvailablePaymentMethodsResponse,
         ListAvailablePaymentMethodsErrorBody,
     > {
+        self.send_list_available_payment_methods(merchant_code, params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list_available_payment_methods`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_available_payment_methods_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListAvailablePaymentMethodsParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        ListAvailablePaymentMethodsResponse,
+        ListAvailablePaymentMethodsErrorBody,
+    > {
+        self.send_list_available_payment_methods(merchant_code, params, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list_available_payment_methods`], additionally returning the metadata of the response.
+    pub async fn list_available_payment_methods_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListAvailablePaymentMethodsParams,
+    ) -> crate::error::SdkResult<
+        (
+            ListAvailablePaymentMethodsResponse,
+            crate::response::ResponseMeta,
+        ),
+        ListAvailablePaymentMethodsErrorBody,
+    > {
+        self.send_list_available_payment_methods(merchant_code, params, &Default::default())
+            .await
+    }
+    /// Same as [`Self::list_available_payment_methods_with_response`], sending the request with the given options.
+    pub async fn list_available_payment_methods_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListAvailablePaymentMethodsParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (
+            ListAvailablePaymentMethodsResponse,
+            crate::response::ResponseMeta,
+        ),
+        ListAvailablePaymentMethodsErrorBody,
+    > {
+        self.send_list_available_payment_methods(merchant_code, params, &options)
+            .await
+    }
+    async fn send_list_available_payment_methods(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListAvailablePaymentMethodsParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (
+            ListAvailablePaymentMethodsResponse,
+            crate::response::ResponseMeta,
+        ),
+        ListAvailablePaymentMethodsErrorBody,
+    > {
+        self.client
+            .check_scopes(Self::LIST_AVAILABLE_PAYMENT_METHODS_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = format!("/v0.1/merchants/{}/payment-methods", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         if let Some(ref value) = params.amount {
             request = request.query(&[("amount", value)]);
         }
         if let Some(ref value) = params.currency {
             request = request.query(&[("currency", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ListAvailablePaymentMethodsResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (ListAvailablePaymentMethodsResponse, _) =
+                    crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: DetailsError = response.json().await?;
+                let (body, meta): (DetailsError, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     ListAvailablePaymentMethodsErrorBody::BadRequest(body),
+                    meta,
                 ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::create_apple_pay_session`]; any one of them grants access.
+    pub const CREATE_APPLE_PAY_SESSION_SCOPES: &[crate::scope::Scope] = &[];
     /// Create an Apple Pay session
     ///
     /// Creates an Apple Pay merchant session for the specified checkout.
@@ -999,47 +1747,95 @@ impl<'a> CheckoutsClient<'a> {
         body: Option<CreateApplePaySessionRequest>,
     ) -> crate::error::SdkResult<CreateApplePaySessionResponse, CreateApplePaySessionErrorBody>
     {
+        self.send_create_apple_pay_session(checkout_id, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create_apple_pay_session`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn create_apple_pay_session_with_options(
+        &self,
+        checkout_id: impl Into<String>,
+        body: Option<CreateApplePaySessionRequest>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<CreateApplePaySessionResponse, CreateApplePaySessionErrorBody>
+    {
+        self.send_create_apple_pay_session(checkout_id, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create_apple_pay_session`], additionally returning the metadata of the response.
+    pub async fn create_apple_pay_session_with_response(
+        &self,
+        checkout_id: impl Into<String>,
+        body: Option<CreateApplePaySessionRequest>,
+    ) -> crate::error::SdkResult<
+        (CreateApplePaySessionResponse, crate::response::ResponseMeta),
+        CreateApplePaySessionErrorBody,
+    > {
+        self.send_create_apple_pay_session(checkout_id, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::create_apple_pay_session_with_response`], sending the request with the given options.
+    pub async fn create_apple_pay_session_with_response_and_options(
+        &self,
+        checkout_id: impl Into<String>,
+        body: Option<CreateApplePaySessionRequest>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (CreateApplePaySessionResponse, crate::response::ResponseMeta),
+        CreateApplePaySessionErrorBody,
+    > {
+        self.send_create_apple_pay_session(checkout_id, body, &options)
+            .await
+    }
+    async fn send_create_apple_pay_session(
+        &self,
+        checkout_id: impl Into<String>,
+        body: Option<CreateApplePaySessionRequest>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (CreateApplePaySessionResponse, crate::response::ResponseMeta),
+        CreateApplePaySessionErrorBody,
+    > {
+        self.client
+            .check_scopes(Self::CREATE_APPLE_PAY_SESSION_SCOPES)?;
+        if let Some(body) = &body {
+            self.client.validate_request(body)?;
+        }
         let path = format!("/v0.2/checkouts/{}/apple-pay-session", checkout_id.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .put(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::PUT, &url, options);
         if let Some(body) = body {
             request = request.json(&body);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: CreateApplePaySessionResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (CreateApplePaySessionResponse, _) =
+                    crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body_bytes = response.bytes().await?;
-                let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
+                let (body, meta): (CreateApplePaySessionBadRequestBody, _) =
+                    crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateApplePaySessionErrorBody::BadRequest(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateApplePaySessionErrorBody::NotFound(body),
+                    meta,
                 ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
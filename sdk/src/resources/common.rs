@@ -2,6 +2,7 @@
 
 /// Object attributes that are modifiable only by SumUp applications.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct Attributes {
     #[serde(
         flatten,
@@ -10,6 +11,37 @@ pub struct Attributes {
     )]
     pub additional_properties: std::collections::HashMap<String, serde_json::Value>,
 }
+impl Attributes {
+    /// Returns a builder for [`Attributes`], taking its required fields.
+    pub fn builder() -> AttributesBuilder {
+        AttributesBuilder {
+            inner: Self {
+                additional_properties: Default::default(),
+            },
+        }
+    }
+}
+/// Builder for [`Attributes`], see [`Attributes::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct AttributesBuilder {
+    inner: Attributes,
+}
+impl AttributesBuilder {
+    /// Properties not described by the API specification.
+    pub fn additional_properties(
+        mut self,
+        additional_properties: impl Into<std::collections::HashMap<String, serde_json::Value>>,
+    ) -> Self {
+        self.inner.additional_properties = additional_properties.into();
+        self
+    }
+    /// Returns the built [`Attributes`].
+    pub fn build(self) -> Attributes {
+        self.inner
+    }
+}
+impl crate::validation::Validate for Attributes {}
 /// Issuing card network of the payment card used for the transaction.
 ///
 /// Example: `VISA`
@@ -90,6 +122,19 @@ pub enum Currency {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for Currency {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 /// Entry mode of the payment details.
 ///
 /// Example: `CUSTOMER_ENTRY`
@@ -151,6 +196,19 @@ pub enum EntryMode {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for EntryMode {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 /// Details of an API error.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Error {
@@ -281,11 +339,25 @@ pub enum MembershipStatus {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for MembershipStatus {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 /// Set of user-defined key-value pairs attached to the object. Partial updates are not supported. When updating, always submit whole metadata. Maximum of 64 parameters are allowed in the object.
 ///
 /// Constraints:
 /// - max properties: 64
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct Metadata {
     #[serde(
         flatten,
@@ -294,6 +366,37 @@ pub struct Metadata {
     )]
     pub additional_properties: std::collections::HashMap<String, serde_json::Value>,
 }
+impl Metadata {
+    /// Returns a builder for [`Metadata`], taking its required fields.
+    pub fn builder() -> MetadataBuilder {
+        MetadataBuilder {
+            inner: Self {
+                additional_properties: Default::default(),
+            },
+        }
+    }
+}
+/// Builder for [`Metadata`], see [`Metadata::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct MetadataBuilder {
+    inner: Metadata,
+}
+impl MetadataBuilder {
+    /// Properties not described by the API specification.
+    pub fn additional_properties(
+        mut self,
+        additional_properties: impl Into<std::collections::HashMap<String, serde_json::Value>>,
+    ) -> Self {
+        self.inner.additional_properties = additional_properties.into();
+        self
+    }
+    /// Returns the built [`Metadata`].
+    pub fn build(self) -> Metadata {
+        self.inner
+    }
+}
+impl crate::validation::Validate for Metadata {}
 /// Payment type used for the transaction.
 ///
 /// Example: `ECOM`
@@ -324,6 +427,19 @@ pub enum PaymentType {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for PaymentType {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 /// A RFC 9457 problem details object.
 ///
 /// Additional properties specific to the problem type may be present.
@@ -395,7 +511,7 @@ pub struct TransactionBase {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     /// The timestamp of when the transaction was created.
@@ -416,6 +532,17 @@ pub struct TransactionBase {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub installments_count: Option<i64>,
 }
+impl TransactionBase {
+    /// Returns `amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+}
 /// Checkout-specific fields associated with a transaction.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct TransactionCheckoutInfo {
@@ -428,12 +555,12 @@ pub struct TransactionCheckoutInfo {
     ///
     /// Example: `6`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_amount: Option<f32>,
+    pub vat_amount: Option<crate::money::Amount>,
     /// Amount of the tip (out of the total transaction amount).
     ///
     /// Example: `3`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tip_amount: Option<f32>,
+    pub tip_amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entry_mode: Option<EntryMode>,
     /// Authorization code for the transaction sent by the payment card issuer or bank. Applicable only to card payments.
@@ -8,6 +8,7 @@
 use super::common::*;
 /// Profile's personal address information.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct AddressLegacy {
     /// City name from the address.
     ///
@@ -40,6 +41,76 @@ pub struct AddressLegacy {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
 }
+impl AddressLegacy {
+    /// Returns a builder for [`AddressLegacy`], taking its required fields.
+    pub fn builder() -> AddressLegacyBuilder {
+        AddressLegacyBuilder {
+            inner: Self {
+                city: None,
+                country: None,
+                line_1: None,
+                line_2: None,
+                postal_code: None,
+                state: None,
+            },
+        }
+    }
+}
+/// Builder for [`AddressLegacy`], see [`AddressLegacy::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct AddressLegacyBuilder {
+    inner: AddressLegacy,
+}
+impl AddressLegacyBuilder {
+    /// City name from the address.
+    ///
+    /// Example: `Berlin`
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.inner.city = Some(city.into());
+        self
+    }
+    /// Two letter country code formatted according to [ISO3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
+    ///
+    /// Example: `DE`
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.inner.country = Some(country.into());
+        self
+    }
+    /// First line of the address with details of the street name and number.
+    ///
+    /// Example: `Sample street`
+    pub fn line_1(mut self, line_1: impl Into<String>) -> Self {
+        self.inner.line_1 = Some(line_1.into());
+        self
+    }
+    /// Second line of the address with details of the building, unit, apartment, and floor numbers.
+    ///
+    /// Example: `ap. 5`
+    pub fn line_2(mut self, line_2: impl Into<String>) -> Self {
+        self.inner.line_2 = Some(line_2.into());
+        self
+    }
+    /// Postal code from the address.
+    ///
+    /// Example: `10115`
+    pub fn postal_code(mut self, postal_code: impl Into<String>) -> Self {
+        self.inner.postal_code = Some(postal_code.into());
+        self
+    }
+    /// State name or abbreviation from the address.
+    ///
+    /// Example: `Berlin`
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.inner.state = Some(state.into());
+        self
+    }
+    /// Returns the built [`AddressLegacy`].
+    pub fn build(self) -> AddressLegacy {
+        self.inner
+    }
+}
+impl crate::validation::Validate for AddressLegacy {}
 /// Saved customer details.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Customer {
@@ -86,6 +157,7 @@ pub struct PaymentInstrumentResponse {
 }
 /// Personal details for the customer.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct PersonalDetails {
     /// First name of the customer.
     ///
@@ -123,6 +195,96 @@ pub struct PersonalDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub address: Option<AddressLegacy>,
 }
+impl PersonalDetails {
+    /// Returns a builder for [`PersonalDetails`], taking its required fields.
+    pub fn builder() -> PersonalDetailsBuilder {
+        PersonalDetailsBuilder {
+            inner: Self {
+                first_name: None,
+                last_name: None,
+                email: None,
+                phone: None,
+                birth_date: None,
+                tax_id: None,
+                address: None,
+            },
+        }
+    }
+}
+/// Builder for [`PersonalDetails`], see [`PersonalDetails::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct PersonalDetailsBuilder {
+    inner: PersonalDetails,
+}
+impl PersonalDetailsBuilder {
+    /// First name of the customer.
+    ///
+    /// Example: `John`
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.inner.first_name = Some(first_name.into());
+        self
+    }
+    /// Last name of the customer.
+    ///
+    /// Example: `Doe`
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.inner.last_name = Some(last_name.into());
+        self
+    }
+    /// Email address of the customer.
+    ///
+    /// Example: `user@example.com`
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.inner.email = Some(email.into());
+        self
+    }
+    /// Phone number of the customer.
+    ///
+    /// Example: `+491635559723`
+    pub fn phone(mut self, phone: impl Into<String>) -> Self {
+        self.inner.phone = Some(phone.into());
+        self
+    }
+    /// Date of birth of the customer.
+    ///
+    /// Example: `1993-12-31`
+    pub fn birth_date(mut self, birth_date: impl Into<crate::datetime::Date>) -> Self {
+        self.inner.birth_date = Some(birth_date.into());
+        self
+    }
+    /// Identification number used for tax purposes, such as a CPF in Brazil.
+    ///
+    /// Constraints:
+    /// - max length: 255
+    ///
+    /// Example: `423.378.593-47`
+    pub fn tax_id(mut self, tax_id: impl Into<String>) -> Self {
+        self.inner.tax_id = Some(tax_id.into());
+        self
+    }
+    /// Profile's personal address information.
+    pub fn address(mut self, address: impl Into<AddressLegacy>) -> Self {
+        self.inner.address = Some(address.into());
+        self
+    }
+    /// Returns the built [`PersonalDetails`].
+    pub fn build(self) -> PersonalDetails {
+        self.inner
+    }
+}
+impl crate::validation::Validate for PersonalDetails {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.tax_id {
+            let path = crate::validation::field_path(path, "tax_id");
+            crate::validation::check_length(value, None, Some(255), &path, violations);
+        }
+        if let Some(value) = &self.address {
+            let path = crate::validation::field_path(path, "address");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 /// Type of the payment instrument.
 ///
 /// Example: `card`
@@ -152,6 +314,7 @@ pub struct PaymentInstrumentResponseCard {
 }
 /// Details of the customer.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateRequest {
     /// Unique identifier of the customer.
     ///
@@ -160,40 +323,172 @@ pub struct CreateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub personal_details: Option<PersonalDetails>,
 }
+impl CreateRequest {
+    /// Returns a builder for [`CreateRequest`], taking its required fields.
+    pub fn builder(customer_id: impl Into<String>) -> CreateRequestBuilder {
+        CreateRequestBuilder {
+            inner: Self {
+                customer_id: customer_id.into(),
+                personal_details: None,
+            },
+        }
+    }
+}
+/// Builder for [`CreateRequest`], see [`CreateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateRequestBuilder {
+    inner: CreateRequest,
+}
+impl CreateRequestBuilder {
+    /// Personal details for the customer.
+    pub fn personal_details(mut self, personal_details: impl Into<PersonalDetails>) -> Self {
+        self.inner.personal_details = Some(personal_details.into());
+        self
+    }
+    /// Returns the built [`CreateRequest`].
+    pub fn build(self) -> CreateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.personal_details {
+            let path = crate::validation::field_path(path, "personal_details");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 /// Customer fields to update.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct UpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub personal_details: Option<PersonalDetails>,
 }
+impl UpdateRequest {
+    /// Returns a builder for [`UpdateRequest`], taking its required fields.
+    pub fn builder() -> UpdateRequestBuilder {
+        UpdateRequestBuilder {
+            inner: Self {
+                personal_details: None,
+            },
+        }
+    }
+}
+/// Builder for [`UpdateRequest`], see [`UpdateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct UpdateRequestBuilder {
+    inner: UpdateRequest,
+}
+impl UpdateRequestBuilder {
+    /// Personal details for the customer.
+    pub fn personal_details(mut self, personal_details: impl Into<PersonalDetails>) -> Self {
+        self.inner.personal_details = Some(personal_details.into());
+        self
+    }
+    /// Returns the built [`UpdateRequest`].
+    pub fn build(self) -> UpdateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for UpdateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.personal_details {
+            let path = crate::validation::field_path(path, "personal_details");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 /// Returns the list of saved payment instruments for the customer.
 pub type ListPaymentInstrumentsResponse = Vec<PaymentInstrumentResponse>;
 use crate::client::Client;
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CreateBadRequestBodyObject {
+    /// Unique identifier of this error occurrence.
+    ///
+    /// Example: `32a44c6c-85d3-49e8-86bf-a5bba98c4621`
+    pub instance: String,
+    /// Platform code for the error.
+    ///
+    /// Example: `INVALID`
+    pub error_code: String,
+    /// Short description of the error.
+    ///
+    /// Example: `customer_id`
+    pub error_message: String,
+}
+/// The request body is invalid.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum CreateBadRequestBody {
+    Object(CreateBadRequestBodyObject),
+    ErrorExtended(ErrorExtended),
+    Other(serde_json::Value),
+}
 #[derive(Debug, PartialEq)]
 pub enum CreateErrorBody {
-    BadRequest(crate::error::UnknownApiBody),
+    BadRequest(CreateBadRequestBody),
     Unauthorized(Problem),
     Forbidden(ErrorForbidden),
     Conflict(Error),
 }
+impl crate::error::ErrorBody for CreateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::Conflict(_) => Some(reqwest::StatusCode::CONFLICT),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum GetErrorBody {
     Unauthorized(Problem),
     Forbidden(ErrorForbidden),
     NotFound(Error),
 }
+impl crate::error::ErrorBody for GetErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum UpdateErrorBody {
     Unauthorized(Problem),
     Forbidden(ErrorForbidden),
     NotFound(Error),
 }
+impl crate::error::ErrorBody for UpdateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum ListPaymentInstrumentsErrorBody {
     Unauthorized(Problem),
     Forbidden(ErrorForbidden),
     NotFound(Error),
 }
+impl crate::error::ErrorBody for ListPaymentInstrumentsErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum DeactivatePaymentInstrumentErrorBody {
     BadRequest(Error),
@@ -201,8 +496,18 @@ pub enum DeactivatePaymentInstrumentErrorBody {
     Forbidden(ErrorForbidden),
     NotFound(Error),
 }
+impl crate::error::ErrorBody for DeactivatePaymentInstrumentErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 /// Client for the Customers API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CustomersClient<'a> {
     client: &'a Client,
 }
@@ -214,6 +519,11 @@ impl<'a> CustomersClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::create`]; any one of them grants access.
+    pub const CREATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::PaymentInstruments,
+        crate::scope::Scope::CustomersWrite,
+    ];
     /// Create a customer
     ///
     /// Creates a new saved customer resource which you can later manipulate and save payment instruments to.
@@ -224,62 +534,101 @@ impl<'a> CustomersClient<'a> {
     /// - 401: The request is not authorized.
     /// - 403: The request is authenticated but not permitted for this operation.
     /// - 409: A customer with the provided identifier already exists.
+    ///
+    /// OAuth 2.0 scopes (any of): `payment_instruments`, `customers.write`
     pub async fn create(
         &self,
         body: CreateRequest,
     ) -> crate::error::SdkResult<Customer, CreateErrorBody> {
+        self.send_create(body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn create_with_options(
+        &self,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Customer, CreateErrorBody> {
+        self.send_create(body, &options).await.map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], additionally returning the metadata of the response.
+    pub async fn create_with_response(
+        &self,
+        body: CreateRequest,
+    ) -> crate::error::SdkResult<(Customer, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(body, &Default::default()).await
+    }
+    /// Same as [`Self::create_with_response`], sending the request with the given options.
+    pub async fn create_with_response_and_options(
+        &self,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Customer, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(body, &options).await
+    }
+    async fn send_create(
+        &self,
+        body: CreateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Customer, crate::response::ResponseMeta), CreateErrorBody> {
+        self.client.check_scopes(Self::CREATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = "/v0.1/customers";
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .post(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::POST, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::CREATED => {
-                let data: Customer = response.json().await?;
-                Ok(data)
+                let (data, meta): (Customer, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body_bytes = response.bytes().await?;
-                let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::api(CreateErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (CreateBadRequestBody, _) =
+                    crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: ErrorForbidden = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::Forbidden(
-                    body,
-                )))
+                let (body, meta): (ErrorForbidden, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::Forbidden(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::CONFLICT => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::Conflict(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::Conflict(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get`]; any one of them grants access.
+    pub const GET_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::PaymentInstruments,
+        crate::scope::Scope::CustomersRead,
+    ];
     /// Retrieve a customer
     ///
     /// Retrieves an identified saved customer resource through the unique `customer_id` parameter, generated upon customer creation.
@@ -289,52 +638,91 @@ impl<'a> CustomersClient<'a> {
     /// - 401: The request is not authorized.
     /// - 403: The request is authenticated but not permitted for this operation.
     /// - 404: The requested resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `payment_instruments`, `customers.read`
     pub async fn get(
         &self,
         customer_id: impl Into<String>,
     ) -> crate::error::SdkResult<Customer, GetErrorBody> {
+        self.send_get(customer_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_with_options(
+        &self,
+        customer_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Customer, GetErrorBody> {
+        self.send_get(customer_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], additionally returning the metadata of the response.
+    pub async fn get_with_response(
+        &self,
+        customer_id: impl Into<String>,
+    ) -> crate::error::SdkResult<(Customer, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(customer_id, &Default::default()).await
+    }
+    /// Same as [`Self::get_with_response`], sending the request with the given options.
+    pub async fn get_with_response_and_options(
+        &self,
+        customer_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Customer, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(customer_id, &options).await
+    }
+    async fn send_get(
+        &self,
+        customer_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Customer, crate::response::ResponseMeta), GetErrorBody> {
+        self.client.check_scopes(Self::GET_SCOPES)?;
         let path = format!("/v0.1/customers/{}", customer_id.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Customer = response.json().await?;
-                Ok(data)
+                let (data, meta): (Customer, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: ErrorForbidden = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::Forbidden(body)))
+                let (body, meta): (ErrorForbidden, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::Forbidden(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::NotFound(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::update`]; any one of them grants access.
+    pub const UPDATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::PaymentInstruments,
+        crate::scope::Scope::CustomersWrite,
+    ];
     /// Update a customer
     ///
     /// Updates an identified saved customer resource's personal details.
@@ -346,56 +734,101 @@ impl<'a> CustomersClient<'a> {
     /// - 401: The request is not authorized.
     /// - 403: The request is authenticated but not permitted for this operation.
     /// - 404: The requested resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `payment_instruments`, `customers.write`
     pub async fn update(
         &self,
         customer_id: impl Into<String>,
         body: UpdateRequest,
     ) -> crate::error::SdkResult<Customer, UpdateErrorBody> {
+        self.send_update(customer_id, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn update_with_options(
+        &self,
+        customer_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Customer, UpdateErrorBody> {
+        self.send_update(customer_id, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], additionally returning the metadata of the response.
+    pub async fn update_with_response(
+        &self,
+        customer_id: impl Into<String>,
+        body: UpdateRequest,
+    ) -> crate::error::SdkResult<(Customer, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(customer_id, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::update_with_response`], sending the request with the given options.
+    pub async fn update_with_response_and_options(
+        &self,
+        customer_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Customer, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(customer_id, body, &options).await
+    }
+    async fn send_update(
+        &self,
+        customer_id: impl Into<String>,
+        body: UpdateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Customer, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.client.check_scopes(Self::UPDATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!("/v0.1/customers/{}", customer_id.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .put(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::PUT, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Customer = response.json().await?;
-                Ok(data)
+                let (data, meta): (Customer, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: ErrorForbidden = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::Forbidden(
-                    body,
-                )))
+                let (body, meta): (ErrorForbidden, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::Forbidden(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::NotFound(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list_payment_instruments`]; any one of them grants access.
+    pub const LIST_PAYMENT_INSTRUMENTS_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::PaymentInstruments,
+        crate::scope::Scope::CustomersRead,
+    ];
     /// List payment instruments
     ///
     /// Lists all payment instrument resources that are saved for an identified customer.
@@ -405,57 +838,115 @@ impl<'a> CustomersClient<'a> {
     /// - 401: The request is not authorized.
     /// - 403: The request is authenticated but not permitted for this operation.
     /// - 404: The requested resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `payment_instruments`, `customers.read`
     pub async fn list_payment_instruments(
         &self,
         customer_id: impl Into<String>,
     ) -> crate::error::SdkResult<ListPaymentInstrumentsResponse, ListPaymentInstrumentsErrorBody>
     {
+        self.send_list_payment_instruments(customer_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list_payment_instruments`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_payment_instruments_with_options(
+        &self,
+        customer_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<ListPaymentInstrumentsResponse, ListPaymentInstrumentsErrorBody>
+    {
+        self.send_list_payment_instruments(customer_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list_payment_instruments`], additionally returning the metadata of the response.
+    pub async fn list_payment_instruments_with_response(
+        &self,
+        customer_id: impl Into<String>,
+    ) -> crate::error::SdkResult<
+        (
+            ListPaymentInstrumentsResponse,
+            crate::response::ResponseMeta,
+        ),
+        ListPaymentInstrumentsErrorBody,
+    > {
+        self.send_list_payment_instruments(customer_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::list_payment_instruments_with_response`], sending the request with the given options.
+    pub async fn list_payment_instruments_with_response_and_options(
+        &self,
+        customer_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (
+            ListPaymentInstrumentsResponse,
+            crate::response::ResponseMeta,
+        ),
+        ListPaymentInstrumentsErrorBody,
+    > {
+        self.send_list_payment_instruments(customer_id, &options)
+            .await
+    }
+    async fn send_list_payment_instruments(
+        &self,
+        customer_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (
+            ListPaymentInstrumentsResponse,
+            crate::response::ResponseMeta,
+        ),
+        ListPaymentInstrumentsErrorBody,
+    > {
+        self.client
+            .check_scopes(Self::LIST_PAYMENT_INSTRUMENTS_SCOPES)?;
         let path = format!("/v0.1/customers/{}/payment-instruments", customer_id.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ListPaymentInstrumentsResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (ListPaymentInstrumentsResponse, _) =
+                    crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     ListPaymentInstrumentsErrorBody::Unauthorized(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: ErrorForbidden = response.json().await?;
+                let (body, meta): (ErrorForbidden, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     ListPaymentInstrumentsErrorBody::Forbidden(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     ListPaymentInstrumentsErrorBody::NotFound(body),
+                    meta,
                 ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::deactivate_payment_instrument`]; any one of them grants access.
+    pub const DEACTIVATE_PAYMENT_INSTRUMENT_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::PaymentInstruments,
+        crate::scope::Scope::CustomersWrite,
+    ];
     /// Deactivate a payment instrument
     ///
     /// Deactivates an identified card payment instrument resource for a customer.
@@ -466,61 +957,110 @@ impl<'a> CustomersClient<'a> {
     /// - 401: The request is not authorized.
     /// - 403: The request is authenticated but not permitted for this operation.
     /// - 404: The requested resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `payment_instruments`, `customers.write`
     pub async fn deactivate_payment_instrument(
         &self,
         customer_id: impl Into<String>,
         token: impl Into<String>,
     ) -> crate::error::SdkResult<(), DeactivatePaymentInstrumentErrorBody> {
+        self.send_deactivate_payment_instrument(customer_id, token, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::deactivate_payment_instrument`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn deactivate_payment_instrument_with_options(
+        &self,
+        customer_id: impl Into<String>,
+        token: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(), DeactivatePaymentInstrumentErrorBody> {
+        self.send_deactivate_payment_instrument(customer_id, token, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::deactivate_payment_instrument`], additionally returning the metadata of the response.
+    pub async fn deactivate_payment_instrument_with_response(
+        &self,
+        customer_id: impl Into<String>,
+        token: impl Into<String>,
+    ) -> crate::error::SdkResult<
+        ((), crate::response::ResponseMeta),
+        DeactivatePaymentInstrumentErrorBody,
+    > {
+        self.send_deactivate_payment_instrument(customer_id, token, &Default::default())
+            .await
+    }
+    /// Same as [`Self::deactivate_payment_instrument_with_response`], sending the request with the given options.
+    pub async fn deactivate_payment_instrument_with_response_and_options(
+        &self,
+        customer_id: impl Into<String>,
+        token: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        ((), crate::response::ResponseMeta),
+        DeactivatePaymentInstrumentErrorBody,
+    > {
+        self.send_deactivate_payment_instrument(customer_id, token, &options)
+            .await
+    }
+    async fn send_deactivate_payment_instrument(
+        &self,
+        customer_id: impl Into<String>,
+        token: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        ((), crate::response::ResponseMeta),
+        DeactivatePaymentInstrumentErrorBody,
+    > {
+        self.client
+            .check_scopes(Self::DEACTIVATE_PAYMENT_INSTRUMENT_SCOPES)?;
         let path = format!(
             "/v0.1/customers/{}/payment-instruments/{}",
             customer_id.into(),
             token.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .delete(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::DELETE, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
-            reqwest::StatusCode::NO_CONTENT => Ok(()),
+            reqwest::StatusCode::NO_CONTENT => {
+                Ok(((), crate::response::ResponseMeta::from_response(&response)))
+            }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Error = response.json().await?;
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     DeactivatePaymentInstrumentErrorBody::BadRequest(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     DeactivatePaymentInstrumentErrorBody::Unauthorized(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: ErrorForbidden = response.json().await?;
+                let (body, meta): (ErrorForbidden, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     DeactivatePaymentInstrumentErrorBody::Forbidden(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     DeactivatePaymentInstrumentErrorBody::NotFound(body),
+                    meta,
                 ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
@@ -104,6 +104,7 @@ pub enum UserType {
 }
 /// Allows you to update user data of managed users.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct UpdateRequestUser {
     /// User's nickname. Used for display purposes only.
     ///
@@ -120,7 +121,61 @@ pub struct UpdateRequestUser {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<crate::secret::Secret>,
 }
+impl UpdateRequestUser {
+    /// Returns a builder for [`UpdateRequestUser`], taking its required fields.
+    pub fn builder() -> UpdateRequestUserBuilder {
+        UpdateRequestUserBuilder {
+            inner: Self {
+                nickname: None,
+                password: None,
+            },
+        }
+    }
+}
+/// Builder for [`UpdateRequestUser`], see [`UpdateRequestUser::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct UpdateRequestUserBuilder {
+    inner: UpdateRequestUser,
+}
+impl UpdateRequestUserBuilder {
+    /// User's nickname. Used for display purposes only.
+    ///
+    /// Constraints:
+    /// - max length: 64
+    ///
+    /// Example: `Test User`
+    pub fn nickname(mut self, nickname: impl Into<String>) -> Self {
+        self.inner.nickname = Some(nickname.into());
+        self
+    }
+    /// Password of the member to add. Only used if `is_managed_user` is true.
+    ///
+    /// Constraints:
+    /// - min length: 8
+    pub fn password(mut self, password: impl Into<crate::secret::Secret>) -> Self {
+        self.inner.password = Some(password.into());
+        self
+    }
+    /// Returns the built [`UpdateRequestUser`].
+    pub fn build(self) -> UpdateRequestUser {
+        self.inner
+    }
+}
+impl crate::validation::Validate for UpdateRequestUser {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.nickname {
+            let path = crate::validation::field_path(path, "nickname");
+            crate::validation::check_length(value, None, Some(64), &path, violations);
+        }
+        if let Some(value) = &self.password {
+            let path = crate::validation::field_path(path, "password");
+            crate::validation::check_length(value, Some(8), None, &path, violations);
+        }
+    }
+}
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct ListParams {
     /// Offset of the first member to return.
     ///
@@ -165,6 +220,111 @@ pub struct ListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<String>>,
 }
+impl ListParams {
+    /// Returns a builder for [`ListParams`], taking its required fields.
+    pub fn builder() -> ListParamsBuilder {
+        ListParamsBuilder {
+            inner: Self {
+                offset: None,
+                limit: None,
+                scroll: None,
+                email: None,
+                user_id: None,
+                status: None,
+                roles: None,
+            },
+        }
+    }
+}
+/// Builder for [`ListParams`], see [`ListParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ListParamsBuilder {
+    inner: ListParams,
+}
+impl ListParamsBuilder {
+    /// Offset of the first member to return.
+    ///
+    /// Constraints:
+    /// - value >= 0
+    ///
+    /// Example: `0`
+    pub fn offset(mut self, offset: impl Into<i64>) -> Self {
+        self.inner.offset = Some(offset.into());
+        self
+    }
+    /// Maximum number of members to return.
+    ///
+    /// Constraints:
+    /// - value >= 1
+    /// - value <= 25
+    ///
+    /// Example: `10`
+    pub fn limit(mut self, limit: impl Into<i64>) -> Self {
+        self.inner.limit = Some(limit.into());
+        self
+    }
+    /// Indicates to skip count query.
+    ///
+    /// Example: `true`
+    pub fn scroll(mut self, scroll: impl Into<bool>) -> Self {
+        self.inner.scroll = Some(scroll.into());
+        self
+    }
+    /// Filter the returned members by email address prefix.
+    ///
+    /// Example: `user`
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.inner.email = Some(email.into());
+        self
+    }
+    /// Search for a member by user id.
+    ///
+    /// Constraints:
+    /// - format: `uuid`
+    ///
+    /// Example: `245b2ead-85bf-45ff-856f-311a88a5d454`
+    pub fn user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.inner.user_id = Some(user_id.into());
+        self
+    }
+    /// Filter the returned members by the membership status.
+    pub fn status(mut self, status: impl Into<MembershipStatus>) -> Self {
+        self.inner.status = Some(status.into());
+        self
+    }
+    /// Filter the returned members by role.
+    pub fn roles(mut self, roles: impl Into<Vec<String>>) -> Self {
+        self.inner.roles = Some(roles.into());
+        self
+    }
+    /// Returns the built [`ListParams`].
+    pub fn build(self) -> ListParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for ListParams {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.offset {
+            let path = crate::validation::field_path(path, "offset");
+            crate::validation::check_range(value, Some((0.0, false)), None, &path, violations);
+        }
+        if let Some(value) = &self.limit {
+            let path = crate::validation::field_path(path, "limit");
+            crate::validation::check_range(
+                value,
+                Some((1.0, false)),
+                Some((25.0, false)),
+                &path,
+                violations,
+            );
+        }
+        if let Some(value) = &self.status {
+            let path = crate::validation::field_path(path, "status");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 /// Returns a list of Member objects.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ListResponse {
@@ -173,6 +333,7 @@ pub struct ListResponse {
     pub total_count: Option<i64>,
 }
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateRequest {
     /// True if the user is managed by the merchant. In this case, we'll created a virtual user with the provided password and nickname.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -207,7 +368,106 @@ pub struct CreateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<Attributes>,
 }
+impl CreateRequest {
+    /// Returns a builder for [`CreateRequest`], taking its required fields.
+    pub fn builder(
+        email: impl Into<String>,
+        roles: impl Into<Vec<String>>,
+    ) -> CreateRequestBuilder {
+        CreateRequestBuilder {
+            inner: Self {
+                is_managed_user: None,
+                email: email.into(),
+                password: None,
+                nickname: None,
+                roles: roles.into(),
+                metadata: None,
+                attributes: None,
+            },
+        }
+    }
+}
+/// Builder for [`CreateRequest`], see [`CreateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateRequestBuilder {
+    inner: CreateRequest,
+}
+impl CreateRequestBuilder {
+    /// True if the user is managed by the merchant. In this case, we'll created a virtual user with the provided password and nickname.
+    pub fn is_managed_user(mut self, is_managed_user: impl Into<bool>) -> Self {
+        self.inner.is_managed_user = Some(is_managed_user.into());
+        self
+    }
+    /// Password of the member to add. Only used if `is_managed_user` is true. In the case of service accounts, the password is not used and can not be defined by the caller.
+    ///
+    /// Constraints:
+    /// - min length: 8
+    pub fn password(mut self, password: impl Into<crate::secret::Secret>) -> Self {
+        self.inner.password = Some(password.into());
+        self
+    }
+    /// Nickname of the member to add. Only used if `is_managed_user` is true. Used for display purposes only.
+    ///
+    /// Constraints:
+    /// - max length: 64
+    ///
+    /// Example: `Test User`
+    pub fn nickname(mut self, nickname: impl Into<String>) -> Self {
+        self.inner.nickname = Some(nickname.into());
+        self
+    }
+    /// Set of user-defined key-value pairs attached to the object. Partial updates are not supported. When updating, always submit whole metadata. Maximum of 64 parameters are allowed in the object.
+    pub fn metadata(mut self, metadata: impl Into<Metadata>) -> Self {
+        self.inner.metadata = Some(metadata.into());
+        self
+    }
+    /// Object attributes that are modifiable only by SumUp applications.
+    pub fn attributes(mut self, attributes: impl Into<Attributes>) -> Self {
+        self.inner.attributes = Some(attributes.into());
+        self
+    }
+    /// Returns the built [`CreateRequest`].
+    pub fn build(self) -> CreateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        {
+            let value = &self.email;
+            let path = crate::validation::field_path(path, "email");
+            crate::validation::check_length(value, None, Some(256), &path, violations);
+        }
+        if let Some(value) = &self.password {
+            let path = crate::validation::field_path(path, "password");
+            crate::validation::check_length(value, Some(8), None, &path, violations);
+        }
+        if let Some(value) = &self.nickname {
+            let path = crate::validation::field_path(path, "nickname");
+            crate::validation::check_length(value, None, Some(64), &path, violations);
+        }
+        {
+            let value = &self.roles;
+            let path = crate::validation::field_path(path, "roles");
+            crate::validation::check_items(value.len(), None, Some(124), &path, violations);
+            for (index, value) in value.iter().enumerate() {
+                let path = crate::validation::item_path(&path, index);
+                crate::validation::check_length(value, None, Some(64), &path, violations);
+            }
+        }
+        if let Some(value) = &self.metadata {
+            let path = crate::validation::field_path(path, "metadata");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.attributes {
+            let path = crate::validation::field_path(path, "attributes");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct UpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<String>>,
@@ -219,26 +479,126 @@ pub struct UpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<UpdateRequestUser>,
 }
+impl UpdateRequest {
+    /// Returns a builder for [`UpdateRequest`], taking its required fields.
+    pub fn builder() -> UpdateRequestBuilder {
+        UpdateRequestBuilder {
+            inner: Self {
+                roles: None,
+                metadata: None,
+                attributes: None,
+                user: None,
+            },
+        }
+    }
+}
+/// Builder for [`UpdateRequest`], see [`UpdateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct UpdateRequestBuilder {
+    inner: UpdateRequest,
+}
+impl UpdateRequestBuilder {
+    /// Sets the `roles` property.
+    pub fn roles(mut self, roles: impl Into<Vec<String>>) -> Self {
+        self.inner.roles = Some(roles.into());
+        self
+    }
+    /// Set of user-defined key-value pairs attached to the object. Partial updates are not supported. When updating, always submit whole metadata. Maximum of 64 parameters are allowed in the object.
+    pub fn metadata(mut self, metadata: impl Into<Metadata>) -> Self {
+        self.inner.metadata = Some(metadata.into());
+        self
+    }
+    /// Object attributes that are modifiable only by SumUp applications.
+    pub fn attributes(mut self, attributes: impl Into<Attributes>) -> Self {
+        self.inner.attributes = Some(attributes.into());
+        self
+    }
+    /// Allows you to update user data of managed users.
+    pub fn user(mut self, user: impl Into<UpdateRequestUser>) -> Self {
+        self.inner.user = Some(user.into());
+        self
+    }
+    /// Returns the built [`UpdateRequest`].
+    pub fn build(self) -> UpdateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for UpdateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.roles {
+            let path = crate::validation::field_path(path, "roles");
+            crate::validation::check_items(value.len(), None, Some(124), &path, violations);
+            for (index, value) in value.iter().enumerate() {
+                let path = crate::validation::item_path(&path, index);
+                crate::validation::check_length(value, None, Some(64), &path, violations);
+            }
+        }
+        if let Some(value) = &self.metadata {
+            let path = crate::validation::field_path(path, "metadata");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.attributes {
+            let path = crate::validation::field_path(path, "attributes");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.user {
+            let path = crate::validation::field_path(path, "user");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 use crate::client::Client;
 #[derive(Debug, PartialEq)]
 pub enum ListErrorBody {
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for ListErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum CreateErrorBody {
     BadRequest(Problem),
     NotFound(Problem),
     TooManyRequests(Problem),
 }
+impl crate::error::ErrorBody for CreateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+            Self::TooManyRequests(_) => Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum DeleteErrorBody {
     Forbidden(Problem),
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for DeleteErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum GetErrorBody {
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for GetErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum UpdateErrorBody {
     BadRequest(Problem),
@@ -246,8 +606,18 @@ pub enum UpdateErrorBody {
     NotFound(Problem),
     Conflict(Problem),
 }
+impl crate::error::ErrorBody for UpdateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+            Self::Conflict(_) => Some(reqwest::StatusCode::CONFLICT),
+        }
+    }
+}
 /// Client for the Members API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MembersClient<'a> {
     client: &'a Client,
 }
@@ -259,6 +629,11 @@ impl<'a> MembersClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list`]; any one of them grants access.
+    pub const LIST_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::MembersRead,
+    ];
     /// List members
     ///
     /// Lists merchant members.
@@ -266,25 +641,57 @@ impl<'a> MembersClient<'a> {
     /// Responses:
     /// - 200: Returns a list of Member objects.
     /// - 404: Merchant not found.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `members.read`
     pub async fn list(
         &self,
         merchant_code: impl Into<String>,
         params: ListParams,
     ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(merchant_code, params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(merchant_code, params, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], additionally returning the metadata of the response.
+    pub async fn list_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(merchant_code, params, &Default::default())
+            .await
+    }
+    /// Same as [`Self::list_with_response`], sending the request with the given options.
+    pub async fn list_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(merchant_code, params, &options).await
+    }
+    async fn send_list(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.client.check_scopes(Self::LIST_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = format!("/v0.1/merchants/{}/members", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         if let Some(ref value) = params.offset {
             request = request.query(&[("offset", value)]);
         }
@@ -303,27 +710,62 @@ impl<'a> MembersClient<'a> {
         if let Some(ref value) = params.status {
             request = request.query(&[("status", value)]);
         }
-        if let Some(ref value) = params.roles {
+        for value in params.roles.as_ref().into_iter().flatten() {
             request = request.query(&[("roles", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ListResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (ListResponse, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(ListErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// Streams the items of [`Self::list`] across all pages, starting at `params.offset`.
+    pub fn list_all(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+    ) -> crate::pagination::Paginator<'a, Member, ListErrorBody, i64> {
+        let client = self.clone();
+        let merchant_code: String = merchant_code.into();
+        let limit = params.limit;
+        crate::pagination::Paginator::offset(
+            params.offset.unwrap_or_default(),
+            limit,
+            move |offset| {
+                let client = client.clone();
+                let merchant_code = merchant_code.clone();
+                let params = ListParams {
+                    offset: Some(offset),
+                    ..params.clone()
+                };
+                async move {
+                    let page = client.list(merchant_code, params).await?;
+                    Ok((page.items, page.total_count))
+                }
+            },
+        )
+    }
+    /// OAuth 2.0 scopes accepted by [`Self::create`]; any one of them grants access.
+    pub const CREATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::MembersWrite,
+    ];
     /// Create a member
     ///
     /// Create a merchant member.
@@ -334,56 +776,101 @@ impl<'a> MembersClient<'a> {
     /// - 404: Merchant not found.
     /// - 429: Too many invitations were sent to that user and the rate limit was exceeded. The
     ///   Retry-After header indicates when the client can retry.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `members.write`
     pub async fn create(
         &self,
         merchant_code: impl Into<String>,
         body: CreateRequest,
     ) -> crate::error::SdkResult<Member, CreateErrorBody> {
+        self.send_create(merchant_code, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn create_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Member, CreateErrorBody> {
+        self.send_create(merchant_code, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], additionally returning the metadata of the response.
+    pub async fn create_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+    ) -> crate::error::SdkResult<(Member, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(merchant_code, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::create_with_response`], sending the request with the given options.
+    pub async fn create_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Member, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(merchant_code, body, &options).await
+    }
+    async fn send_create(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Member, crate::response::ResponseMeta), CreateErrorBody> {
+        self.client.check_scopes(Self::CREATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!("/v0.1/merchants/{}/members", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .post(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::POST, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::CREATED => {
-                let data: Member = response.json().await?;
-                Ok(data)
+                let (data, meta): (Member, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::TOO_MANY_REQUESTS => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateErrorBody::TooManyRequests(body),
+                    meta,
                 ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::delete`]; any one of them grants access.
+    pub const DELETE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::MembersWrite,
+    ];
     /// Delete a member
     ///
     /// Deletes a merchant member.
@@ -392,50 +879,93 @@ impl<'a> MembersClient<'a> {
     /// - 200: Returns an empty response if the deletion succeeded.
     /// - 403: Member deletion was forbidden.
     /// - 404: Merchant or member not found.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `members.write`
     pub async fn delete(
         &self,
         merchant_code: impl Into<String>,
         member_id: impl Into<String>,
     ) -> crate::error::SdkResult<(), DeleteErrorBody> {
+        self.send_delete(merchant_code, member_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::delete`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn delete_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(), DeleteErrorBody> {
+        self.send_delete(merchant_code, member_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::delete`], additionally returning the metadata of the response.
+    pub async fn delete_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), DeleteErrorBody> {
+        self.send_delete(merchant_code, member_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::delete_with_response`], sending the request with the given options.
+    pub async fn delete_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), DeleteErrorBody> {
+        self.send_delete(merchant_code, member_id, &options).await
+    }
+    async fn send_delete(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), DeleteErrorBody> {
+        self.client.check_scopes(Self::DELETE_SCOPES)?;
         let path = format!(
             "/v0.1/merchants/{}/members/{}",
             merchant_code.into(),
             member_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .delete(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::DELETE, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
-            reqwest::StatusCode::OK => Ok(()),
+            reqwest::StatusCode::OK => {
+                Ok(((), crate::response::ResponseMeta::from_response(&response)))
+            }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(DeleteErrorBody::Forbidden(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    DeleteErrorBody::Forbidden(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(DeleteErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    DeleteErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get`]; any one of them grants access.
+    pub const GET_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::MembersRead,
+    ];
     /// Retrieve a member
     ///
     /// Retrieve a merchant member.
@@ -443,47 +973,87 @@ impl<'a> MembersClient<'a> {
     /// Responses:
     /// - 200: Returns the Member object for a valid identifier.
     /// - 404: Merchant or member not found.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `members.read`
     pub async fn get(
         &self,
         merchant_code: impl Into<String>,
         member_id: impl Into<String>,
     ) -> crate::error::SdkResult<Member, GetErrorBody> {
+        self.send_get(merchant_code, member_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Member, GetErrorBody> {
+        self.send_get(merchant_code, member_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], additionally returning the metadata of the response.
+    pub async fn get_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+    ) -> crate::error::SdkResult<(Member, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(merchant_code, member_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::get_with_response`], sending the request with the given options.
+    pub async fn get_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Member, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(merchant_code, member_id, &options).await
+    }
+    async fn send_get(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Member, crate::response::ResponseMeta), GetErrorBody> {
+        self.client.check_scopes(Self::GET_SCOPES)?;
         let path = format!(
             "/v0.1/merchants/{}/members/{}",
             merchant_code.into(),
             member_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Member = response.json().await?;
-                Ok(data)
+                let (data, meta): (Member, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::update`]; any one of them grants access.
+    pub const UPDATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::MembersWrite,
+    ];
     /// Update a member
     ///
     /// Update the merchant member.
@@ -494,62 +1064,110 @@ impl<'a> MembersClient<'a> {
     /// - 403: Cannot change password for managed user. Password was already used before.
     /// - 404: Merchant or member not found.
     /// - 409: Cannot update member as some data conflict with existing members.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `members.write`
     pub async fn update(
         &self,
         merchant_code: impl Into<String>,
         member_id: impl Into<String>,
         body: UpdateRequest,
     ) -> crate::error::SdkResult<Member, UpdateErrorBody> {
+        self.send_update(merchant_code, member_id, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn update_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Member, UpdateErrorBody> {
+        self.send_update(merchant_code, member_id, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], additionally returning the metadata of the response.
+    pub async fn update_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        body: UpdateRequest,
+    ) -> crate::error::SdkResult<(Member, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(merchant_code, member_id, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::update_with_response`], sending the request with the given options.
+    pub async fn update_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Member, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(merchant_code, member_id, body, &options)
+            .await
+    }
+    async fn send_update(
+        &self,
+        merchant_code: impl Into<String>,
+        member_id: impl Into<String>,
+        body: UpdateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Member, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.client.check_scopes(Self::UPDATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!(
             "/v0.1/merchants/{}/members/{}",
             merchant_code.into(),
             member_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .put(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::PUT, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Member = response.json().await?;
-                Ok(data)
+                let (data, meta): (Member, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::Forbidden(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::Forbidden(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::CONFLICT => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::Conflict(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::Conflict(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
@@ -74,6 +74,7 @@ pub struct MembershipResource {
 }
 pub type ResourceType = String;
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct ListParams {
     /// Offset of the first member to return.
     ///
@@ -132,6 +133,135 @@ pub struct ListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<String>>,
 }
+impl ListParams {
+    /// Returns a builder for [`ListParams`], taking its required fields.
+    pub fn builder() -> ListParamsBuilder {
+        ListParamsBuilder {
+            inner: Self {
+                offset: None,
+                limit: None,
+                kind: None,
+                status: None,
+                resource_type: None,
+                resource_attributes_sandbox: None,
+                resource_name: None,
+                resource_parent_id: None,
+                resource_parent_type: None,
+                roles: None,
+            },
+        }
+    }
+}
+/// Builder for [`ListParams`], see [`ListParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ListParamsBuilder {
+    inner: ListParams,
+}
+impl ListParamsBuilder {
+    /// Offset of the first member to return.
+    ///
+    /// Constraints:
+    /// - value >= 0
+    ///
+    /// Example: `0`
+    pub fn offset(mut self, offset: impl Into<i64>) -> Self {
+        self.inner.offset = Some(offset.into());
+        self
+    }
+    /// Maximum number of members to return.
+    ///
+    /// Constraints:
+    /// - value >= 1
+    /// - value <= 25
+    ///
+    /// Example: `10`
+    pub fn limit(mut self, limit: impl Into<i64>) -> Self {
+        self.inner.limit = Some(limit.into());
+        self
+    }
+    /// Filter memberships by resource kind.
+    pub fn kind(mut self, kind: impl Into<ResourceType>) -> Self {
+        self.inner.kind = Some(kind.into());
+        self
+    }
+    /// Filter the returned memberships by the membership status.
+    pub fn status(mut self, status: impl Into<MembershipStatus>) -> Self {
+        self.inner.status = Some(status.into());
+        self
+    }
+    /// Filter memberships by resource kind.
+    pub fn resource_type(mut self, resource_type: impl Into<ResourceType>) -> Self {
+        self.inner.resource_type = Some(resource_type.into());
+        self
+    }
+    /// Filter memberships by the sandbox status of the resource the membership is in.
+    pub fn resource_attributes_sandbox(
+        mut self,
+        resource_attributes_sandbox: impl Into<bool>,
+    ) -> Self {
+        self.inner.resource_attributes_sandbox = Some(resource_attributes_sandbox.into());
+        self
+    }
+    /// Filter memberships by the name of the resource the membership is in.
+    pub fn resource_name(mut self, resource_name: impl Into<String>) -> Self {
+        self.inner.resource_name = Some(resource_name.into());
+        self
+    }
+    /// Filter memberships by the parent of the resource the membership is in.
+    /// When filtering by parent both `resource.parent.id` and `resource.parent.type` must be present. Pass explicit null to filter for resources without a parent.
+    pub fn resource_parent_id(
+        mut self,
+        resource_parent_id: impl Into<crate::Nullable<String>>,
+    ) -> Self {
+        self.inner.resource_parent_id = Some(resource_parent_id.into());
+        self
+    }
+    /// Filter memberships by the parent of the resource the membership is in.
+    /// When filtering by parent both `resource.parent.id` and `resource.parent.type` must be present. Pass explicit null to filter for resources without a parent.
+    pub fn resource_parent_type(
+        mut self,
+        resource_parent_type: impl Into<crate::Nullable<String>>,
+    ) -> Self {
+        self.inner.resource_parent_type = Some(resource_parent_type.into());
+        self
+    }
+    /// Filter the returned memberships by role.
+    pub fn roles(mut self, roles: impl Into<Vec<String>>) -> Self {
+        self.inner.roles = Some(roles.into());
+        self
+    }
+    /// Returns the built [`ListParams`].
+    pub fn build(self) -> ListParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for ListParams {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.offset {
+            let path = crate::validation::field_path(path, "offset");
+            crate::validation::check_range(value, Some((0.0, false)), None, &path, violations);
+        }
+        if let Some(value) = &self.limit {
+            let path = crate::validation::field_path(path, "limit");
+            crate::validation::check_range(
+                value,
+                Some((1.0, false)),
+                Some((25.0, false)),
+                &path,
+                violations,
+            );
+        }
+        if let Some(value) = &self.status {
+            let path = crate::validation::field_path(path, "status");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(crate::Nullable::Value(value)) = &self.resource_parent_type {
+            let path = crate::validation::field_path(path, "resource.parent.type");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 /// Returns a list of Membership objects.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ListResponse {
@@ -144,8 +274,16 @@ pub enum ListErrorBody {
     BadRequest(Problem),
     Unauthorized(Problem),
 }
+impl crate::error::ErrorBody for ListErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+        }
+    }
+}
 /// Client for the Memberships API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MembershipsClient<'a> {
     client: &'a Client,
 }
@@ -157,6 +295,11 @@ impl<'a> MembershipsClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list`]; any one of them grants access.
+    pub const LIST_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserProfile,
+        crate::scope::Scope::UserProfileReadonly,
+    ];
     /// List memberships
     ///
     /// List memberships of the current user.
@@ -165,24 +308,49 @@ impl<'a> MembershipsClient<'a> {
     /// - 200: Returns a list of Membership objects.
     /// - 400: Invalid query parameter combination.
     /// - 401: Authentication failed or missing required scope.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.profile`, `user.profile_readonly`
     pub async fn list(
         &self,
         params: ListParams,
     ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_with_options(
+        &self,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(params, &options).await.map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], additionally returning the metadata of the response.
+    pub async fn list_with_response(
+        &self,
+        params: ListParams,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(params, &Default::default()).await
+    }
+    /// Same as [`Self::list_with_response`], sending the request with the given options.
+    pub async fn list_with_response_and_options(
+        &self,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(params, &options).await
+    }
+    async fn send_list(
+        &self,
+        params: ListParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.client.check_scopes(Self::LIST_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = "/v0.1/memberships";
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         if let Some(ref value) = params.offset {
             request = request.query(&[("offset", value)]);
         }
@@ -224,31 +392,59 @@ impl<'a> MembershipsClient<'a> {
                 }
             }
         }
-        if let Some(ref value) = params.roles {
+        for value in params.roles.as_ref().into_iter().flatten() {
             request = request.query(&[("roles", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ListResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (ListResponse, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(ListErrorBody::BadRequest(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(ListErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// Streams the items of [`Self::list`] across all pages, starting at `params.offset`.
+    pub fn list_all(
+        &self,
+        params: ListParams,
+    ) -> crate::pagination::Paginator<'a, Membership, ListErrorBody, i64> {
+        let client = self.clone();
+        let limit = params.limit;
+        crate::pagination::Paginator::offset(
+            params.offset.unwrap_or_default(),
+            limit,
+            move |offset| {
+                let client = client.clone();
+                let params = ListParams {
+                    offset: Some(offset),
+                    ..params.clone()
+                };
+                async move {
+                    let page = client.list(params).await?;
+                    Ok((page.items, Some(page.total_count)))
+                }
+            },
+        )
+    }
 }
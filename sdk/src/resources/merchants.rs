@@ -581,38 +581,140 @@ pub struct Timestamps {
 }
 pub type Version = String;
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct GetParams {
     /// The version of the resource. At the moment, the only supported value is `latest`. When provided and the requested resource's `change_status` is pending, the resource will be returned with all pending changes applied. When no changes are pending the resource is returned as is. The `change_status` in the response body will reflect the current state of the resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
+impl GetParams {
+    /// Returns a builder for [`GetParams`], taking its required fields.
+    pub fn builder() -> GetParamsBuilder {
+        GetParamsBuilder {
+            inner: Self { version: None },
+        }
+    }
+}
+/// Builder for [`GetParams`], see [`GetParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct GetParamsBuilder {
+    inner: GetParams,
+}
+impl GetParamsBuilder {
+    /// The version of the resource. At the moment, the only supported value is `latest`. When provided and the requested resource's `change_status` is pending, the resource will be returned with all pending changes applied. When no changes are pending the resource is returned as is. The `change_status` in the response body will reflect the current state of the resource.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.inner.version = Some(version.into());
+        self
+    }
+    /// Returns the built [`GetParams`].
+    pub fn build(self) -> GetParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for GetParams {}
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct ListPersonsParams {
     /// The version of the resource. At the moment, the only supported value is `latest`. When provided and the requested resource's `change_status` is pending, the resource will be returned with all pending changes applied. When no changes are pending the resource is returned as is. The `change_status` in the response body will reflect the current state of the resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
+impl ListPersonsParams {
+    /// Returns a builder for [`ListPersonsParams`], taking its required fields.
+    pub fn builder() -> ListPersonsParamsBuilder {
+        ListPersonsParamsBuilder {
+            inner: Self { version: None },
+        }
+    }
+}
+/// Builder for [`ListPersonsParams`], see [`ListPersonsParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ListPersonsParamsBuilder {
+    inner: ListPersonsParams,
+}
+impl ListPersonsParamsBuilder {
+    /// The version of the resource. At the moment, the only supported value is `latest`. When provided and the requested resource's `change_status` is pending, the resource will be returned with all pending changes applied. When no changes are pending the resource is returned as is. The `change_status` in the response body will reflect the current state of the resource.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.inner.version = Some(version.into());
+        self
+    }
+    /// Returns the built [`ListPersonsParams`].
+    pub fn build(self) -> ListPersonsParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for ListPersonsParams {}
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct GetPersonParams {
     /// The version of the resource. At the moment, the only supported value is `latest`. When provided and the requested resource's `change_status` is pending, the resource will be returned with all pending changes applied. When no changes are pending the resource is returned as is. The `change_status` in the response body will reflect the current state of the resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
+impl GetPersonParams {
+    /// Returns a builder for [`GetPersonParams`], taking its required fields.
+    pub fn builder() -> GetPersonParamsBuilder {
+        GetPersonParamsBuilder {
+            inner: Self { version: None },
+        }
+    }
+}
+/// Builder for [`GetPersonParams`], see [`GetPersonParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct GetPersonParamsBuilder {
+    inner: GetPersonParams,
+}
+impl GetPersonParamsBuilder {
+    /// The version of the resource. At the moment, the only supported value is `latest`. When provided and the requested resource's `change_status` is pending, the resource will be returned with all pending changes applied. When no changes are pending the resource is returned as is. The `change_status` in the response body will reflect the current state of the resource.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.inner.version = Some(version.into());
+        self
+    }
+    /// Returns the built [`GetPersonParams`].
+    pub fn build(self) -> GetPersonParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for GetPersonParams {}
 use crate::client::Client;
 #[derive(Debug, PartialEq)]
 pub enum GetErrorBody {
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for GetErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum ListPersonsErrorBody {
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for ListPersonsErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum GetPersonErrorBody {
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for GetPersonErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 /// Client for the Merchants API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MerchantsClient<'a> {
     client: &'a Client,
 }
@@ -624,6 +726,11 @@ impl<'a> MerchantsClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get`]; any one of them grants access.
+    pub const GET_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserProfile,
+        crate::scope::Scope::UserProfileReadonly,
+    ];
     /// Get Merchant
     ///
     /// Returns a Merchant for a valid Merchant code.
@@ -631,46 +738,87 @@ impl<'a> MerchantsClient<'a> {
     /// Responses:
     /// - 200: Returns a Merchant for a valid identifier.
     /// - 404: The requested Merchant does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.profile`, `user.profile_readonly`
     pub async fn get(
         &self,
         merchant_code: impl Into<String>,
         params: GetParams,
     ) -> crate::error::SdkResult<Merchant, GetErrorBody> {
+        self.send_get(merchant_code, params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: GetParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Merchant, GetErrorBody> {
+        self.send_get(merchant_code, params, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], additionally returning the metadata of the response.
+    pub async fn get_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        params: GetParams,
+    ) -> crate::error::SdkResult<(Merchant, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(merchant_code, params, &Default::default())
+            .await
+    }
+    /// Same as [`Self::get_with_response`], sending the request with the given options.
+    pub async fn get_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: GetParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Merchant, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(merchant_code, params, &options).await
+    }
+    async fn send_get(
+        &self,
+        merchant_code: impl Into<String>,
+        params: GetParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Merchant, crate::response::ResponseMeta), GetErrorBody> {
+        self.client.check_scopes(Self::GET_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = format!("/v1/merchants/{}", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         if let Some(ref value) = params.version {
             request = request.query(&[("version", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Merchant = response.json().await?;
-                Ok(data)
+                let (data, meta): (Merchant, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list_persons`]; any one of them grants access.
+    pub const LIST_PERSONS_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserProfile,
+        crate::scope::Scope::UserProfileReadonly,
+    ];
     /// List Persons
     ///
     /// Returns the Persons related to a Merchant.
@@ -678,48 +826,98 @@ impl<'a> MerchantsClient<'a> {
     /// Responses:
     /// - 200: Returns a list of Persons for a valid Merchant identifier.
     /// - 404: The requested Merchant does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.profile`, `user.profile_readonly`
     pub async fn list_persons(
         &self,
         merchant_code: impl Into<String>,
         params: ListPersonsParams,
     ) -> crate::error::SdkResult<ListPersonsResponseBody, ListPersonsErrorBody> {
+        self.send_list_persons(merchant_code, params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list_persons`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_persons_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListPersonsParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<ListPersonsResponseBody, ListPersonsErrorBody> {
+        self.send_list_persons(merchant_code, params, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list_persons`], additionally returning the metadata of the response.
+    pub async fn list_persons_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListPersonsParams,
+    ) -> crate::error::SdkResult<
+        (ListPersonsResponseBody, crate::response::ResponseMeta),
+        ListPersonsErrorBody,
+    > {
+        self.send_list_persons(merchant_code, params, &Default::default())
+            .await
+    }
+    /// Same as [`Self::list_persons_with_response`], sending the request with the given options.
+    pub async fn list_persons_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListPersonsParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (ListPersonsResponseBody, crate::response::ResponseMeta),
+        ListPersonsErrorBody,
+    > {
+        self.send_list_persons(merchant_code, params, &options)
+            .await
+    }
+    async fn send_list_persons(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListPersonsParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (ListPersonsResponseBody, crate::response::ResponseMeta),
+        ListPersonsErrorBody,
+    > {
+        self.client.check_scopes(Self::LIST_PERSONS_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = format!("/v1/merchants/{}/persons", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         if let Some(ref value) = params.version {
             request = request.query(&[("version", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ListPersonsResponseBody = response.json().await?;
-                Ok(data)
+                let (data, meta): (ListPersonsResponseBody, _) =
+                    crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(ListPersonsErrorBody::NotFound(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListPersonsErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get_person`]; any one of them grants access.
+    pub const GET_PERSON_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserProfile,
+        crate::scope::Scope::UserProfileReadonly,
+    ];
     /// Get Person
     ///
     /// Returns a single Person related to a Merchant.
@@ -727,50 +925,89 @@ impl<'a> MerchantsClient<'a> {
     /// Responses:
     /// - 200: Returns a Person for a valid identifier.
     /// - 404: The requested Person does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.profile`, `user.profile_readonly`
     pub async fn get_person(
         &self,
         merchant_code: impl Into<String>,
         person_id: impl Into<String>,
         params: GetPersonParams,
     ) -> crate::error::SdkResult<Person, GetPersonErrorBody> {
+        self.send_get_person(merchant_code, person_id, params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get_person`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_person_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        person_id: impl Into<String>,
+        params: GetPersonParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Person, GetPersonErrorBody> {
+        self.send_get_person(merchant_code, person_id, params, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get_person`], additionally returning the metadata of the response.
+    pub async fn get_person_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        person_id: impl Into<String>,
+        params: GetPersonParams,
+    ) -> crate::error::SdkResult<(Person, crate::response::ResponseMeta), GetPersonErrorBody> {
+        self.send_get_person(merchant_code, person_id, params, &Default::default())
+            .await
+    }
+    /// Same as [`Self::get_person_with_response`], sending the request with the given options.
+    pub async fn get_person_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        person_id: impl Into<String>,
+        params: GetPersonParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Person, crate::response::ResponseMeta), GetPersonErrorBody> {
+        self.send_get_person(merchant_code, person_id, params, &options)
+            .await
+    }
+    async fn send_get_person(
+        &self,
+        merchant_code: impl Into<String>,
+        person_id: impl Into<String>,
+        params: GetPersonParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Person, crate::response::ResponseMeta), GetPersonErrorBody> {
+        self.client.check_scopes(Self::GET_PERSON_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = format!(
             "/v1/merchants/{}/persons/{}",
             merchant_code.into(),
             person_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         if let Some(ref value) = params.version {
             request = request.query(&[("version", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Person = response.json().await?;
-                Ok(data)
+                let (data, meta): (Person, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetPersonErrorBody::NotFound(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetPersonErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
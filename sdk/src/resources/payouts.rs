@@ -23,7 +23,7 @@ pub struct FinancialPayout {
     /// Amount of the payout or deduction in major units.
     ///
     /// Example: `132.45`
-    pub amount: f32,
+    pub amount: crate::money::Amount,
     /// Payout date associated with the record, in `YYYY-MM-DD` format.
     ///
     /// Example: `2024-02-29`
@@ -35,7 +35,7 @@ pub struct FinancialPayout {
     /// Fee amount associated with the payout record, in major units.
     ///
     /// Example: `3.12`
-    pub fee: f32,
+    pub fee: crate::money::Amount,
     /// Merchant-facing outcome of the payout record.
     ///
     /// Example: `SUCCESSFUL`
@@ -89,6 +89,19 @@ pub enum ListParamsFormat {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for ListParamsFormat {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ListParamsOrder {
     #[serde(rename = "asc")]
@@ -98,7 +111,21 @@ pub enum ListParamsOrder {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for ListParamsOrder {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct ListParams {
     /// Start date of the payout period filter, inclusive, in [ISO8601](https://en.wikipedia.org/wiki/ISO_8601) `date` format (`YYYY-MM-DD`).
     ///
@@ -128,14 +155,98 @@ pub struct ListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<ListParamsOrder>,
 }
+impl ListParams {
+    /// Returns a builder for [`ListParams`], taking its required fields.
+    pub fn builder(
+        start_date: impl Into<crate::datetime::Date>,
+        end_date: impl Into<crate::datetime::Date>,
+    ) -> ListParamsBuilder {
+        ListParamsBuilder {
+            inner: Self {
+                start_date: start_date.into(),
+                end_date: end_date.into(),
+                format: None,
+                limit: None,
+                order: None,
+            },
+        }
+    }
+}
+/// Builder for [`ListParams`], see [`ListParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ListParamsBuilder {
+    inner: ListParams,
+}
+impl ListParamsBuilder {
+    /// Response format for the payout list.
+    ///
+    /// Example: `json`
+    pub fn format(mut self, format: impl Into<ListParamsFormat>) -> Self {
+        self.inner.format = Some(format.into());
+        self
+    }
+    /// Maximum number of payout records to return.
+    ///
+    /// Constraints:
+    /// - value >= 1
+    /// - value <= 9999
+    ///
+    /// Example: `10`
+    pub fn limit(mut self, limit: impl Into<i64>) -> Self {
+        self.inner.limit = Some(limit.into());
+        self
+    }
+    /// Sort direction for the returned payouts.
+    ///
+    /// Example: `desc`
+    pub fn order(mut self, order: impl Into<ListParamsOrder>) -> Self {
+        self.inner.order = Some(order.into());
+        self
+    }
+    /// Returns the built [`ListParams`].
+    pub fn build(self) -> ListParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for ListParams {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.format {
+            let path = crate::validation::field_path(path, "format");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.limit {
+            let path = crate::validation::field_path(path, "limit");
+            crate::validation::check_range(
+                value,
+                Some((1.0, false)),
+                Some((9999.0, false)),
+                &path,
+                violations,
+            );
+        }
+        if let Some(value) = &self.order {
+            let path = crate::validation::field_path(path, "order");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 use crate::client::Client;
 #[derive(Debug, PartialEq)]
 pub enum ListErrorBody {
     BadRequest(crate::error::UnknownApiBody),
     Unauthorized(Problem),
 }
+impl crate::error::ErrorBody for ListErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+        }
+    }
+}
 /// Client for the Payouts API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PayoutsClient<'a> {
     client: &'a Client,
 }
@@ -147,6 +258,12 @@ impl<'a> PayoutsClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list`]; any one of them grants access.
+    pub const LIST_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserProfile,
+        crate::scope::Scope::UserProfileReadonly,
+        crate::scope::Scope::PayoutsRead,
+    ];
     /// List payouts
     ///
     /// Lists payout and payout-deduction records for the specified merchant account within the requested date range.
@@ -161,25 +278,60 @@ impl<'a> PayoutsClient<'a> {
     /// - 200: Returns the list of payout and deduction records for the requested period.
     /// - 400: The request is invalid for the submitted query parameters.
     /// - 401: The request is not authorized.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.profile`, `user.profile_readonly`, `payouts.read`
     pub async fn list(
         &self,
         merchant_code: impl Into<String>,
         params: ListParams,
     ) -> crate::error::SdkResult<FinancialPayouts, ListErrorBody> {
+        self.send_list(merchant_code, params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<FinancialPayouts, ListErrorBody> {
+        self.send_list(merchant_code, params, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], additionally returning the metadata of the response.
+    pub async fn list_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+    ) -> crate::error::SdkResult<(FinancialPayouts, crate::response::ResponseMeta), ListErrorBody>
+    {
+        self.send_list(merchant_code, params, &Default::default())
+            .await
+    }
+    /// Same as [`Self::list_with_response`], sending the request with the given options.
+    pub async fn list_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(FinancialPayouts, crate::response::ResponseMeta), ListErrorBody>
+    {
+        self.send_list(merchant_code, params, &options).await
+    }
+    async fn send_list(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(FinancialPayouts, crate::response::ResponseMeta), ListErrorBody>
+    {
+        self.client.check_scopes(Self::LIST_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = format!("/v1.0/merchants/{}/payouts", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         request = request.query(&[("start_date", &params.start_date)]);
         request = request.query(&[("end_date", &params.end_date)]);
         if let Some(ref value) = params.format {
@@ -191,28 +343,34 @@ impl<'a> PayoutsClient<'a> {
         if let Some(ref value) = params.order {
             request = request.query(&[("order", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: FinancialPayouts = response.json().await?;
-                Ok(data)
+                let (data, meta): (FinancialPayouts, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::api(ListErrorBody::BadRequest(body)))
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(ListErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
@@ -3,11 +3,37 @@
 //! A reader represents a device that accepts payments. You can use the SumUp Solo to accept in-person payments.
 use super::common::*;
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct Affiliate {
     pub app_id: String,
     pub key: String,
 }
+impl Affiliate {
+    /// Returns a builder for [`Affiliate`], taking its required fields.
+    pub fn builder(app_id: impl Into<String>, key: impl Into<String>) -> AffiliateBuilder {
+        AffiliateBuilder {
+            inner: Self {
+                app_id: app_id.into(),
+                key: key.into(),
+            },
+        }
+    }
+}
+/// Builder for [`Affiliate`], see [`Affiliate::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct AffiliateBuilder {
+    inner: Affiliate,
+}
+impl AffiliateBuilder {
+    /// Returns the built [`Affiliate`].
+    pub fn build(self) -> Affiliate {
+        self.inner
+    }
+}
+impl crate::validation::Validate for Affiliate {}
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct Amount {
     /// Currency ISO 4217 code
     ///
@@ -18,6 +44,30 @@ pub struct Amount {
     /// Example: `1000`
     pub value: i64,
 }
+impl Amount {
+    /// Returns a builder for [`Amount`], taking its required fields.
+    pub fn builder(currency: impl Into<String>, value: impl Into<i64>) -> AmountBuilder {
+        AmountBuilder {
+            inner: Self {
+                currency: currency.into(),
+                value: value.into(),
+            },
+        }
+    }
+}
+/// Builder for [`Amount`], see [`Amount::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct AmountBuilder {
+    inner: Amount,
+}
+impl AmountBuilder {
+    /// Returns the built [`Amount`].
+    pub fn build(self) -> Amount {
+        self.inner
+    }
+}
+impl crate::validation::Validate for Amount {}
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct CreateReaderCheckoutResponse {
     pub data: CreateReaderCheckoutResponseData,
@@ -52,6 +102,27 @@ pub struct Reader {
     /// Example: `2023-01-20T15:16:17Z`
     pub updated_at: crate::datetime::DateTime,
 }
+/// The callback payload containing the status change of the Reader Checkout.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReaderCheckoutStatusChange {
+    /// Type of event.
+    ///
+    /// Example: `solo.transaction.updated`
+    pub event_type: String,
+    /// Unique identifier for the event.
+    ///
+    /// Constraints:
+    /// - format: `uuid`
+    ///
+    /// Example: `3fa85f64-5717-4562-b3fc-2c963f66afa6`
+    pub id: String,
+    /// The event payload.
+    pub payload: ReaderCheckoutStatusChangePayload,
+    /// Timestamp of the event.
+    ///
+    /// Example: `2023-10-05T14:48:00Z`
+    pub timestamp: crate::datetime::DateTime,
+}
 /// Information about the underlying physical device.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ReaderDevice {
@@ -231,6 +302,46 @@ pub struct GetReaderCheckoutResponseData {
     /// Checkout expiration timestamp. After this time, the checkout will be automatically cancelled.
     pub valid_until: crate::datetime::DateTime,
 }
+/// The current status of the transaction.
+///
+/// Example: `successful`
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ReaderCheckoutStatusChangePayloadStatus {
+    #[serde(rename = "successful")]
+    Successful,
+    #[serde(rename = "failed")]
+    Failed,
+    #[serde(untagged)]
+    Other(String),
+}
+/// The event payload.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReaderCheckoutStatusChangePayload {
+    /// The unique client transaction id. It is the same returned by the Checkout.
+    ///
+    /// Constraints:
+    /// - format: `uuid`
+    ///
+    /// Example: `3fa85f64-5717-4562-b3fc-2c963f66afa6`
+    pub client_transaction_id: String,
+    /// The merchant code associated with the transaction.
+    ///
+    /// Example: `M1234567`
+    pub merchant_code: String,
+    /// The current status of the transaction.
+    ///
+    /// Example: `successful`
+    pub status: ReaderCheckoutStatusChangePayloadStatus,
+    /// The transaction id. Deprecated: use `client_transaction_id` instead.
+    ///
+    /// Constraints:
+    /// - format: `uuid`
+    ///
+    /// Example: `3fa85f64-5717-4562-b3fc-2c963f66afa6`
+    #[deprecated]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+}
 /// Identifier of the model of the device.
 ///
 /// Example: `solo`
@@ -341,6 +452,7 @@ pub struct StatusResponseData {
 /// Optional object containing data for transactions from ERP integrators in Greece that comply with the AADE 1155 protocol.
 /// When such regulatory/business requirements apply, this object must be provided and contains the data needed to validate the transaction with the AADE signature provider.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateCheckoutRequestAade {
     /// The identifier of the AADE signature provider.
     ///
@@ -355,9 +467,39 @@ pub struct CreateCheckoutRequestAade {
     /// Example: `B71D7A555072E3EE4D2FD3F457A29115830E6CBA;;20251112214713;796;104;900;900;54889039`
     pub signature_data: String,
 }
+impl CreateCheckoutRequestAade {
+    /// Returns a builder for [`CreateCheckoutRequestAade`], taking its required fields.
+    pub fn builder(
+        provider_id: impl Into<String>,
+        signature: impl Into<String>,
+        signature_data: impl Into<String>,
+    ) -> CreateCheckoutRequestAadeBuilder {
+        CreateCheckoutRequestAadeBuilder {
+            inner: Self {
+                provider_id: provider_id.into(),
+                signature: signature.into(),
+                signature_data: signature_data.into(),
+            },
+        }
+    }
+}
+/// Builder for [`CreateCheckoutRequestAade`], see [`CreateCheckoutRequestAade::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateCheckoutRequestAadeBuilder {
+    inner: CreateCheckoutRequestAade,
+}
+impl CreateCheckoutRequestAadeBuilder {
+    /// Returns the built [`CreateCheckoutRequestAade`].
+    pub fn build(self) -> CreateCheckoutRequestAade {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateCheckoutRequestAade {}
 /// Additional metadata for the transaction.
 /// It is key-value object that can be associated with the transaction.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateCheckoutRequestAffiliateTags {
     #[serde(
         flatten,
@@ -366,9 +508,41 @@ pub struct CreateCheckoutRequestAffiliateTags {
     )]
     pub additional_properties: std::collections::HashMap<String, serde_json::Value>,
 }
+impl CreateCheckoutRequestAffiliateTags {
+    /// Returns a builder for [`CreateCheckoutRequestAffiliateTags`], taking its required fields.
+    pub fn builder() -> CreateCheckoutRequestAffiliateTagsBuilder {
+        CreateCheckoutRequestAffiliateTagsBuilder {
+            inner: Self {
+                additional_properties: Default::default(),
+            },
+        }
+    }
+}
+/// Builder for [`CreateCheckoutRequestAffiliateTags`], see [`CreateCheckoutRequestAffiliateTags::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateCheckoutRequestAffiliateTagsBuilder {
+    inner: CreateCheckoutRequestAffiliateTags,
+}
+impl CreateCheckoutRequestAffiliateTagsBuilder {
+    /// Properties not described by the API specification.
+    pub fn additional_properties(
+        mut self,
+        additional_properties: impl Into<std::collections::HashMap<String, serde_json::Value>>,
+    ) -> Self {
+        self.inner.additional_properties = additional_properties.into();
+        self
+    }
+    /// Returns the built [`CreateCheckoutRequestAffiliateTags`].
+    pub fn build(self) -> CreateCheckoutRequestAffiliateTags {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateCheckoutRequestAffiliateTags {}
 /// Affiliate metadata for the transaction.
 /// It is a field that allow for integrators to track the source of the transaction.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateCheckoutRequestAffiliate {
     /// Application ID of the affiliate.
     /// It is a unique identifier for the application and should be set by the integrator in the [Affiliate Keys](https://developer.sumup.com/affiliate-keys) page.
@@ -391,6 +565,49 @@ pub struct CreateCheckoutRequestAffiliate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<CreateCheckoutRequestAffiliateTags>,
 }
+impl CreateCheckoutRequestAffiliate {
+    /// Returns a builder for [`CreateCheckoutRequestAffiliate`], taking its required fields.
+    pub fn builder(
+        app_id: impl Into<String>,
+        foreign_transaction_id: impl Into<String>,
+        key: impl Into<String>,
+    ) -> CreateCheckoutRequestAffiliateBuilder {
+        CreateCheckoutRequestAffiliateBuilder {
+            inner: Self {
+                app_id: app_id.into(),
+                foreign_transaction_id: foreign_transaction_id.into(),
+                key: key.into(),
+                tags: None,
+            },
+        }
+    }
+}
+/// Builder for [`CreateCheckoutRequestAffiliate`], see [`CreateCheckoutRequestAffiliate::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateCheckoutRequestAffiliateBuilder {
+    inner: CreateCheckoutRequestAffiliate,
+}
+impl CreateCheckoutRequestAffiliateBuilder {
+    /// Additional metadata for the transaction.
+    /// It is key-value object that can be associated with the transaction.
+    pub fn tags(mut self, tags: impl Into<CreateCheckoutRequestAffiliateTags>) -> Self {
+        self.inner.tags = Some(tags.into());
+        self
+    }
+    /// Returns the built [`CreateCheckoutRequestAffiliate`].
+    pub fn build(self) -> CreateCheckoutRequestAffiliate {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateCheckoutRequestAffiliate {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.tags {
+            let path = crate::validation::field_path(path, "tags");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 /// The card type of the card used for the transaction.
 /// Is is required only for some countries (e.g: Brazil).
 ///
@@ -404,12 +621,26 @@ pub enum CreateCheckoutRequestCardType {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for CreateCheckoutRequestCardType {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 /// Amount structure.
 ///
 /// The amount is represented as an integer value altogether with the currency and the minor unit.
 ///
 /// For example, EUR 1.00 is represented as value 100 with minor unit of 2.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateCheckoutRequestTotalAmount {
     /// Currency ISO 4217 code
     ///
@@ -431,27 +662,167 @@ pub struct CreateCheckoutRequestTotalAmount {
     /// Example: `1000`
     pub value: i64,
 }
+impl CreateCheckoutRequestTotalAmount {
+    /// Returns a builder for [`CreateCheckoutRequestTotalAmount`], taking its required fields.
+    pub fn builder(
+        currency: impl Into<String>,
+        minor_unit: impl Into<i64>,
+        value: impl Into<i64>,
+    ) -> CreateCheckoutRequestTotalAmountBuilder {
+        CreateCheckoutRequestTotalAmountBuilder {
+            inner: Self {
+                currency: currency.into(),
+                minor_unit: minor_unit.into(),
+                value: value.into(),
+            },
+        }
+    }
+}
+/// Builder for [`CreateCheckoutRequestTotalAmount`], see [`CreateCheckoutRequestTotalAmount::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateCheckoutRequestTotalAmountBuilder {
+    inner: CreateCheckoutRequestTotalAmount,
+}
+impl CreateCheckoutRequestTotalAmountBuilder {
+    /// Returns the built [`CreateCheckoutRequestTotalAmount`].
+    pub fn build(self) -> CreateCheckoutRequestTotalAmount {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateCheckoutRequestTotalAmount {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        {
+            let value = &self.minor_unit;
+            let path = crate::validation::field_path(path, "minor_unit");
+            crate::validation::check_range(value, Some((0.0, false)), None, &path, violations);
+        }
+        {
+            let value = &self.value;
+            let path = crate::validation::field_path(path, "value");
+            crate::validation::check_range(value, Some((0.0, false)), None, &path, violations);
+        }
+    }
+}
 /// Returns a list Reader objects.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ListResponse {
     pub items: Vec<Reader>,
 }
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateRequest {
     pub pairing_code: ReaderPairingCode,
     pub name: ReaderName,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
 }
+impl CreateRequest {
+    /// Returns a builder for [`CreateRequest`], taking its required fields.
+    pub fn builder(
+        pairing_code: impl Into<ReaderPairingCode>,
+        name: impl Into<ReaderName>,
+    ) -> CreateRequestBuilder {
+        CreateRequestBuilder {
+            inner: Self {
+                pairing_code: pairing_code.into(),
+                name: name.into(),
+                metadata: None,
+            },
+        }
+    }
+}
+/// Builder for [`CreateRequest`], see [`CreateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateRequestBuilder {
+    inner: CreateRequest,
+}
+impl CreateRequestBuilder {
+    /// Set of user-defined key-value pairs attached to the object. Partial updates are not supported. When updating, always submit whole metadata. Maximum of 64 parameters are allowed in the object.
+    pub fn metadata(mut self, metadata: impl Into<Metadata>) -> Self {
+        self.inner.metadata = Some(metadata.into());
+        self
+    }
+    /// Returns the built [`CreateRequest`].
+    pub fn build(self) -> CreateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        {
+            let value = &self.pairing_code;
+            let path = crate::validation::field_path(path, "pairing_code");
+            crate::validation::check_length(value, Some(8), Some(9), &path, violations);
+        }
+        {
+            let value = &self.name;
+            let path = crate::validation::field_path(path, "name");
+            crate::validation::check_length(value, None, Some(500), &path, violations);
+        }
+        if let Some(value) = &self.metadata {
+            let path = crate::validation::field_path(path, "metadata");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct UpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<ReaderName>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
 }
+impl UpdateRequest {
+    /// Returns a builder for [`UpdateRequest`], taking its required fields.
+    pub fn builder() -> UpdateRequestBuilder {
+        UpdateRequestBuilder {
+            inner: Self {
+                name: None,
+                metadata: None,
+            },
+        }
+    }
+}
+/// Builder for [`UpdateRequest`], see [`UpdateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct UpdateRequestBuilder {
+    inner: UpdateRequest,
+}
+impl UpdateRequestBuilder {
+    /// Custom human-readable, user-defined name for easier identification of the reader.
+    pub fn name(mut self, name: impl Into<ReaderName>) -> Self {
+        self.inner.name = Some(name.into());
+        self
+    }
+    /// Set of user-defined key-value pairs attached to the object. Partial updates are not supported. When updating, always submit whole metadata. Maximum of 64 parameters are allowed in the object.
+    pub fn metadata(mut self, metadata: impl Into<Metadata>) -> Self {
+        self.inner.metadata = Some(metadata.into());
+        self
+    }
+    /// Returns the built [`UpdateRequest`].
+    pub fn build(self) -> UpdateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for UpdateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.name {
+            let path = crate::validation::field_path(path, "name");
+            crate::validation::check_length(value, None, Some(500), &path, violations);
+        }
+        if let Some(value) = &self.metadata {
+            let path = crate::validation::field_path(path, "metadata");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 /// A checkout initial attributes
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateCheckoutRequest {
     /// Optional object containing data for transactions from ERP integrators in Greece that comply with the AADE 1155 protocol.
     /// When such regulatory/business requirements apply, this object must be provided and contains the data needed to validate the transaction with the AADE signature provider.
@@ -526,8 +897,153 @@ pub struct CreateCheckoutRequest {
     /// For example, EUR 1.00 is represented as value 100 with minor unit of 2.
     pub total_amount: CreateCheckoutRequestTotalAmount,
 }
+impl CreateCheckoutRequest {
+    /// Returns a builder for [`CreateCheckoutRequest`], taking its required fields.
+    pub fn builder(
+        total_amount: impl Into<CreateCheckoutRequestTotalAmount>,
+    ) -> CreateCheckoutRequestBuilder {
+        CreateCheckoutRequestBuilder {
+            inner: Self {
+                aade: None,
+                affiliate: None,
+                card_type: None,
+                description: None,
+                installments: None,
+                return_url: None,
+                tip_rates: None,
+                tip_timeout: None,
+                total_amount: total_amount.into(),
+            },
+        }
+    }
+}
+/// Builder for [`CreateCheckoutRequest`], see [`CreateCheckoutRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateCheckoutRequestBuilder {
+    inner: CreateCheckoutRequest,
+}
+impl CreateCheckoutRequestBuilder {
+    /// Optional object containing data for transactions from ERP integrators in Greece that comply with the AADE 1155 protocol.
+    /// When such regulatory/business requirements apply, this object must be provided and contains the data needed to validate the transaction with the AADE signature provider.
+    pub fn aade(mut self, aade: impl Into<CreateCheckoutRequestAade>) -> Self {
+        self.inner.aade = Some(aade.into());
+        self
+    }
+    /// Affiliate metadata for the transaction.
+    /// It is a field that allow for integrators to track the source of the transaction.
+    pub fn affiliate(
+        mut self,
+        affiliate: impl Into<crate::Nullable<CreateCheckoutRequestAffiliate>>,
+    ) -> Self {
+        self.inner.affiliate = Some(affiliate.into());
+        self
+    }
+    /// The card type of the card used for the transaction.
+    /// Is is required only for some countries (e.g: Brazil).
+    ///
+    /// Example: `credit`
+    pub fn card_type(mut self, card_type: impl Into<CreateCheckoutRequestCardType>) -> Self {
+        self.inner.card_type = Some(card_type.into());
+        self
+    }
+    /// Description of the checkout to be shown in the Merchant Sales
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.inner.description = Some(description.into());
+        self
+    }
+    /// Number of installments for the transaction.
+    /// It may vary according to the merchant country.
+    /// For example, in Brazil, the maximum number of installments is 12.
+    ///
+    /// Omit if the merchant country does support installments.
+    /// Otherwise, the checkout will be rejected.
+    ///
+    /// Constraints:
+    /// - value >= 1
+    ///
+    /// Example: `1`
+    pub fn installments(mut self, installments: impl Into<crate::Nullable<i64>>) -> Self {
+        self.inner.installments = Some(installments.into());
+        self
+    }
+    /// Webhook URL to which the payment result will be sent.
+    /// It must be a HTTPS url.
+    ///
+    /// Constraints:
+    /// - format: `uri`
+    ///
+    /// Example: `https://www.example.com`
+    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
+        self.inner.return_url = Some(return_url.into());
+        self
+    }
+    /// List of tipping rates to be displayed to the cardholder.
+    /// The rates are in percentage and should be between 0.01 and 0.99.
+    /// The list should be sorted in ascending order.
+    pub fn tip_rates(mut self, tip_rates: impl Into<Vec<f32>>) -> Self {
+        self.inner.tip_rates = Some(tip_rates.into());
+        self
+    }
+    /// Time in seconds the cardholder has to select a tip rate.
+    /// If not provided, the default value is 30 seconds.
+    ///
+    /// It can only be set if `tip_rates` is provided.
+    ///
+    /// **Note**: If the target device is a Solo, it must be in version 3.3.38.0 or higher.
+    ///
+    /// Constraints:
+    /// - value >= 30
+    /// - value <= 120
+    ///
+    /// Example: `30`
+    pub fn tip_timeout(mut self, tip_timeout: impl Into<i64>) -> Self {
+        self.inner.tip_timeout = Some(tip_timeout.into());
+        self
+    }
+    /// Returns the built [`CreateCheckoutRequest`].
+    pub fn build(self) -> CreateCheckoutRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateCheckoutRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.aade {
+            let path = crate::validation::field_path(path, "aade");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(crate::Nullable::Value(value)) = &self.affiliate {
+            let path = crate::validation::field_path(path, "affiliate");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.card_type {
+            let path = crate::validation::field_path(path, "card_type");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(crate::Nullable::Value(value)) = &self.installments {
+            let path = crate::validation::field_path(path, "installments");
+            crate::validation::check_range(value, Some((1.0, false)), None, &path, violations);
+        }
+        if let Some(value) = &self.tip_timeout {
+            let path = crate::validation::field_path(path, "tip_timeout");
+            crate::validation::check_range(
+                value,
+                Some((30.0, false)),
+                Some((120.0, false)),
+                &path,
+                violations,
+            );
+        }
+        {
+            let value = &self.total_amount;
+            let path = crate::validation::field_path(path, "total_amount");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 /// Payment details to initiate on the reader.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateGoCheckoutRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub affiliate: Option<Affiliate>,
@@ -542,30 +1058,121 @@ pub struct CreateGoCheckoutRequest {
     pub tip_amount: Option<i64>,
     pub total_amount: Amount,
 }
+impl CreateGoCheckoutRequest {
+    /// Returns a builder for [`CreateGoCheckoutRequest`], taking its required fields.
+    pub fn builder(
+        client_transaction_id: impl Into<String>,
+        total_amount: impl Into<Amount>,
+    ) -> CreateGoCheckoutRequestBuilder {
+        CreateGoCheckoutRequestBuilder {
+            inner: Self {
+                affiliate: None,
+                client_transaction_id: client_transaction_id.into(),
+                tip_amount: None,
+                total_amount: total_amount.into(),
+            },
+        }
+    }
+}
+/// Builder for [`CreateGoCheckoutRequest`], see [`CreateGoCheckoutRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateGoCheckoutRequestBuilder {
+    inner: CreateGoCheckoutRequest,
+}
+impl CreateGoCheckoutRequestBuilder {
+    /// Sets the `affiliate` property.
+    pub fn affiliate(mut self, affiliate: impl Into<Affiliate>) -> Self {
+        self.inner.affiliate = Some(affiliate.into());
+        self
+    }
+    /// Optional tip amount in minor units, added on top of total_amount.
+    ///
+    /// Example: `100`
+    pub fn tip_amount(mut self, tip_amount: impl Into<i64>) -> Self {
+        self.inner.tip_amount = Some(tip_amount.into());
+        self
+    }
+    /// Returns the built [`CreateGoCheckoutRequest`].
+    pub fn build(self) -> CreateGoCheckoutRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateGoCheckoutRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.affiliate {
+            let path = crate::validation::field_path(path, "affiliate");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        {
+            let value = &self.total_amount;
+            let path = crate::validation::field_path(path, "total_amount");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 use crate::client::Client;
 #[derive(Debug, PartialEq)]
 pub enum ListErrorBody {
     Unauthorized(Problem),
 }
+impl crate::error::ErrorBody for ListErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum CreateErrorBody {
     BadRequest(Problem),
     NotFound(Problem),
     Conflict(Problem),
 }
+impl crate::error::ErrorBody for CreateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+            Self::Conflict(_) => Some(reqwest::StatusCode::CONFLICT),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum DeleteErrorBody {
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for DeleteErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum GetErrorBody {
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for GetErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum UpdateErrorBody {
     Forbidden(Problem),
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for UpdateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum CreateCheckoutErrorBody {
     BadRequest(Problem),
@@ -573,17 +1180,44 @@ pub enum CreateCheckoutErrorBody {
     NotFound(Problem),
     UnprocessableEntity(Problem),
 }
+impl crate::error::ErrorBody for CreateCheckoutErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+            Self::UnprocessableEntity(_) => Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum GetCheckoutErrorBody {
     Unauthorized(Problem),
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for GetCheckoutErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum GetStatusErrorBody {
     BadRequest(Problem),
     Unauthorized(Problem),
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for GetStatusErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum TerminateCheckoutErrorBody {
     BadRequest(Problem),
@@ -591,6 +1225,16 @@ pub enum TerminateCheckoutErrorBody {
     NotFound(Problem),
     UnprocessableEntity(Problem),
 }
+impl crate::error::ErrorBody for TerminateCheckoutErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+            Self::UnprocessableEntity(_) => Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum CreateGoCheckoutErrorBody {
     BadRequest(Problem),
@@ -598,8 +1242,18 @@ pub enum CreateGoCheckoutErrorBody {
     NotFound(Problem),
     UnprocessableEntity(Problem),
 }
+impl crate::error::ErrorBody for CreateGoCheckoutErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+            Self::UnprocessableEntity(_) => Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY),
+        }
+    }
+}
 /// Client for the Readers API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReadersClient<'a> {
     client: &'a Client,
 }
@@ -611,6 +1265,11 @@ impl<'a> ReadersClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list`]; any one of them grants access.
+    pub const LIST_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::ReadersRead,
+        crate::scope::Scope::TerminalsRead,
+    ];
     /// List Readers
     ///
     /// List all readers of the merchant.
@@ -618,44 +1277,77 @@ impl<'a> ReadersClient<'a> {
     /// Responses:
     /// - 200: Returns a list Reader objects.
     /// - 401: Authentication failed or missing required scope.
+    ///
+    /// OAuth 2.0 scopes (any of): `readers.read`, `terminals.read`
     pub async fn list(
         &self,
         merchant_code: impl Into<String>,
     ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(merchant_code, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(merchant_code, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], additionally returning the metadata of the response.
+    pub async fn list_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(merchant_code, &Default::default()).await
+    }
+    /// Same as [`Self::list_with_response`], sending the request with the given options.
+    pub async fn list_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(merchant_code, &options).await
+    }
+    async fn send_list(
+        &self,
+        merchant_code: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.client.check_scopes(Self::LIST_SCOPES)?;
         let path = format!("/v0.1/merchants/{}/readers", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ListResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (ListResponse, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(ListErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::create`]; any one of them grants access.
+    pub const CREATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::ReadersWrite,
+        crate::scope::Scope::TerminalsWrite,
+    ];
     /// Create a Reader
     ///
     /// Create a new Reader for the merchant account.
@@ -665,54 +1357,101 @@ impl<'a> ReadersClient<'a> {
     /// - 400: The request is invalid.
     /// - 404: There's no pending reader for the submitted pairing code.
     /// - 409: The Reader is not in a pending state.
+    ///
+    /// OAuth 2.0 scopes (any of): `readers.write`, `terminals.write`
     pub async fn create(
         &self,
         merchant_code: impl Into<String>,
         body: CreateRequest,
     ) -> crate::error::SdkResult<Reader, CreateErrorBody> {
+        self.send_create(merchant_code, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn create_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Reader, CreateErrorBody> {
+        self.send_create(merchant_code, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], additionally returning the metadata of the response.
+    pub async fn create_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+    ) -> crate::error::SdkResult<(Reader, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(merchant_code, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::create_with_response`], sending the request with the given options.
+    pub async fn create_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Reader, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(merchant_code, body, &options).await
+    }
+    async fn send_create(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Reader, crate::response::ResponseMeta), CreateErrorBody> {
+        self.client.check_scopes(Self::CREATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!("/v0.1/merchants/{}/readers", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .post(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::POST, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::CREATED => {
-                let data: Reader = response.json().await?;
-                Ok(data)
+                let (data, meta): (Reader, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::CONFLICT => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::Conflict(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::Conflict(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::delete`]; any one of them grants access.
+    pub const DELETE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::ReadersWrite,
+        crate::scope::Scope::TerminalsWrite,
+    ];
     /// Delete a reader
     ///
     /// Delete a reader.
@@ -720,44 +1459,86 @@ impl<'a> ReadersClient<'a> {
     /// Responses:
     /// - 200: Returns an empty response if the deletion succeeded.
     /// - 404: The requested Reader resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `readers.write`, `terminals.write`
     pub async fn delete(
         &self,
         merchant_code: impl Into<String>,
         reader_id: impl Into<String>,
     ) -> crate::error::SdkResult<(), DeleteErrorBody> {
+        self.send_delete(merchant_code, reader_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::delete`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn delete_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(), DeleteErrorBody> {
+        self.send_delete(merchant_code, reader_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::delete`], additionally returning the metadata of the response.
+    pub async fn delete_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), DeleteErrorBody> {
+        self.send_delete(merchant_code, reader_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::delete_with_response`], sending the request with the given options.
+    pub async fn delete_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), DeleteErrorBody> {
+        self.send_delete(merchant_code, reader_id, &options).await
+    }
+    async fn send_delete(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), DeleteErrorBody> {
+        self.client.check_scopes(Self::DELETE_SCOPES)?;
         let path = format!(
             "/v0.1/merchants/{}/readers/{}",
             merchant_code.into(),
             reader_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .delete(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::DELETE, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
-            reqwest::StatusCode::OK => Ok(()),
+            reqwest::StatusCode::OK => {
+                Ok(((), crate::response::ResponseMeta::from_response(&response)))
+            }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(DeleteErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    DeleteErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get`]; any one of them grants access.
+    pub const GET_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::ReadersRead,
+        crate::scope::Scope::TerminalsRead,
+    ];
     /// Retrieve a Reader
     ///
     /// Retrieve a Reader.
@@ -765,47 +1546,87 @@ impl<'a> ReadersClient<'a> {
     /// Responses:
     /// - 200: Returns a Reader object for a valid identifier.
     /// - 404: The requested Reader resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `readers.read`, `terminals.read`
     pub async fn get(
         &self,
         merchant_code: impl Into<String>,
         reader_id: impl Into<String>,
     ) -> crate::error::SdkResult<Reader, GetErrorBody> {
+        self.send_get(merchant_code, reader_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Reader, GetErrorBody> {
+        self.send_get(merchant_code, reader_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], additionally returning the metadata of the response.
+    pub async fn get_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+    ) -> crate::error::SdkResult<(Reader, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(merchant_code, reader_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::get_with_response`], sending the request with the given options.
+    pub async fn get_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Reader, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(merchant_code, reader_id, &options).await
+    }
+    async fn send_get(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Reader, crate::response::ResponseMeta), GetErrorBody> {
+        self.client.check_scopes(Self::GET_SCOPES)?;
         let path = format!(
             "/v0.1/merchants/{}/readers/{}",
             merchant_code.into(),
             reader_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Reader = response.json().await?;
-                Ok(data)
+                let (data, meta): (Reader, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::update`]; any one of them grants access.
+    pub const UPDATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::ReadersWrite,
+        crate::scope::Scope::TerminalsWrite,
+    ];
     /// Update a Reader
     ///
     /// Update a Reader.
@@ -814,55 +1635,101 @@ impl<'a> ReadersClient<'a> {
     /// - 200: Returns the updated Reader object if the update succeeded.
     /// - 403: The request isn't sufficiently authorized to modify the reader.
     /// - 404: The requested Reader resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `readers.write`, `terminals.write`
     pub async fn update(
         &self,
         merchant_code: impl Into<String>,
         reader_id: impl Into<String>,
         body: UpdateRequest,
     ) -> crate::error::SdkResult<Reader, UpdateErrorBody> {
+        self.send_update(merchant_code, reader_id, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn update_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Reader, UpdateErrorBody> {
+        self.send_update(merchant_code, reader_id, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], additionally returning the metadata of the response.
+    pub async fn update_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: UpdateRequest,
+    ) -> crate::error::SdkResult<(Reader, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(merchant_code, reader_id, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::update_with_response`], sending the request with the given options.
+    pub async fn update_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Reader, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(merchant_code, reader_id, body, &options)
+            .await
+    }
+    async fn send_update(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: UpdateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Reader, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.client.check_scopes(Self::UPDATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!(
             "/v0.1/merchants/{}/readers/{}",
             merchant_code.into(),
             reader_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .patch(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::PATCH, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Reader = response.json().await?;
-                Ok(data)
+                let (data, meta): (Reader, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::Forbidden(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::Forbidden(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::create_checkout`]; any one of them grants access.
+    pub const CREATE_CHECKOUT_SCOPES: &[crate::scope::Scope] = &[crate::scope::Scope::ReadersWrite];
     /// Create a Reader Checkout
     ///
     /// Creates a Checkout for a Reader.
@@ -883,69 +1750,125 @@ impl<'a> ReadersClient<'a> {
     /// - 401: Unauthorized
     /// - 404: Response when given reader is not found
     /// - 422: Response when given params (or one of them) are invalid
+    ///
+    /// OAuth 2.0 scopes (any of): `readers.write`
     pub async fn create_checkout(
         &self,
         merchant_code: impl Into<String>,
         reader_id: impl Into<String>,
         body: CreateCheckoutRequest,
     ) -> crate::error::SdkResult<CreateReaderCheckoutResponse, CreateCheckoutErrorBody> {
+        self.send_create_checkout(merchant_code, reader_id, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create_checkout`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn create_checkout_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: CreateCheckoutRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<CreateReaderCheckoutResponse, CreateCheckoutErrorBody> {
+        self.send_create_checkout(merchant_code, reader_id, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create_checkout`], additionally returning the metadata of the response.
+    pub async fn create_checkout_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: CreateCheckoutRequest,
+    ) -> crate::error::SdkResult<
+        (CreateReaderCheckoutResponse, crate::response::ResponseMeta),
+        CreateCheckoutErrorBody,
+    > {
+        self.send_create_checkout(merchant_code, reader_id, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::create_checkout_with_response`], sending the request with the given options.
+    pub async fn create_checkout_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: CreateCheckoutRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (CreateReaderCheckoutResponse, crate::response::ResponseMeta),
+        CreateCheckoutErrorBody,
+    > {
+        self.send_create_checkout(merchant_code, reader_id, body, &options)
+            .await
+    }
+    async fn send_create_checkout(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: CreateCheckoutRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (CreateReaderCheckoutResponse, crate::response::ResponseMeta),
+        CreateCheckoutErrorBody,
+    > {
+        self.client.check_scopes(Self::CREATE_CHECKOUT_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!(
             "/v0.1/merchants/{}/readers/{}/checkout",
             merchant_code.into(),
             reader_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .post(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::POST, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::CREATED => {
-                let data: CreateReaderCheckoutResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (CreateReaderCheckoutResponse, _) =
+                    crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateCheckoutErrorBody::BadRequest(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateCheckoutErrorBody::Unauthorized(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateCheckoutErrorBody::NotFound(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateCheckoutErrorBody::UnprocessableEntity(body),
+                    meta,
                 ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get_checkout`]; any one of them grants access.
+    pub const GET_CHECKOUT_SCOPES: &[crate::scope::Scope] = &[crate::scope::Scope::ReadersRead];
     /// Get a Reader Checkout
     ///
     /// Get a Checkout for a Reader.
@@ -954,12 +1877,68 @@ impl<'a> ReadersClient<'a> {
     /// - 200: The Checkout got successfully retrieved for the given reader.
     /// - 401: Unauthorized
     /// - 404: Response when given reader or checkout is not found
+    ///
+    /// OAuth 2.0 scopes (any of): `readers.read`
     pub async fn get_checkout(
         &self,
         merchant_code: impl Into<String>,
         reader_id: impl Into<String>,
         checkout_id: impl Into<String>,
     ) -> crate::error::SdkResult<GetReaderCheckoutResponse, GetCheckoutErrorBody> {
+        self.send_get_checkout(merchant_code, reader_id, checkout_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get_checkout`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_checkout_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        checkout_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<GetReaderCheckoutResponse, GetCheckoutErrorBody> {
+        self.send_get_checkout(merchant_code, reader_id, checkout_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get_checkout`], additionally returning the metadata of the response.
+    pub async fn get_checkout_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        checkout_id: impl Into<String>,
+    ) -> crate::error::SdkResult<
+        (GetReaderCheckoutResponse, crate::response::ResponseMeta),
+        GetCheckoutErrorBody,
+    > {
+        self.send_get_checkout(merchant_code, reader_id, checkout_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::get_checkout_with_response`], sending the request with the given options.
+    pub async fn get_checkout_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        checkout_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (GetReaderCheckoutResponse, crate::response::ResponseMeta),
+        GetCheckoutErrorBody,
+    > {
+        self.send_get_checkout(merchant_code, reader_id, checkout_id, &options)
+            .await
+    }
+    async fn send_get_checkout(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        checkout_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (GetReaderCheckoutResponse, crate::response::ResponseMeta),
+        GetCheckoutErrorBody,
+    > {
+        self.client.check_scopes(Self::GET_CHECKOUT_SCOPES)?;
         let path = format!(
             "/v0.1/merchants/{}/readers/{}/checkout/{}",
             merchant_code.into(),
@@ -967,44 +1946,39 @@ impl<'a> ReadersClient<'a> {
             checkout_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: GetReaderCheckoutResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (GetReaderCheckoutResponse, _) =
+                    crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     GetCheckoutErrorBody::Unauthorized(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetCheckoutErrorBody::NotFound(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetCheckoutErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get_status`]; any one of them grants access.
+    pub const GET_STATUS_SCOPES: &[crate::scope::Scope] = &[crate::scope::Scope::ReadersRead];
     /// Get a Reader Status
     ///
     /// Provides the last known status for a Reader.
@@ -1032,61 +2006,103 @@ impl<'a> ReadersClient<'a> {
     /// - 400: Response when given params (or one of them) are invalid
     /// - 401: Response when given merchant's token is invalid
     /// - 404: Response when given reader is not found
+    ///
+    /// OAuth 2.0 scopes (any of): `readers.read`
     pub async fn get_status(
         &self,
         merchant_code: impl Into<String>,
         reader_id: impl Into<String>,
     ) -> crate::error::SdkResult<StatusResponse, GetStatusErrorBody> {
+        self.send_get_status(merchant_code, reader_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get_status`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_status_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<StatusResponse, GetStatusErrorBody> {
+        self.send_get_status(merchant_code, reader_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get_status`], additionally returning the metadata of the response.
+    pub async fn get_status_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+    ) -> crate::error::SdkResult<(StatusResponse, crate::response::ResponseMeta), GetStatusErrorBody>
+    {
+        self.send_get_status(merchant_code, reader_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::get_status_with_response`], sending the request with the given options.
+    pub async fn get_status_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(StatusResponse, crate::response::ResponseMeta), GetStatusErrorBody>
+    {
+        self.send_get_status(merchant_code, reader_id, &options)
+            .await
+    }
+    async fn send_get_status(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(StatusResponse, crate::response::ResponseMeta), GetStatusErrorBody>
+    {
+        self.client.check_scopes(Self::GET_STATUS_SCOPES)?;
         let path = format!(
             "/v0.1/merchants/{}/readers/{}/status",
             merchant_code.into(),
             reader_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: StatusResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (StatusResponse, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetStatusErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetStatusErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     GetStatusErrorBody::Unauthorized(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetStatusErrorBody::NotFound(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetStatusErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::terminate_checkout`]; any one of them grants access.
+    pub const TERMINATE_CHECKOUT_SCOPES: &[crate::scope::Scope] =
+        &[crate::scope::Scope::ReadersWrite];
     /// Terminate a Reader Checkout
     ///
     /// Terminate a Reader Checkout stops the current transaction on the target device.
@@ -1110,64 +2126,111 @@ impl<'a> ReadersClient<'a> {
     /// - 401: Unauthorized
     /// - 404: Response when given reader is not found
     /// - 422: Response when given params (or one of them) are invalid
+    ///
+    /// OAuth 2.0 scopes (any of): `readers.write`
     pub async fn terminate_checkout(
         &self,
         merchant_code: impl Into<String>,
         reader_id: impl Into<String>,
     ) -> crate::error::SdkResult<(), TerminateCheckoutErrorBody> {
+        self.send_terminate_checkout(merchant_code, reader_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::terminate_checkout`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn terminate_checkout_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(), TerminateCheckoutErrorBody> {
+        self.send_terminate_checkout(merchant_code, reader_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::terminate_checkout`], additionally returning the metadata of the response.
+    pub async fn terminate_checkout_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), TerminateCheckoutErrorBody>
+    {
+        self.send_terminate_checkout(merchant_code, reader_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::terminate_checkout_with_response`], sending the request with the given options.
+    pub async fn terminate_checkout_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), TerminateCheckoutErrorBody>
+    {
+        self.send_terminate_checkout(merchant_code, reader_id, &options)
+            .await
+    }
+    async fn send_terminate_checkout(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), TerminateCheckoutErrorBody>
+    {
+        self.client.check_scopes(Self::TERMINATE_CHECKOUT_SCOPES)?;
         let path = format!(
             "/v0.1/merchants/{}/readers/{}/terminate",
             merchant_code.into(),
             reader_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .post(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::POST, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
-            reqwest::StatusCode::ACCEPTED => Ok(()),
+            reqwest::StatusCode::ACCEPTED => {
+                Ok(((), crate::response::ResponseMeta::from_response(&response)))
+            }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     TerminateCheckoutErrorBody::BadRequest(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     TerminateCheckoutErrorBody::Unauthorized(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     TerminateCheckoutErrorBody::NotFound(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     TerminateCheckoutErrorBody::UnprocessableEntity(body),
+                    meta,
                 ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::create_go_checkout`]; any one of them grants access.
+    pub const CREATE_GO_CHECKOUT_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::Payments,
+        crate::scope::Scope::ReadersWrite,
+    ];
     /// Create a Go Reader Payment
     ///
     /// Initiates a payment on the SumUp Go terminal identified by the reader ID.
@@ -1180,67 +2243,143 @@ impl<'a> ReadersClient<'a> {
     /// - 401: Authentication failed or missing required scope.
     /// - 404: The requested Reader resource does not exist.
     /// - 422: The request could not be processed as it violates a business rule.
+    ///
+    /// OAuth 2.0 scopes (any of): `payments`, `readers.write`
     pub async fn create_go_checkout(
         &self,
         merchant_code: impl Into<String>,
         reader_id: impl Into<String>,
         body: CreateGoCheckoutRequest,
     ) -> crate::error::SdkResult<ReaderPaymentResponse, CreateGoCheckoutErrorBody> {
+        self.send_create_go_checkout(merchant_code, reader_id, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create_go_checkout`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn create_go_checkout_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: CreateGoCheckoutRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<ReaderPaymentResponse, CreateGoCheckoutErrorBody> {
+        self.send_create_go_checkout(merchant_code, reader_id, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create_go_checkout`], additionally returning the metadata of the response.
+    pub async fn create_go_checkout_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: CreateGoCheckoutRequest,
+    ) -> crate::error::SdkResult<
+        (ReaderPaymentResponse, crate::response::ResponseMeta),
+        CreateGoCheckoutErrorBody,
+    > {
+        self.send_create_go_checkout(merchant_code, reader_id, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::create_go_checkout_with_response`], sending the request with the given options.
+    pub async fn create_go_checkout_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: CreateGoCheckoutRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (ReaderPaymentResponse, crate::response::ResponseMeta),
+        CreateGoCheckoutErrorBody,
+    > {
+        self.send_create_go_checkout(merchant_code, reader_id, body, &options)
+            .await
+    }
+    async fn send_create_go_checkout(
+        &self,
+        merchant_code: impl Into<String>,
+        reader_id: impl Into<String>,
+        body: CreateGoCheckoutRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<
+        (ReaderPaymentResponse, crate::response::ResponseMeta),
+        CreateGoCheckoutErrorBody,
+    > {
+        self.client.check_scopes(Self::CREATE_GO_CHECKOUT_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!(
             "/v0/merchants/{}/readers/{}/go-checkout",
             merchant_code.into(),
             reader_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .post(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::POST, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send_deduplicated(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ReaderPaymentResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (ReaderPaymentResponse, _) =
+                    crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateGoCheckoutErrorBody::BadRequest(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateGoCheckoutErrorBody::Unauthorized(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateGoCheckoutErrorBody::NotFound(body),
+                    meta,
                 ))
             }
             reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     CreateGoCheckoutErrorBody::UnprocessableEntity(body),
+                    meta,
                 ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
 }
+/// Requests SumUp sends to your server for the operations of this module.
+pub mod callbacks {
+    /// The callback payload containing the status change of the Reader Checkout.
+    ///
+    /// Sent as `POST {$request.body#/return_url}` for requests made with [`create_checkout`](super::ReadersClient::create_checkout).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ReaderCheckoutStatusChangeCallback;
+    impl crate::callback::Callback for ReaderCheckoutStatusChangeCallback {
+        const NAME: &'static str = "ReaderCheckoutStatusChange";
+        const OPERATION: &'static str = "create_checkout";
+        const METHOD: reqwest::Method = reqwest::Method::POST;
+        const URL: &'static str = "{$request.body#/return_url}";
+        const RESPONSES: &'static [crate::callback::CallbackResponse] = &[
+            crate::callback::CallbackResponse {
+                status: 200,
+                description: "Your server returns this code if it accepts the callback.\nIf the server returns any other code, the callback will be retried up to 5 times with exponential backoff.",
+            },
+        ];
+        type Request = super::ReaderCheckoutStatusChange;
+        type Response = ();
+    }
+}
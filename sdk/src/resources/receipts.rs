@@ -45,16 +45,9 @@ pub struct ReceiptEvent {
     pub status: Option<TransactionEventStatus>,
     /// Amount associated with the transaction event, in major units.
     ///
-    /// Constraints:
-    /// - format: `double`
-    ///
     /// Example: `10.10`
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::string_or_number::deserialize_option"
-    )]
-    pub amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<crate::money::Amount>,
     /// The timestamp of when the transaction event occurred.
     ///
     /// Example: `2020-05-25T10:49:42.784Z`
@@ -323,16 +316,9 @@ pub struct ReceiptTransactionProductsItem {
     pub description: Option<String>,
     /// Product price.
     ///
-    /// Constraints:
-    /// - format: `double`
-    ///
     /// Example: `150.0`
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::string_or_number::deserialize_option"
-    )]
-    pub price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<crate::money::Amount>,
     /// VAT rate.
     ///
     /// Constraints:
@@ -347,40 +333,19 @@ pub struct ReceiptTransactionProductsItem {
     pub vat_rate: Option<f64>,
     /// VAT amount for a single product.
     ///
-    /// Constraints:
-    /// - format: `double`
-    ///
     /// Example: `0.0`
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::string_or_number::deserialize_option"
-    )]
-    pub single_vat_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_vat_amount: Option<crate::money::Amount>,
     /// Product price including VAT.
     ///
-    /// Constraints:
-    /// - format: `double`
-    ///
     /// Example: `150.0`
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::string_or_number::deserialize_option"
-    )]
-    pub price_with_vat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_with_vat: Option<crate::money::Amount>,
     /// Total VAT amount for the product quantity.
     ///
-    /// Constraints:
-    /// - format: `double`
-    ///
     /// Example: `0.0`
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::string_or_number::deserialize_option"
-    )]
-    pub vat_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_amount: Option<crate::money::Amount>,
     /// Product quantity.
     ///
     /// Example: `1`
@@ -388,28 +353,14 @@ pub struct ReceiptTransactionProductsItem {
     pub quantity: Option<i64>,
     /// Total price calculated as the product price multiplied by the quantity.
     ///
-    /// Constraints:
-    /// - format: `double`
-    ///
     /// Example: `150.0`
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::string_or_number::deserialize_option"
-    )]
-    pub total_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_price: Option<crate::money::Amount>,
     /// Total product price including VAT.
     ///
-    /// Constraints:
-    /// - format: `double`
-    ///
     /// Example: `150.0`
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::string_or_number::deserialize_option"
-    )]
-    pub total_with_vat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_with_vat: Option<crate::money::Amount>,
 }
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ReceiptTransactionVatRatesItem {
@@ -417,12 +368,12 @@ pub struct ReceiptTransactionVatRatesItem {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub gross: Option<f32>,
+    pub gross: Option<crate::money::Amount>,
     /// Net amount to which the VAT rate applies.
     ///
     /// Example: `8.49`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub net: Option<f32>,
+    pub net: Option<crate::money::Amount>,
     /// VAT rate applied to the transaction amount.
     ///
     /// Example: `0.19`
@@ -432,9 +383,10 @@ pub struct ReceiptTransactionVatRatesItem {
     ///
     /// Example: `1.61`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat: Option<f32>,
+    pub vat: Option<crate::money::Amount>,
 }
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct GetParams {
     /// Short unique identifier for the merchant.
     ///
@@ -446,6 +398,45 @@ pub struct GetParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tx_event_id: Option<i64>,
 }
+impl GetParams {
+    /// Returns a builder for [`GetParams`], taking its required fields.
+    pub fn builder(mid: impl Into<String>) -> GetParamsBuilder {
+        GetParamsBuilder {
+            inner: Self {
+                mid: mid.into(),
+                tx_event_id: None,
+            },
+        }
+    }
+}
+/// Builder for [`GetParams`], see [`GetParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct GetParamsBuilder {
+    inner: GetParams,
+}
+impl GetParamsBuilder {
+    /// Unique identifier of the transaction event to include on the receipt.
+    ///
+    /// Example: `9567461191`
+    pub fn tx_event_id(mut self, tx_event_id: impl Into<i64>) -> Self {
+        self.inner.tx_event_id = Some(tx_event_id.into());
+        self
+    }
+    /// Returns the built [`GetParams`].
+    pub fn build(self) -> GetParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for GetParams {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        {
+            let value = &self.mid;
+            let path = crate::validation::field_path(path, "mid");
+            crate::validation::check_required(value, &path, violations);
+        }
+    }
+}
 use crate::client::Client;
 #[derive(Debug, PartialEq)]
 pub enum GetErrorBody {
@@ -453,8 +444,17 @@ pub enum GetErrorBody {
     Unauthorized(Problem),
     NotFound(Error),
 }
+impl crate::error::ErrorBody for GetErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 /// Client for the Receipts API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReceiptsClient<'a> {
     client: &'a Client,
 }
@@ -466,6 +466,8 @@ impl<'a> ReceiptsClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get`]; any one of them grants access.
+    pub const GET_SCOPES: &[crate::scope::Scope] = &[crate::scope::Scope::ReceiptsRead];
     /// Retrieve receipt details
     ///
     /// Retrieves receipt specific data for a transaction.
@@ -475,54 +477,94 @@ impl<'a> ReceiptsClient<'a> {
     /// - 400: The request is invalid for the submitted parameters.
     /// - 401: The request is not authorized.
     /// - 404: The requested transaction event does not exist for the provided transaction.
+    ///
+    /// OAuth 2.0 scopes (any of): `receipts.read`
     pub async fn get(
         &self,
         transaction_id: impl Into<String>,
         params: GetParams,
     ) -> crate::error::SdkResult<Receipt, GetErrorBody> {
+        self.send_get(transaction_id, params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_with_options(
+        &self,
+        transaction_id: impl Into<String>,
+        params: GetParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Receipt, GetErrorBody> {
+        self.send_get(transaction_id, params, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], additionally returning the metadata of the response.
+    pub async fn get_with_response(
+        &self,
+        transaction_id: impl Into<String>,
+        params: GetParams,
+    ) -> crate::error::SdkResult<(Receipt, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(transaction_id, params, &Default::default())
+            .await
+    }
+    /// Same as [`Self::get_with_response`], sending the request with the given options.
+    pub async fn get_with_response_and_options(
+        &self,
+        transaction_id: impl Into<String>,
+        params: GetParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Receipt, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(transaction_id, params, &options).await
+    }
+    async fn send_get(
+        &self,
+        transaction_id: impl Into<String>,
+        params: GetParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Receipt, crate::response::ResponseMeta), GetErrorBody> {
+        self.client.check_scopes(Self::GET_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = format!("/v1.1/receipts/{}", transaction_id.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         request = request.query(&[("mid", &params.mid)]);
         if let Some(ref value) = params.tx_event_id {
             request = request.query(&[("tx_event_id", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Receipt = response.json().await?;
-                Ok(data)
+                let (data, meta): (Receipt, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::BadRequest(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::NotFound(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
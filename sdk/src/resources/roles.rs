@@ -44,6 +44,7 @@ pub struct ListResponse {
     pub items: Vec<Role>,
 }
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct CreateRequest {
     /// User-defined name of the role.
     ///
@@ -62,7 +63,61 @@ pub struct CreateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
+impl CreateRequest {
+    /// Returns a builder for [`CreateRequest`], taking its required fields.
+    pub fn builder(
+        name: impl Into<String>,
+        permissions: impl Into<Vec<String>>,
+    ) -> CreateRequestBuilder {
+        CreateRequestBuilder {
+            inner: Self {
+                name: name.into(),
+                permissions: permissions.into(),
+                metadata: None,
+                description: None,
+            },
+        }
+    }
+}
+/// Builder for [`CreateRequest`], see [`CreateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CreateRequestBuilder {
+    inner: CreateRequest,
+}
+impl CreateRequestBuilder {
+    /// Set of user-defined key-value pairs attached to the object. Partial updates are not supported. When updating, always submit whole metadata. Maximum of 64 parameters are allowed in the object.
+    pub fn metadata(mut self, metadata: impl Into<Metadata>) -> Self {
+        self.inner.metadata = Some(metadata.into());
+        self
+    }
+    /// User-defined description of the role.
+    ///
+    /// Example: `Manges the shop and the employees.`
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.inner.description = Some(description.into());
+        self
+    }
+    /// Returns the built [`CreateRequest`].
+    pub fn build(self) -> CreateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for CreateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        {
+            let value = &self.permissions;
+            let path = crate::validation::field_path(path, "permissions");
+            crate::validation::check_items(value.len(), None, Some(100), &path, violations);
+        }
+        if let Some(value) = &self.metadata {
+            let path = crate::validation::field_path(path, "metadata");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct UpdateRequest {
     /// User-defined name of the role.
     ///
@@ -81,32 +136,124 @@ pub struct UpdateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
+impl UpdateRequest {
+    /// Returns a builder for [`UpdateRequest`], taking its required fields.
+    pub fn builder() -> UpdateRequestBuilder {
+        UpdateRequestBuilder {
+            inner: Self {
+                name: None,
+                permissions: None,
+                description: None,
+            },
+        }
+    }
+}
+/// Builder for [`UpdateRequest`], see [`UpdateRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct UpdateRequestBuilder {
+    inner: UpdateRequest,
+}
+impl UpdateRequestBuilder {
+    /// User-defined name of the role.
+    ///
+    /// Example: `Senior Shop Manager II`
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.inner.name = Some(name.into());
+        self
+    }
+    /// User's permissions.
+    ///
+    /// Constraints:
+    /// - max items: 100
+    pub fn permissions(mut self, permissions: impl Into<Vec<String>>) -> Self {
+        self.inner.permissions = Some(permissions.into());
+        self
+    }
+    /// User-defined description of the role.
+    ///
+    /// Example: `Manges the shop and the employees.`
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.inner.description = Some(description.into());
+        self
+    }
+    /// Returns the built [`UpdateRequest`].
+    pub fn build(self) -> UpdateRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for UpdateRequest {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.permissions {
+            let path = crate::validation::field_path(path, "permissions");
+            crate::validation::check_items(value.len(), None, Some(100), &path, violations);
+        }
+    }
+}
 use crate::client::Client;
 #[derive(Debug, PartialEq)]
 pub enum ListErrorBody {
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for ListErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum CreateErrorBody {
     BadRequest(Problem),
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for CreateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum DeleteErrorBody {
     BadRequest(Problem),
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for DeleteErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum GetErrorBody {
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for GetErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum UpdateErrorBody {
     BadRequest(Problem),
     NotFound(Problem),
 }
+impl crate::error::ErrorBody for UpdateErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 /// Client for the Roles API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RolesClient<'a> {
     client: &'a Client,
 }
@@ -118,6 +265,11 @@ impl<'a> RolesClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list`]; any one of them grants access.
+    pub const LIST_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::RolesRead,
+    ];
     /// List roles
     ///
     /// List merchant's custom roles.
@@ -125,42 +277,77 @@ impl<'a> RolesClient<'a> {
     /// Responses:
     /// - 200: Returns a list of Role objects.
     /// - 404: Merchant not found.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `roles.read`
     pub async fn list(
         &self,
         merchant_code: impl Into<String>,
     ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(merchant_code, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(merchant_code, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], additionally returning the metadata of the response.
+    pub async fn list_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(merchant_code, &Default::default()).await
+    }
+    /// Same as [`Self::list_with_response`], sending the request with the given options.
+    pub async fn list_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(merchant_code, &options).await
+    }
+    async fn send_list(
+        &self,
+        merchant_code: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.client.check_scopes(Self::LIST_SCOPES)?;
         let path = format!("/v0.1/merchants/{}/roles", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ListResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (ListResponse, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(ListErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::create`]; any one of them grants access.
+    pub const CREATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::RolesWrite,
+    ];
     /// Create a role
     ///
     /// Create a custom role for the merchant. Roles are defined by the set of permissions that they grant to the members that they are assigned to.
@@ -169,50 +356,94 @@ impl<'a> RolesClient<'a> {
     /// - 201: Returns the Role object after successful custom role creation.
     /// - 400: Invalid request.
     /// - 404: Merchant not found.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `roles.write`
     pub async fn create(
         &self,
         merchant_code: impl Into<String>,
         body: CreateRequest,
     ) -> crate::error::SdkResult<Role, CreateErrorBody> {
+        self.send_create(merchant_code, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn create_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Role, CreateErrorBody> {
+        self.send_create(merchant_code, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::create`], additionally returning the metadata of the response.
+    pub async fn create_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+    ) -> crate::error::SdkResult<(Role, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(merchant_code, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::create_with_response`], sending the request with the given options.
+    pub async fn create_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Role, crate::response::ResponseMeta), CreateErrorBody> {
+        self.send_create(merchant_code, body, &options).await
+    }
+    async fn send_create(
+        &self,
+        merchant_code: impl Into<String>,
+        body: CreateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Role, crate::response::ResponseMeta), CreateErrorBody> {
+        self.client.check_scopes(Self::CREATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!("/v0.1/merchants/{}/roles", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .post(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::POST, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::CREATED => {
-                let data: Role = response.json().await?;
-                Ok(data)
+                let (data, meta): (Role, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(CreateErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    CreateErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::delete`]; any one of them grants access.
+    pub const DELETE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::RolesWrite,
+    ];
     /// Delete a role
     ///
     /// Delete a custom role.
@@ -221,50 +452,93 @@ impl<'a> RolesClient<'a> {
     /// - 200: Returns an empty response if the role deletion succeeded.
     /// - 400: Invalid request.
     /// - 404: Merchant not found.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `roles.write`
     pub async fn delete(
         &self,
         merchant_code: impl Into<String>,
         role_id: impl Into<String>,
     ) -> crate::error::SdkResult<(), DeleteErrorBody> {
+        self.send_delete(merchant_code, role_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::delete`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn delete_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(), DeleteErrorBody> {
+        self.send_delete(merchant_code, role_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::delete`], additionally returning the metadata of the response.
+    pub async fn delete_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), DeleteErrorBody> {
+        self.send_delete(merchant_code, role_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::delete_with_response`], sending the request with the given options.
+    pub async fn delete_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), DeleteErrorBody> {
+        self.send_delete(merchant_code, role_id, &options).await
+    }
+    async fn send_delete(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<((), crate::response::ResponseMeta), DeleteErrorBody> {
+        self.client.check_scopes(Self::DELETE_SCOPES)?;
         let path = format!(
             "/v0.1/merchants/{}/roles/{}",
             merchant_code.into(),
             role_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .delete(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::DELETE, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
-            reqwest::StatusCode::OK => Ok(()),
+            reqwest::StatusCode::OK => {
+                Ok(((), crate::response::ResponseMeta::from_response(&response)))
+            }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(DeleteErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    DeleteErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(DeleteErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    DeleteErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get`]; any one of them grants access.
+    pub const GET_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::RolesRead,
+    ];
     /// Retrieve a role
     ///
     /// Retrieve a custom role by ID.
@@ -272,47 +546,87 @@ impl<'a> RolesClient<'a> {
     /// Responses:
     /// - 200: Returns the Role object for a valid identifier.
     /// - 404: Merchant or role not found.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `roles.read`
     pub async fn get(
         &self,
         merchant_code: impl Into<String>,
         role_id: impl Into<String>,
     ) -> crate::error::SdkResult<Role, GetErrorBody> {
+        self.send_get(merchant_code, role_id, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Role, GetErrorBody> {
+        self.send_get(merchant_code, role_id, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], additionally returning the metadata of the response.
+    pub async fn get_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+    ) -> crate::error::SdkResult<(Role, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(merchant_code, role_id, &Default::default())
+            .await
+    }
+    /// Same as [`Self::get_with_response`], sending the request with the given options.
+    pub async fn get_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Role, crate::response::ResponseMeta), GetErrorBody> {
+        self.send_get(merchant_code, role_id, &options).await
+    }
+    async fn send_get(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Role, crate::response::ResponseMeta), GetErrorBody> {
+        self.client.check_scopes(Self::GET_SCOPES)?;
         let path = format!(
             "/v0.1/merchants/{}/roles/{}",
             merchant_code.into(),
             role_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let request = self.client.request(reqwest::Method::GET, &url, options);
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Role = response.json().await?;
-                Ok(data)
+                let (data, meta): (Role, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::update`]; any one of them grants access.
+    pub const UPDATE_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::UserSubaccounts,
+        crate::scope::Scope::RolesWrite,
+    ];
     /// Update a role
     ///
     /// Update a custom role.
@@ -321,52 +635,96 @@ impl<'a> RolesClient<'a> {
     /// - 200: Returns the updated Role object if the update succeeded.
     /// - 400: Invalid request.
     /// - 404: Merchant not found.
+    ///
+    /// OAuth 2.0 scopes (any of): `user.subaccounts`, `roles.write`
     pub async fn update(
         &self,
         merchant_code: impl Into<String>,
         role_id: impl Into<String>,
         body: UpdateRequest,
     ) -> crate::error::SdkResult<Role, UpdateErrorBody> {
+        self.send_update(merchant_code, role_id, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn update_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<Role, UpdateErrorBody> {
+        self.send_update(merchant_code, role_id, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::update`], additionally returning the metadata of the response.
+    pub async fn update_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        body: UpdateRequest,
+    ) -> crate::error::SdkResult<(Role, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(merchant_code, role_id, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::update_with_response`], sending the request with the given options.
+    pub async fn update_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        body: UpdateRequest,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Role, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.send_update(merchant_code, role_id, body, &options)
+            .await
+    }
+    async fn send_update(
+        &self,
+        merchant_code: impl Into<String>,
+        role_id: impl Into<String>,
+        body: UpdateRequest,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(Role, crate::response::ResponseMeta), UpdateErrorBody> {
+        self.client.check_scopes(Self::UPDATE_SCOPES)?;
+        self.client.validate_request(&body)?;
         let path = format!(
             "/v0.1/merchants/{}/roles/{}",
             merchant_code.into(),
             role_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
+        let request = self
             .client
-            .http_client()
-            .patch(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout())
+            .request(reqwest::Method::PATCH, &url, options)
             .json(&body);
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: Role = response.json().await?;
-                Ok(data)
+                let (data, meta): (Role, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(UpdateErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    UpdateErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
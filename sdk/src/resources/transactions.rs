@@ -105,7 +105,7 @@ pub struct Event {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
     /// The timestamp of when the transaction event occurred.
     ///
     /// Example: `2020-05-25T10:49:42.784Z`
@@ -115,7 +115,7 @@ pub struct Event {
     ///
     /// Example: `0.25`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fee_amount: Option<f32>,
+    pub fee_amount: Option<crate::money::Amount>,
     /// Consecutive number of the installment associated with the event.
     ///
     /// Example: `1`
@@ -125,12 +125,12 @@ pub struct Event {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deducted_amount: Option<f32>,
+    pub deducted_amount: Option<crate::money::Amount>,
     /// Fee deducted from the merchant for the event, in major units.
     ///
     /// Example: `0.25`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deducted_fee_amount: Option<f32>,
+    pub deducted_fee_amount: Option<crate::money::Amount>,
 }
 pub type HorizontalAccuracy = f32;
 pub type Lat = f32;
@@ -160,12 +160,12 @@ pub struct Link {
     ///
     /// Example: `0.01`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub min_amount: Option<f32>,
+    pub min_amount: Option<crate::money::Amount>,
     /// Maximum amount allowed for a refund, in major units.
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_amount: Option<f32>,
+    pub max_amount: Option<crate::money::Amount>,
 }
 pub type Lon = f32;
 /// Product details associated with a transaction.
@@ -183,12 +183,9 @@ pub struct Product {
     pub price_label: Option<String>,
     /// Product price.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `100`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub price: Option<f64>,
+    pub price: Option<crate::money::Amount>,
     /// VAT rate applied to the product price.
     ///
     /// Constraints:
@@ -199,28 +196,19 @@ pub struct Product {
     pub vat_rate: Option<f64>,
     /// VAT amount for a single product.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `19`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub single_vat_amount: Option<f64>,
+    pub single_vat_amount: Option<crate::money::Amount>,
     /// Product price including VAT.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `119`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub price_with_vat: Option<f64>,
+    pub price_with_vat: Option<crate::money::Amount>,
     /// Total VAT amount for the product quantity.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `19`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_amount: Option<f64>,
+    pub vat_amount: Option<crate::money::Amount>,
     /// Product quantity.
     ///
     /// Example: `1`
@@ -228,20 +216,14 @@ pub struct Product {
     pub quantity: Option<i64>,
     /// Total price calculated as the product price multiplied by the quantity.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `100`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_price: Option<f64>,
+    pub total_price: Option<crate::money::Amount>,
     /// Total product price including VAT.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `119`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_with_vat: Option<f64>,
+    pub total_with_vat: Option<crate::money::Amount>,
 }
 /// Detailed information about a transaction event.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -254,12 +236,9 @@ pub struct TransactionEvent {
     pub status: Option<TransactionEventStatus>,
     /// Amount of the event.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `58.8`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f64>,
+    pub amount: Option<crate::money::Amount>,
     /// Date when the transaction event is due to occur.
     ///
     /// Example: `2020-05-25`
@@ -298,7 +277,7 @@ pub struct TransactionFull {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     /// The timestamp of when the transaction was created.
@@ -327,12 +306,12 @@ pub struct TransactionFull {
     ///
     /// Example: `6`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat_amount: Option<f32>,
+    pub vat_amount: Option<crate::money::Amount>,
     /// Amount of the tip (out of the total transaction amount).
     ///
     /// Example: `3`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tip_amount: Option<f32>,
+    pub tip_amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entry_mode: Option<EntryMode>,
     /// Authorization code for the transaction sent by the payment card issuer or bank. Applicable only to card payments.
@@ -380,12 +359,9 @@ pub struct TransactionFull {
     pub username: Option<String>,
     /// Transaction SumUp total fee amount.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `8`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fee_amount: Option<f64>,
+    pub fee_amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lat: Option<Lat>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -473,6 +449,44 @@ pub struct TransactionFull {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_enabled: Option<bool>,
 }
+impl TransactionFull {
+    /// Returns `amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+    /// Returns `vat_amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn vat_amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.vat_amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+    /// Returns `tip_amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn tip_amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.tip_amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+    /// Returns `fee_amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn fee_amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.fee_amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+}
 /// Transaction entry returned in history listing responses.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct TransactionHistory {
@@ -490,7 +504,7 @@ pub struct TransactionHistory {
     ///
     /// Example: `10.1`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     /// The timestamp of when the transaction was created.
@@ -565,12 +579,29 @@ pub struct TransactionHistory {
     pub payout_type: Option<TransactionHistoryPayoutType>,
     /// Total refunded amount.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `0`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub refunded_amount: Option<f64>,
+    pub refunded_amount: Option<crate::money::Amount>,
+}
+impl TransactionHistory {
+    /// Returns `amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
+    /// Returns `refunded_amount` in `currency` as [`Money`](crate::Money), or `None` when either is missing.
+    ///
+    /// Fails if the amount has more decimal places than the currency allows.
+    pub fn refunded_amount_money(&self) -> Option<Result<crate::Money, crate::money::MoneyError>> {
+        let (Some(amount), Some(currency)) = (self.refunded_amount, self.currency.as_ref()) else {
+            return None;
+        };
+        Some(crate::Money::from_amount(amount, currency.clone()))
+    }
 }
 /// Hypermedia link used for transaction history pagination.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -690,28 +721,19 @@ pub struct TransactionFullVatRatesItem {
     pub rate: Option<f64>,
     /// NET amount of products having this VAT rate applied.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `1.36`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub net: Option<f64>,
+    pub net: Option<crate::money::Amount>,
     /// VAT amount of this rate applied.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `0.06`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vat: Option<f64>,
+    pub vat: Option<crate::money::Amount>,
     /// Gross amount of products having this VAT rate applied.
     ///
-    /// Constraints:
-    /// - format: `decimal`
-    ///
     /// Example: `1.42`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub gross: Option<f64>,
+    pub gross: Option<crate::money::Amount>,
 }
 /// High-level status of the transaction from the merchant's perspective.
 ///
@@ -811,6 +833,19 @@ pub enum ListParamsOrder {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for ListParamsOrder {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ListParamsStatusesItem {
     #[serde(rename = "SUCCESSFUL")]
@@ -826,6 +861,19 @@ pub enum ListParamsStatusesItem {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for ListParamsStatusesItem {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ListParamsTypesItem {
     #[serde(rename = "PAYMENT")]
@@ -837,18 +885,61 @@ pub enum ListParamsTypesItem {
     #[serde(untagged)]
     Other(String),
 }
+impl crate::validation::Validate for ListParamsTypesItem {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Self::Other(value) = self {
+            crate::validation::violation(
+                path,
+                crate::validation::ViolationKind::Enum {
+                    value: value.clone(),
+                },
+                violations,
+            );
+        }
+    }
+}
 /// Optional amount for partial refunds.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct RefundRequest {
     /// Amount to be refunded. Eligible amount can't exceed the amount of the transaction and varies based on country and currency. If you do not specify a value, the system performs a full refund of the transaction.
     ///
     /// Example: `5`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f32>,
+    pub amount: Option<crate::money::Amount>,
 }
+impl RefundRequest {
+    /// Returns a builder for [`RefundRequest`], taking its required fields.
+    pub fn builder() -> RefundRequestBuilder {
+        RefundRequestBuilder {
+            inner: Self { amount: None },
+        }
+    }
+}
+/// Builder for [`RefundRequest`], see [`RefundRequest::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct RefundRequestBuilder {
+    inner: RefundRequest,
+}
+impl RefundRequestBuilder {
+    /// Amount to be refunded. Eligible amount can't exceed the amount of the transaction and varies based on country and currency. If you do not specify a value, the system performs a full refund of the transaction.
+    ///
+    /// Example: `5`
+    pub fn amount(mut self, amount: impl Into<crate::money::Amount>) -> Self {
+        self.inner.amount = Some(amount.into());
+        self
+    }
+    /// Returns the built [`RefundRequest`].
+    pub fn build(self) -> RefundRequest {
+        self.inner
+    }
+}
+impl crate::validation::Validate for RefundRequest {}
 /// The transaction was refunded in full or partially based on the request.
 pub type RefundResponse = serde_json::Value;
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct GetParams {
     /// Retrieves the transaction resource with the specified transaction ID (the `id` parameter in the transaction resource).
     ///
@@ -871,7 +962,62 @@ pub struct GetParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_transaction_id: Option<String>,
 }
+impl GetParams {
+    /// Returns a builder for [`GetParams`], taking its required fields.
+    pub fn builder() -> GetParamsBuilder {
+        GetParamsBuilder {
+            inner: Self {
+                id: None,
+                transaction_code: None,
+                foreign_transaction_id: None,
+                client_transaction_id: None,
+            },
+        }
+    }
+}
+/// Builder for [`GetParams`], see [`GetParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct GetParamsBuilder {
+    inner: GetParams,
+}
+impl GetParamsBuilder {
+    /// Retrieves the transaction resource with the specified transaction ID (the `id` parameter in the transaction resource).
+    ///
+    /// Example: `410fc44a-5956-44e1-b5cc-19c6f8d727a4`
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.inner.id = Some(id.into());
+        self
+    }
+    /// Retrieves the transaction resource with the specified transaction code.
+    ///
+    /// Example: `TEENSK4W2K`
+    pub fn transaction_code(mut self, transaction_code: impl Into<String>) -> Self {
+        self.inner.transaction_code = Some(transaction_code.into());
+        self
+    }
+    /// External transaction identifier supplied by the client.
+    ///
+    /// Example: `J13253253x1`
+    pub fn foreign_transaction_id(mut self, foreign_transaction_id: impl Into<String>) -> Self {
+        self.inner.foreign_transaction_id = Some(foreign_transaction_id.into());
+        self
+    }
+    /// Client-supplied identifier of the transaction.
+    ///
+    /// Example: `urn:sumup:pos:sale:MNKKNGST:1D4E3B2D-111D-48D7-9AF0-832DAEF63DD7;2`
+    pub fn client_transaction_id(mut self, client_transaction_id: impl Into<String>) -> Self {
+        self.inner.client_transaction_id = Some(client_transaction_id.into());
+        self
+    }
+    /// Returns the built [`GetParams`].
+    pub fn build(self) -> GetParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for GetParams {}
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct ListParams {
     /// Retrieves the transaction resource with the specified transaction code.
     ///
@@ -932,6 +1078,143 @@ pub struct ListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oldest_ref: Option<String>,
 }
+impl ListParams {
+    /// Returns a builder for [`ListParams`], taking its required fields.
+    pub fn builder() -> ListParamsBuilder {
+        ListParamsBuilder {
+            inner: Self {
+                transaction_code: None,
+                order: None,
+                limit: None,
+                users: None,
+                statuses: None,
+                payment_types: None,
+                entry_modes: None,
+                types: None,
+                changes_since: None,
+                newest_time: None,
+                newest_ref: None,
+                oldest_time: None,
+                oldest_ref: None,
+            },
+        }
+    }
+}
+/// Builder for [`ListParams`], see [`ListParams::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ListParamsBuilder {
+    inner: ListParams,
+}
+impl ListParamsBuilder {
+    /// Retrieves the transaction resource with the specified transaction code.
+    ///
+    /// Example: `TEENSK4W2K`
+    pub fn transaction_code(mut self, transaction_code: impl Into<String>) -> Self {
+        self.inner.transaction_code = Some(transaction_code.into());
+        self
+    }
+    /// Specifies the order in which the returned results are displayed.
+    pub fn order(mut self, order: impl Into<ListParamsOrder>) -> Self {
+        self.inner.order = Some(order.into());
+        self
+    }
+    /// Specifies the maximum number of results per page. Value must be a positive integer and if not specified, will return 10 results.
+    ///
+    /// Example: `10`
+    pub fn limit(mut self, limit: impl Into<i64>) -> Self {
+        self.inner.limit = Some(limit.into());
+        self
+    }
+    /// Filters the returned results by user email.
+    pub fn users(mut self, users: impl Into<Vec<String>>) -> Self {
+        self.inner.users = Some(users.into());
+        self
+    }
+    /// Filters the returned results by the specified list of final statuses of the transactions.
+    pub fn statuses(mut self, statuses: impl Into<Vec<ListParamsStatusesItem>>) -> Self {
+        self.inner.statuses = Some(statuses.into());
+        self
+    }
+    /// Filters the returned results by the specified list of payment types used for the transactions.
+    pub fn payment_types(mut self, payment_types: impl Into<Vec<PaymentType>>) -> Self {
+        self.inner.payment_types = Some(payment_types.into());
+        self
+    }
+    /// Filters the returned results by the specified list of entry modes.
+    pub fn entry_modes(mut self, entry_modes: impl Into<Vec<EntryMode>>) -> Self {
+        self.inner.entry_modes = Some(entry_modes.into());
+        self
+    }
+    /// Filters the returned results by the specified list of transaction types.
+    pub fn types(mut self, types: impl Into<Vec<ListParamsTypesItem>>) -> Self {
+        self.inner.types = Some(types.into());
+        self
+    }
+    /// Filters the results by the latest modification time of resources and returns only transactions that are modified *at or after* the specified timestamp (in [ISO8601](https://en.wikipedia.org/wiki/ISO_8601) format).
+    ///
+    /// Example: `2019-08-28T09:00:00Z`
+    pub fn changes_since(mut self, changes_since: impl Into<crate::datetime::DateTime>) -> Self {
+        self.inner.changes_since = Some(changes_since.into());
+        self
+    }
+    /// Filters the results by the creation time of resources and returns only transactions that are created *before* the specified timestamp (in [ISO8601](https://en.wikipedia.org/wiki/ISO_8601) format).
+    ///
+    /// Example: `2019-08-29T09:00:00Z`
+    pub fn newest_time(mut self, newest_time: impl Into<crate::datetime::DateTime>) -> Self {
+        self.inner.newest_time = Some(newest_time.into());
+        self
+    }
+    /// Filters the results by the reference ID of transaction events and returns only transactions with events whose IDs are *smaller* than the specified value. This parameters supersedes the `newest_time` parameter (if both are provided in the request).
+    ///
+    /// Example: `090df9bf-93b7-40f1-8181-fbdb236568a1`
+    pub fn newest_ref(mut self, newest_ref: impl Into<String>) -> Self {
+        self.inner.newest_ref = Some(newest_ref.into());
+        self
+    }
+    /// Filters the results by the creation time of resources and returns only transactions that are created *at or after* the specified timestamp (in [ISO8601](https://en.wikipedia.org/wiki/ISO_8601) format).
+    ///
+    /// Example: `2019-08-28T09:00:00Z`
+    pub fn oldest_time(mut self, oldest_time: impl Into<crate::datetime::DateTime>) -> Self {
+        self.inner.oldest_time = Some(oldest_time.into());
+        self
+    }
+    /// Filters the results by the reference ID of transaction events and returns only transactions with events whose IDs are *greater* than the specified value. This parameters supersedes the `oldest_time` parameter (if both are provided in the request).
+    ///
+    /// Example: `090df9bf-93b7-40f1-8181-fbdb236568a1`
+    pub fn oldest_ref(mut self, oldest_ref: impl Into<String>) -> Self {
+        self.inner.oldest_ref = Some(oldest_ref.into());
+        self
+    }
+    /// Returns the built [`ListParams`].
+    pub fn build(self) -> ListParams {
+        self.inner
+    }
+}
+impl crate::validation::Validate for ListParams {
+    fn validate_at(&self, path: &str, violations: &mut Vec<crate::validation::Violation>) {
+        if let Some(value) = &self.order {
+            let path = crate::validation::field_path(path, "order");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.statuses {
+            let path = crate::validation::field_path(path, "statuses[]");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.payment_types {
+            let path = crate::validation::field_path(path, "payment_types[]");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.entry_modes {
+            let path = crate::validation::field_path(path, "entry_modes[]");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+        if let Some(value) = &self.types {
+            let path = crate::validation::field_path(path, "types[]");
+            crate::validation::Validate::validate_at(value, &path, violations);
+        }
+    }
+}
 /// Returns a page of transaction history items.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ListResponse {
@@ -951,18 +1234,45 @@ pub enum RefundErrorBody {
     Conflict(Problem),
     UnprocessableEntity(Problem),
 }
+impl crate::error::ErrorBody for RefundErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Forbidden(_) => Some(reqwest::StatusCode::FORBIDDEN),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+            Self::Conflict(_) => Some(reqwest::StatusCode::CONFLICT),
+            Self::UnprocessableEntity(_) => Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum GetErrorBody {
     Unauthorized(Problem),
     NotFound(Error),
 }
+impl crate::error::ErrorBody for GetErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+            Self::NotFound(_) => Some(reqwest::StatusCode::NOT_FOUND),
+        }
+    }
+}
 #[derive(Debug, PartialEq)]
 pub enum ListErrorBody {
     BadRequest(Error),
     Unauthorized(Problem),
 }
+impl crate::error::ErrorBody for ListErrorBody {
+    fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::BadRequest(_) => Some(reqwest::StatusCode::BAD_REQUEST),
+            Self::Unauthorized(_) => Some(reqwest::StatusCode::UNAUTHORIZED),
+        }
+    }
+}
 /// Client for the Transactions API endpoints.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TransactionsClient<'a> {
     client: &'a Client,
 }
@@ -974,6 +1284,11 @@ impl<'a> TransactionsClient<'a> {
     pub fn client(&self) -> &Client {
         self.client
     }
+    /// OAuth 2.0 scopes accepted by [`Self::refund`]; any one of them grants access.
+    pub const REFUND_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::Payments,
+        crate::scope::Scope::RefundsWrite,
+    ];
     /// Refund a transaction
     ///
     /// Refunds an identified transaction either in full or partially.
@@ -985,73 +1300,130 @@ impl<'a> TransactionsClient<'a> {
     /// - 404: The requested transaction does not exist or does not belong to the merchant.
     /// - 409: The transaction cannot be refunded due to business constraints.
     /// - 422: The refund could not be processed by the payment processor.
+    ///
+    /// OAuth 2.0 scopes (any of): `payments`, `refunds.write`
     pub async fn refund(
         &self,
         merchant_code: impl Into<String>,
         transaction_id: impl Into<String>,
         body: Option<RefundRequest>,
     ) -> crate::error::SdkResult<RefundResponse, RefundErrorBody> {
+        self.send_refund(merchant_code, transaction_id, body, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::refund`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn refund_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        transaction_id: impl Into<String>,
+        body: Option<RefundRequest>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<RefundResponse, RefundErrorBody> {
+        self.send_refund(merchant_code, transaction_id, body, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::refund`], additionally returning the metadata of the response.
+    pub async fn refund_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        transaction_id: impl Into<String>,
+        body: Option<RefundRequest>,
+    ) -> crate::error::SdkResult<(RefundResponse, crate::response::ResponseMeta), RefundErrorBody>
+    {
+        self.send_refund(merchant_code, transaction_id, body, &Default::default())
+            .await
+    }
+    /// Same as [`Self::refund_with_response`], sending the request with the given options.
+    pub async fn refund_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        transaction_id: impl Into<String>,
+        body: Option<RefundRequest>,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(RefundResponse, crate::response::ResponseMeta), RefundErrorBody>
+    {
+        self.send_refund(merchant_code, transaction_id, body, &options)
+            .await
+    }
+    async fn send_refund(
+        &self,
+        merchant_code: impl Into<String>,
+        transaction_id: impl Into<String>,
+        body: Option<RefundRequest>,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(RefundResponse, crate::response::ResponseMeta), RefundErrorBody>
+    {
+        self.client.check_scopes(Self::REFUND_SCOPES)?;
+        if let Some(body) = &body {
+            self.client.validate_request(body)?;
+        }
         let path = format!(
             "/v1.0/merchants/{}/payments/{}/refunds",
             merchant_code.into(),
             transaction_id.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .post(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::POST, &url, options);
         if let Some(body) = body {
             request = request.json(&body);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::CREATED => {
-                let data: RefundResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (RefundResponse, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(RefundErrorBody::BadRequest(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    RefundErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::FORBIDDEN => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(RefundErrorBody::Forbidden(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    RefundErrorBody::Forbidden(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(RefundErrorBody::NotFound(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    RefundErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::CONFLICT => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(RefundErrorBody::Conflict(body)))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    RefundErrorBody::Conflict(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
-                let body: Problem = response.json().await?;
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
                 Err(crate::error::SdkError::api(
                     RefundErrorBody::UnprocessableEntity(body),
+                    meta,
                 ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::get`]; any one of them grants access.
+    pub const GET_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::TransactionsHistory,
+        crate::scope::Scope::TransactionsRead,
+    ];
     /// Retrieve a transaction
     ///
     /// Retrieves the full details of an identified transaction. The transaction resource is identified by a query parameter and *one* of following parameters is required:
@@ -1064,25 +1436,60 @@ impl<'a> TransactionsClient<'a> {
     /// - 200: Returns the requested transaction resource.
     /// - 401: The request is not authorized.
     /// - 404: The requested resource does not exist.
+    ///
+    /// OAuth 2.0 scopes (any of): `transactions.history`, `transactions.read`
     pub async fn get(
         &self,
         merchant_code: impl Into<String>,
         params: GetParams,
     ) -> crate::error::SdkResult<TransactionFull, GetErrorBody> {
+        self.send_get(merchant_code, params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn get_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: GetParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<TransactionFull, GetErrorBody> {
+        self.send_get(merchant_code, params, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::get`], additionally returning the metadata of the response.
+    pub async fn get_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        params: GetParams,
+    ) -> crate::error::SdkResult<(TransactionFull, crate::response::ResponseMeta), GetErrorBody>
+    {
+        self.send_get(merchant_code, params, &Default::default())
+            .await
+    }
+    /// Same as [`Self::get_with_response`], sending the request with the given options.
+    pub async fn get_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: GetParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(TransactionFull, crate::response::ResponseMeta), GetErrorBody>
+    {
+        self.send_get(merchant_code, params, &options).await
+    }
+    async fn send_get(
+        &self,
+        merchant_code: impl Into<String>,
+        params: GetParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(TransactionFull, crate::response::ResponseMeta), GetErrorBody>
+    {
+        self.client.check_scopes(Self::GET_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = format!("/v2.1/merchants/{}/transactions", merchant_code.into());
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         if let Some(ref value) = params.id {
             request = request.query(&[("id", value)]);
         }
@@ -1095,30 +1502,40 @@ impl<'a> TransactionsClient<'a> {
         if let Some(ref value) = params.client_transaction_id {
             request = request.query(&[("client_transaction_id", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: TransactionFull = response.json().await?;
-                Ok(data)
+                let (data, meta): (TransactionFull, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::NOT_FOUND => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(GetErrorBody::NotFound(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    GetErrorBody::NotFound(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// OAuth 2.0 scopes accepted by [`Self::list`]; any one of them grants access.
+    pub const LIST_SCOPES: &[crate::scope::Scope] = &[
+        crate::scope::Scope::TransactionsHistory,
+        crate::scope::Scope::TransactionsRead,
+    ];
     /// List transactions
     ///
     /// Lists detailed history of all transactions associated with the merchant profile.
@@ -1127,28 +1544,60 @@ impl<'a> TransactionsClient<'a> {
     /// - 200: Returns a page of transaction history items.
     /// - 400: The request is invalid for the submitted query parameters.
     /// - 401: The request is not authorized.
+    ///
+    /// OAuth 2.0 scopes (any of): `transactions.history`, `transactions.read`
     pub async fn list(
         &self,
         merchant_code: impl Into<String>,
         params: ListParams,
     ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(merchant_code, params, &Default::default())
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], sending the request with the given options, e.g. an idempotency key.
+    pub async fn list_with_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<ListResponse, ListErrorBody> {
+        self.send_list(merchant_code, params, &options)
+            .await
+            .map(|(data, _)| data)
+    }
+    /// Same as [`Self::list`], additionally returning the metadata of the response.
+    pub async fn list_with_response(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(merchant_code, params, &Default::default())
+            .await
+    }
+    /// Same as [`Self::list_with_response`], sending the request with the given options.
+    pub async fn list_with_response_and_options(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        options: crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.send_list(merchant_code, params, &options).await
+    }
+    async fn send_list(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        options: &crate::request_options::RequestOptions,
+    ) -> crate::error::SdkResult<(ListResponse, crate::response::ResponseMeta), ListErrorBody> {
+        self.client.check_scopes(Self::LIST_SCOPES)?;
+        self.client.validate_request(&params)?;
         let path = format!(
             "/v2.1/merchants/{}/transactions/history",
             merchant_code.into()
         );
         let url = format!("{}{}", self.client.base_url(), path);
-        let mut request = self
-            .client
-            .http_client()
-            .get(&url)
-            .header("User-Agent", crate::version::user_agent())
-            .timeout(self.client.timeout());
-        if let Some(authorization) = self.client.authorization() {
-            request = request.header("Authorization", format!("Bearer {}", authorization));
-        }
-        for (header_name, header_value) in self.client.runtime_headers() {
-            request = request.header(*header_name, header_value);
-        }
+        let mut request = self.client.request(reqwest::Method::GET, &url, options);
         if let Some(ref value) = params.transaction_code {
             request = request.query(&[("transaction_code", value)]);
         }
@@ -1158,19 +1607,19 @@ impl<'a> TransactionsClient<'a> {
         if let Some(ref value) = params.limit {
             request = request.query(&[("limit", value)]);
         }
-        if let Some(ref value) = params.users {
+        for value in params.users.as_ref().into_iter().flatten() {
             request = request.query(&[("users[]", value)]);
         }
-        if let Some(ref value) = params.statuses {
+        for value in params.statuses.as_ref().into_iter().flatten() {
             request = request.query(&[("statuses[]", value)]);
         }
-        if let Some(ref value) = params.payment_types {
+        for value in params.payment_types.as_ref().into_iter().flatten() {
             request = request.query(&[("payment_types[]", value)]);
         }
-        if let Some(ref value) = params.entry_modes {
+        for value in params.entry_modes.as_ref().into_iter().flatten() {
             request = request.query(&[("entry_modes[]", value)]);
         }
-        if let Some(ref value) = params.types {
+        for value in params.types.as_ref().into_iter().flatten() {
             request = request.query(&[("types[]", value)]);
         }
         if let Some(ref value) = params.changes_since {
@@ -1188,28 +1637,151 @@ impl<'a> TransactionsClient<'a> {
         if let Some(ref value) = params.oldest_ref {
             request = request.query(&[("oldest_ref", value)]);
         }
-        let response = request.send().await?;
+        let response = self.client.send(request).await?;
         let status = response.status();
         match status {
             reqwest::StatusCode::OK => {
-                let data: ListResponse = response.json().await?;
-                Ok(data)
+                let (data, meta): (ListResponse, _) = crate::error::read_json(response).await?;
+                Ok((data, meta))
             }
             reqwest::StatusCode::BAD_REQUEST => {
-                let body: Error = response.json().await?;
-                Err(crate::error::SdkError::api(ListErrorBody::BadRequest(body)))
+                let (body, meta): (Error, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::BadRequest(body),
+                    meta,
+                ))
             }
             reqwest::StatusCode::UNAUTHORIZED => {
-                let body: Problem = response.json().await?;
-                Err(crate::error::SdkError::api(ListErrorBody::Unauthorized(
-                    body,
-                )))
+                let (body, meta): (Problem, _) = crate::error::read_json(response).await?;
+                Err(crate::error::SdkError::api(
+                    ListErrorBody::Unauthorized(body),
+                    meta,
+                ))
             }
             _ => {
+                let meta = crate::response::ResponseMeta::from_response(&response);
                 let body_bytes = response.bytes().await?;
                 let body = crate::error::UnknownApiBody::from_bytes(body_bytes.as_ref());
-                Err(crate::error::SdkError::unexpected(status, body))
+                Err(crate::error::SdkError::unexpected(body, meta))
             }
         }
     }
+    /// Streams the items of [`Self::list`] across all pages, following the `next` links.
+    pub fn list_all(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+    ) -> crate::pagination::Paginator<'a, TransactionHistory, ListErrorBody> {
+        let client = self.clone();
+        let merchant_code: String = merchant_code.into();
+        crate::pagination::Paginator::new(None, move |cursor: Option<String>| {
+            let client = client.clone();
+            let merchant_code = merchant_code.clone();
+            let params = match &cursor {
+                Some(cursor) => crate::pagination::params_from_cursor(
+                    cursor,
+                    ListParams {
+                        newest_time: None,
+                        newest_ref: None,
+                        oldest_time: None,
+                        oldest_ref: None,
+                        ..params.clone()
+                    },
+                    |params: &mut ListParams, key| match key {
+                        "users[]" => params.users = None,
+                        "statuses[]" => params.statuses = None,
+                        "payment_types[]" => params.payment_types = None,
+                        "entry_modes[]" => params.entry_modes = None,
+                        "types[]" => params.types = None,
+                        _ => {}
+                    },
+                    |params: &mut ListParams, key, value| {
+                        match key {
+                            "transaction_code" => {
+                                params.transaction_code =
+                                    Some(crate::pagination::parse_query_value(value)?);
+                            }
+                            "order" => {
+                                params.order = Some(crate::pagination::parse_query_value(value)?);
+                            }
+                            "limit" => {
+                                params.limit = Some(crate::pagination::parse_query_value(value)?);
+                            }
+                            "users[]" => params
+                                .users
+                                .get_or_insert_with(Vec::new)
+                                .push(crate::pagination::parse_query_value(value)?),
+                            "statuses[]" => params
+                                .statuses
+                                .get_or_insert_with(Vec::new)
+                                .push(crate::pagination::parse_query_value(value)?),
+                            "payment_types[]" => params
+                                .payment_types
+                                .get_or_insert_with(Vec::new)
+                                .push(crate::pagination::parse_query_value(value)?),
+                            "entry_modes[]" => params
+                                .entry_modes
+                                .get_or_insert_with(Vec::new)
+                                .push(crate::pagination::parse_query_value(value)?),
+                            "types[]" => params
+                                .types
+                                .get_or_insert_with(Vec::new)
+                                .push(crate::pagination::parse_query_value(value)?),
+                            "changes_since" => {
+                                params.changes_since =
+                                    Some(crate::pagination::parse_query_value(value)?);
+                            }
+                            "newest_time" => {
+                                params.newest_time =
+                                    Some(crate::pagination::parse_query_value(value)?);
+                            }
+                            "newest_ref" => {
+                                params.newest_ref =
+                                    Some(crate::pagination::parse_query_value(value)?);
+                            }
+                            "oldest_time" => {
+                                params.oldest_time =
+                                    Some(crate::pagination::parse_query_value(value)?);
+                            }
+                            "oldest_ref" => {
+                                params.oldest_ref =
+                                    Some(crate::pagination::parse_query_value(value)?);
+                            }
+                            _ => {}
+                        }
+                        Some(())
+                    },
+                ),
+                None => Ok(params.clone()),
+            };
+            async move {
+                let params = params?;
+                let page = client.list(merchant_code, params).await?;
+                let next = page
+                    .links
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|link| link.rel == "next")
+                    .map(|link| link.href);
+                Ok(crate::pagination::Page {
+                    items: page.items.unwrap_or_default(),
+                    next,
+                })
+            }
+        })
+    }
+    /// Streams the items of [`Self::list`] starting at a saved cursor.
+    ///
+    /// The cursor is the `href` of a `next` link, as returned by
+    /// [`Paginator::cursor`](crate::pagination::Paginator::cursor). Its query
+    /// parameters replace those of `params`, whose other filters still apply.
+    pub fn list_all_from(
+        &self,
+        merchant_code: impl Into<String>,
+        params: ListParams,
+        cursor: impl Into<String>,
+    ) -> crate::pagination::Paginator<'a, TransactionHistory, ListErrorBody> {
+        self.list_all(merchant_code, params)
+            .with_cursor(cursor.into())
+    }
 }
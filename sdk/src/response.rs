@@ -0,0 +1,131 @@
+//! Metadata of HTTP responses returned by the SumUp API.
+//!
+//! A [`ResponseMeta`] is attached to every [`SdkError`](crate::SdkError) built from a
+//! response, so failures can be correlated with SumUp support tickets and your own traces:
+//!
+//! ```no_run
+//! # async fn run(client: sumup::Client) {
+//! if let Err(err) = client.checkouts().get("4e425463-3e1b-431d-83fa-1e51c2925e99").await {
+//!     if let Some(response) = err.response() {
+//!         eprintln!(
+//!             "{} {} failed with {} after {:?} (request ID: {:?})",
+//!             response.method(),
+//!             response.path(),
+//!             response.status(),
+//!             response.elapsed(),
+//!             response.request_id(),
+//!         );
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::time::Duration;
+
+/// Name of the header carrying the ID the SumUp API assigned to the request.
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Status, headers and timing of an HTTP response.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    status: reqwest::StatusCode,
+    headers: reqwest::header::HeaderMap,
+    method: reqwest::Method,
+    path: String,
+    elapsed: Duration,
+    body: Option<bytes::Bytes>,
+}
+
+impl ResponseMeta {
+    /// Creates metadata for a response without headers, e.g. to build errors in tests.
+    pub fn new(
+        method: reqwest::Method,
+        path: impl Into<String>,
+        status: reqwest::StatusCode,
+    ) -> Self {
+        Self {
+            status,
+            headers: reqwest::header::HeaderMap::new(),
+            method,
+            path: path.into(),
+            elapsed: Duration::ZERO,
+            body: None,
+        }
+    }
+
+    /// Sets the response headers.
+    pub fn with_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Sets the time it took to receive the response.
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = elapsed;
+        self
+    }
+
+    /// Keeps the raw response body.
+    pub(crate) fn with_body(mut self, body: bytes::Bytes) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Captures the metadata of a response returned by [`Client::send`](crate::Client).
+    pub(crate) fn from_response(response: &reqwest::Response) -> Self {
+        let (method, elapsed) = match response.extensions().get::<RequestInfo>() {
+            Some(info) => (info.method.clone(), info.elapsed),
+            None => (reqwest::Method::GET, Duration::ZERO),
+        };
+        Self::new(method, response.url().path(), response.status())
+            .with_headers(response.headers().clone())
+            .with_elapsed(elapsed)
+    }
+
+    /// Returns the HTTP status of the response.
+    pub fn status(&self) -> reqwest::StatusCode {
+        self.status
+    }
+
+    /// Returns the response headers.
+    pub fn headers(&self) -> &reqwest::header::HeaderMap {
+        &self.headers
+    }
+
+    /// Returns the value of the [`REQUEST_ID_HEADER`], if present.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+    }
+
+    /// Returns the HTTP method of the request.
+    pub fn method(&self) -> &reqwest::Method {
+        &self.method
+    }
+
+    /// Returns the path of the request URL, e.g. `/v0.1/checkouts`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the time until the response headers were received, including retries.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the raw response body when it was read as JSON.
+    ///
+    /// The body is shared with the buffer it was deserialized from, so keeping it
+    /// does not copy the payload.
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body.as_deref()
+    }
+}
+
+/// Request details recorded in the response extensions by the client.
+#[derive(Debug, Clone)]
+pub(crate) struct RequestInfo {
+    pub(crate) method: reqwest::Method,
+    pub(crate) elapsed: Duration,
+}
@@ -0,0 +1,293 @@
+//! Automatic retries for transient API failures.
+//!
+//! Configure a [`RetryPolicy`] with [`Client::with_retry_policy`](crate::Client::with_retry_policy)
+//! to retry rate-limited responses (`429`), gateway failures (`502`, `503`, `504`) and
+//! connection errors with jittered exponential backoff. `Retry-After` headers sent by the
+//! server take precedence over the computed backoff.
+//!
+//! Only idempotent requests (`GET`, `HEAD`, `OPTIONS`, `PUT`, `DELETE`) are retried by
+//! default. `POST` and `PATCH` requests are retried only for operations that the API
+//! deduplicates by a required field of the request body, so that a retry can never
+//! duplicate a charge or a refund:
+//!
+//! - [`CheckoutsClient::create`](crate::checkouts::CheckoutsClient::create): a checkout
+//!   with the same `checkout_reference` is rejected with `409 Conflict`.
+//! - [`ReadersClient::create_go_checkout`](crate::readers::ReadersClient::create_go_checkout):
+//!   a payment with the same `client_transaction_id` returns the original result.
+//!
+//! An `Idempotency-Key` header (see [`RequestOptions`](crate::RequestOptions)) does not
+//! make a request retryable, as the API specification does not document it.
+
+use std::future::Future;
+use std::time::{Duration, SystemTime};
+
+/// Policy describing when and how often failed requests are retried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that makes at most `max_attempts` attempts per request,
+    /// including the first one.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a policy that never retries.
+    pub fn disabled() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the backoff applied before the first retry. Subsequent retries double it.
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound for a single backoff.
+    ///
+    /// `Retry-After` values above this bound are not waited for; the response is
+    /// returned to the caller instead.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Enables or disables randomization of the computed backoff.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns the maximum number of attempts, including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the backoff applied before the first retry.
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Returns the upper bound for a single backoff.
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Returns whether the computed backoff is randomized.
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    /// Returns the delay before retrying after the given (1-based) failed attempt.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = exponential_backoff(self.initial_backoff, attempt, self.max_backoff);
+
+        if self.jitter {
+            // Equal jitter: keep half of the backoff and randomize the other half.
+            let half = backoff / 2;
+            half + half.mul_f64(random_fraction())
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts with a 200ms initial backoff, capped at 10 seconds, with jitter.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+}
+
+/// Sends the request with `execute`, retrying transient failures according to `policy`.
+///
+/// `deduplicated` marks a request that the API deduplicates by a field of its body, which
+/// makes retrying it safe whatever its method.
+pub(crate) async fn send<F, Fut>(
+    request: reqwest::Request,
+    policy: Option<&RetryPolicy>,
+    deduplicated: bool,
+    execute: F,
+) -> Result<reqwest::Response, reqwest::Error>
+where
+    F: Fn(reqwest::Request) -> Fut,
+    Fut: Future<Output = Result<reqwest::Response, reqwest::Error>>,
+{
+    let policy = match policy {
+        Some(policy)
+            if policy.max_attempts > 1 && (deduplicated || is_idempotent(request.method())) =>
+        {
+            policy
+        }
+        _ => return execute(request).await,
+    };
+
+    let mut attempt = 1;
+    loop {
+        if attempt == policy.max_attempts {
+            return execute(request).await;
+        }
+        // Requests with streaming bodies cannot be replayed.
+        let Some(attempt_request) = request.try_clone() else {
+            return execute(request).await;
+        };
+
+        let delay = match execute(attempt_request).await {
+            Ok(response) if is_retryable_status(response.status()) => {
+                match retry_after(response.headers()) {
+                    Some(delay) if delay > policy.max_backoff => return Ok(response),
+                    Some(delay) => delay,
+                    None => policy.backoff(attempt),
+                }
+            }
+            Err(error) if is_retryable_error(&error) => policy.backoff(attempt),
+            result => return result,
+        };
+
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// Reports whether requests with this method may be sent more than once without side
+/// effects.
+fn is_idempotent(method: &reqwest::Method) -> bool {
+    matches!(
+        *method,
+        reqwest::Method::GET
+            | reqwest::Method::HEAD
+            | reqwest::Method::OPTIONS
+            | reqwest::Method::PUT
+            | reqwest::Method::DELETE
+    )
+}
+
+pub(crate) fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    matches!(
+        status,
+        reqwest::StatusCode::TOO_MANY_REQUESTS
+            | reqwest::StatusCode::BAD_GATEWAY
+            | reqwest::StatusCode::SERVICE_UNAVAILABLE
+            | reqwest::StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+pub(crate) fn is_retryable_error(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout() || error.is_request()
+}
+
+/// Parses a `Retry-After` header given either in seconds or as an HTTP date.
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Doubles `initial` after every step but the first (1-based), capped at `max`.
+pub(crate) fn exponential_backoff(initial: Duration, step: u32, max: Duration) -> Duration {
+    let exponent = step.saturating_sub(1).min(31);
+    initial.saturating_mul(1u32 << exponent).min(max)
+}
+
+/// Returns a pseudo-random number in `[0, 1)`; good enough to spread retries apart.
+fn random_fraction() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_the_cap() {
+        let policy = RetryPolicy::new(10)
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(500))
+            .with_jitter(false);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(40), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn jittered_backoff_stays_within_bounds() {
+        let policy = RetryPolicy::new(3).with_initial_backoff(Duration::from_millis(100));
+
+        for _ in 0..100 {
+            let backoff = policy.backoff(2);
+            assert!(backoff >= Duration::from_millis(100));
+            assert!(backoff <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn new_requires_at_least_one_attempt() {
+        assert_eq!(RetryPolicy::new(0).max_attempts(), 1);
+        assert_eq!(RetryPolicy::disabled().max_attempts(), 1);
+    }
+
+    #[test]
+    fn only_idempotent_methods_are_retryable() {
+        assert!(is_idempotent(&reqwest::Method::GET));
+        assert!(is_idempotent(&reqwest::Method::PUT));
+        assert!(is_idempotent(&reqwest::Method::DELETE));
+        assert!(!is_idempotent(&reqwest::Method::POST));
+        assert!(!is_idempotent(&reqwest::Method::PATCH));
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds_and_http_dates() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            reqwest::header::HeaderValue::from_static("3"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            reqwest::header::HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            reqwest::header::HeaderValue::from_static("soon"),
+        );
+        assert_eq!(retry_after(&headers), None);
+    }
+}
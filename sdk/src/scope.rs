@@ -0,0 +1,134 @@
+// The contents of this file are generated; do not modify them.
+
+//! OAuth 2.0 scopes of the SumUp API.
+/// OAuth 2.0 scopes supported by the SumUp API.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Scope {
+    /// Make payments by creating and processing checkouts.
+    #[serde(rename = "payments")]
+    Payments,
+    /// View checkouts.
+    #[serde(rename = "checkouts.read")]
+    CheckoutsRead,
+    /// Create, process, and deactivate checkouts.
+    #[serde(rename = "checkouts.write")]
+    CheckoutsWrite,
+    /// View transactions and transaction history.
+    #[serde(rename = "transactions.history")]
+    TransactionsHistory,
+    /// View transactions and transaction history.
+    #[serde(rename = "transactions.read")]
+    TransactionsRead,
+    /// Refund transactions.
+    #[serde(rename = "refunds.write")]
+    RefundsWrite,
+    /// View receipts.
+    #[serde(rename = "receipts.read")]
+    ReceiptsRead,
+    /// View user profile details.
+    #[serde(rename = "user.profile_readonly")]
+    UserProfileReadonly,
+    /// View and manage your user profile.
+    #[serde(rename = "user.profile")]
+    UserProfile,
+    /// View and manage the SumUp mobile application settings.
+    #[serde(rename = "user.app-settings")]
+    UserAppSettings,
+    /// Manage customers and their payment instruments.
+    #[serde(rename = "payment_instruments")]
+    PaymentInstruments,
+    /// View customers and their payment instruments.
+    #[serde(rename = "customers.read")]
+    CustomersRead,
+    /// Create and manage customers and their payment instruments.
+    #[serde(rename = "customers.write")]
+    CustomersWrite,
+    /// View and manage your payout settings.
+    #[serde(rename = "user.payout-settings")]
+    UserPayoutSettings,
+    /// View payouts.
+    #[serde(rename = "payouts.read")]
+    PayoutsRead,
+    /// View and manage the user profile details of your employees.
+    #[serde(rename = "user.subaccounts")]
+    UserSubaccounts,
+    /// The `readers.write` scope.
+    #[serde(rename = "readers.write")]
+    ReadersWrite,
+    /// The `members.read` scope.
+    #[serde(rename = "members.read")]
+    MembersRead,
+    /// The `members.write` scope.
+    #[serde(rename = "members.write")]
+    MembersWrite,
+    /// The `roles.read` scope.
+    #[serde(rename = "roles.read")]
+    RolesRead,
+    /// The `roles.write` scope.
+    #[serde(rename = "roles.write")]
+    RolesWrite,
+    /// The `readers.read` scope.
+    #[serde(rename = "readers.read")]
+    ReadersRead,
+    /// The `terminals.read` scope.
+    #[serde(rename = "terminals.read")]
+    TerminalsRead,
+    /// The `terminals.write` scope.
+    #[serde(rename = "terminals.write")]
+    TerminalsWrite,
+    /// A scope unknown to this version of the SDK.
+    #[serde(untagged)]
+    Other(String),
+}
+impl Scope {
+    /// Returns the scope as sent to the authorization server.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Payments => "payments",
+            Self::CheckoutsRead => "checkouts.read",
+            Self::CheckoutsWrite => "checkouts.write",
+            Self::TransactionsHistory => "transactions.history",
+            Self::TransactionsRead => "transactions.read",
+            Self::RefundsWrite => "refunds.write",
+            Self::ReceiptsRead => "receipts.read",
+            Self::UserProfileReadonly => "user.profile_readonly",
+            Self::UserProfile => "user.profile",
+            Self::UserAppSettings => "user.app-settings",
+            Self::PaymentInstruments => "payment_instruments",
+            Self::CustomersRead => "customers.read",
+            Self::CustomersWrite => "customers.write",
+            Self::UserPayoutSettings => "user.payout-settings",
+            Self::PayoutsRead => "payouts.read",
+            Self::UserSubaccounts => "user.subaccounts",
+            Self::ReadersWrite => "readers.write",
+            Self::MembersRead => "members.read",
+            Self::MembersWrite => "members.write",
+            Self::RolesRead => "roles.read",
+            Self::RolesWrite => "roles.write",
+            Self::ReadersRead => "readers.read",
+            Self::TerminalsRead => "terminals.read",
+            Self::TerminalsWrite => "terminals.write",
+            Self::Other(scope) => scope,
+        }
+    }
+    /// Parses a space-separated scope list as returned by the token endpoint.
+    pub fn parse_list(scopes: &str) -> Vec<Self> {
+        scopes.split_whitespace().map(Self::from).collect()
+    }
+}
+impl From<&str> for Scope {
+    fn from(value: &str) -> Self {
+        serde_json::from_value(serde_json::Value::String(value.to_string()))
+            .unwrap_or_else(|_| Self::Other(value.to_string()))
+    }
+}
+impl From<Scope> for String {
+    fn from(value: Scope) -> Self {
+        value.as_str().to_string()
+    }
+}
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -1,4 +1,7 @@
-/// Wrapper for password values that keeps the inner string private.
+/// Wrapper for passwords, API keys and tokens that keeps the inner string private.
+///
+/// The [`Debug`](std::fmt::Debug) output masks the value, so secrets do not end up in logs.
+/// With the `zeroize` feature enabled, the memory holding the value is wiped on drop.
 #[derive(Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 #[serde(transparent)]
 pub struct Secret {
@@ -19,8 +22,8 @@ impl Secret {
     }
 
     /// Consumes the wrapper and returns the secret string.
-    pub fn into_secret(self) -> String {
-        self.secret
+    pub fn into_secret(mut self) -> String {
+        std::mem::take(&mut self.secret)
     }
 }
 
@@ -42,6 +45,13 @@ impl std::fmt::Debug for Secret {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl Drop for Secret {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.secret);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,407 @@
+//! Client-side validation of requests against the constraints of the API specification.
+//!
+//! Request bodies and query parameters implement [`Validate`], as do the types nested in
+//! them. It checks the length, pattern, range and allowed values documented on their
+//! fields and reports all violations with the path of the offending field:
+//!
+//! ```
+//! use sumup::checkouts::CreateRequest;
+//! use sumup::validation::Validate;
+//! use sumup::{Amount, Currency};
+//!
+//! let request = CreateRequest::builder("r".repeat(91), Amount::from(10), Currency::EUR, "MH4H92C7")
+//!     .build();
+//! let error = request.validate().expect_err("reference is too long");
+//! assert_eq!(error.violations()[0].path(), "checkout_reference");
+//! ```
+//!
+//! [`Client::with_request_validation`](crate::Client::with_request_validation) runs the
+//! validation before every request, failing with [`SdkError::Validation`](crate::SdkError::Validation)
+//! instead of sending a request the API would reject.
+//!
+//! Required fields are taken by the builders of request bodies and query parameters, so
+//! only required query parameters left empty are reported. Enum values in the `Other`
+//! variant are reported as well, including values the API accepts but this SDK version
+//! does not know yet; leave validation disabled to send those.
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// A value whose constraints can be checked before it is sent to the API.
+pub trait Validate {
+    /// Checks the value, returning every violated constraint.
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut violations = Vec::new();
+        self.validate_at("", &mut violations);
+        ValidationError::check(violations)
+    }
+
+    /// Appends the violations of the value, located at `path`, to `violations`.
+    fn validate_at(&self, path: &str, violations: &mut Vec<Violation>) {
+        let _ = (path, violations);
+    }
+}
+
+/// A constraint of the API specification that a value violates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    path: String,
+    kind: ViolationKind,
+}
+
+impl Violation {
+    /// Path of the offending field, e.g. `personal_details.address.country` or
+    /// `products[0].name`, using the field names of the API.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The violated constraint.
+    pub fn kind(&self) -> &ViolationKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "value"
+        } else {
+            &self.path
+        };
+        write!(f, "{path} {}", self.kind)
+    }
+}
+
+/// The constraints checked by [`Validate`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ViolationKind {
+    /// A required query parameter is empty.
+    Required,
+    /// A string is shorter than allowed, counted in characters.
+    MinLength { min: usize, actual: usize },
+    /// A string is longer than allowed, counted in characters.
+    MaxLength { max: usize, actual: usize },
+    /// A string does not match the pattern.
+    Pattern { pattern: &'static str },
+    /// A number is below the minimum, or equal to it when `exclusive`.
+    Minimum { min: f64, exclusive: bool },
+    /// A number is above the maximum, or equal to it when `exclusive`.
+    Maximum { max: f64, exclusive: bool },
+    /// A list has fewer items than allowed.
+    MinItems { min: usize, actual: usize },
+    /// A list has more items than allowed.
+    MaxItems { max: usize, actual: usize },
+    /// A value is not one of the allowed values.
+    Enum { value: String },
+    /// A value cannot be parsed into the type of the field.
+    Invalid { value: String },
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Required => write!(f, "is required"),
+            Self::MinLength { min, actual } => {
+                write!(f, "must be at least {min} characters long, got {actual}")
+            }
+            Self::MaxLength { max, actual } => {
+                write!(f, "must be at most {max} characters long, got {actual}")
+            }
+            Self::Pattern { pattern } => write!(f, "must match `{pattern}`"),
+            Self::Minimum {
+                min,
+                exclusive: false,
+            } => write!(f, "must be at least {min}"),
+            Self::Minimum {
+                min,
+                exclusive: true,
+            } => write!(f, "must be greater than {min}"),
+            Self::Maximum {
+                max,
+                exclusive: false,
+            } => write!(f, "must be at most {max}"),
+            Self::Maximum {
+                max,
+                exclusive: true,
+            } => write!(f, "must be less than {max}"),
+            Self::MinItems { min, actual } => {
+                write!(f, "must have at least {min} items, got {actual}")
+            }
+            Self::MaxItems { max, actual } => {
+                write!(f, "must have at most {max} items, got {actual}")
+            }
+            Self::Enum { value } => write!(f, "has unknown value `{value}`"),
+            Self::Invalid { value } => write!(f, "has invalid value `{value}`"),
+        }
+    }
+}
+
+/// Error returned when a value violates constraints of the API specification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    violations: Vec<Violation>,
+}
+
+impl ValidationError {
+    /// Returns the violated constraints; never empty.
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    /// Fails with the collected violations unless there are none.
+    pub(crate) fn check(violations: Vec<Violation>) -> Result<(), Self> {
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Self { violations })
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid request: ")?;
+        for (index, violation) in self.violations.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{violation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+macro_rules! impl_validate_for_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(impl Validate for $ty {})*
+    };
+}
+
+impl_validate_for_leaf!(
+    String,
+    bool,
+    u8,
+    i32,
+    i64,
+    f32,
+    f64,
+    serde_json::Value,
+    crate::money::Amount,
+    crate::secret::Secret,
+    crate::datetime::DateTime,
+    crate::datetime::Date,
+    crate::datetime::Time,
+);
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_at(&self, path: &str, violations: &mut Vec<Violation>) {
+        if let Some(value) = self {
+            value.validate_at(path, violations);
+        }
+    }
+}
+
+impl<T: Validate> Validate for crate::Nullable<T> {
+    fn validate_at(&self, path: &str, violations: &mut Vec<Violation>) {
+        if let crate::Nullable::Value(value) = self {
+            value.validate_at(path, violations);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_at(&self, path: &str, violations: &mut Vec<Violation>) {
+        for (index, item) in self.iter().enumerate() {
+            item.validate_at(&item_path(path, index), violations);
+        }
+    }
+}
+
+impl<T: Validate> Validate for HashMap<String, T> {
+    fn validate_at(&self, path: &str, violations: &mut Vec<Violation>) {
+        for (key, value) in self {
+            value.validate_at(&field_path(path, key), violations);
+        }
+    }
+}
+
+/// Strings whose length and pattern can be checked.
+pub(crate) trait Text {
+    fn text(&self) -> &str;
+}
+
+impl Text for String {
+    fn text(&self) -> &str {
+        self
+    }
+}
+
+impl Text for crate::secret::Secret {
+    fn text(&self) -> &str {
+        self.secret()
+    }
+}
+
+/// Numbers whose range can be checked.
+pub(crate) trait Number {
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_number {
+    ($($ty:ty),* $(,)?) => {
+        $(impl Number for $ty {
+            fn to_f64(&self) -> f64 {
+                *self as f64
+            }
+        })*
+    };
+}
+
+impl_number!(i32, i64, f32, f64);
+
+impl Number for crate::money::Amount {
+    fn to_f64(&self) -> f64 {
+        crate::money::Amount::to_f64(self)
+    }
+}
+
+pub(crate) fn field_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+pub(crate) fn item_path(path: &str, index: usize) -> String {
+    format!("{path}[{index}]")
+}
+
+pub(crate) fn violation(path: &str, kind: ViolationKind, violations: &mut Vec<Violation>) {
+    violations.push(Violation {
+        path: path.to_string(),
+        kind,
+    });
+}
+
+pub(crate) fn check_required(value: &impl Text, path: &str, violations: &mut Vec<Violation>) {
+    if value.text().is_empty() {
+        violation(path, ViolationKind::Required, violations);
+    }
+}
+
+pub(crate) fn check_length(
+    value: &impl Text,
+    min: Option<usize>,
+    max: Option<usize>,
+    path: &str,
+    violations: &mut Vec<Violation>,
+) {
+    let actual = value.text().chars().count();
+    if let Some(min) = min.filter(|min| actual < *min) {
+        violation(path, ViolationKind::MinLength { min, actual }, violations);
+    }
+    if let Some(max) = max.filter(|max| actual > *max) {
+        violation(path, ViolationKind::MaxLength { max, actual }, violations);
+    }
+}
+
+pub(crate) fn check_range(
+    value: &impl Number,
+    min: Option<(f64, bool)>,
+    max: Option<(f64, bool)>,
+    path: &str,
+    violations: &mut Vec<Violation>,
+) {
+    let value = value.to_f64();
+    if let Some((min, exclusive)) = min {
+        if value < min || (exclusive && value == min) {
+            violation(path, ViolationKind::Minimum { min, exclusive }, violations);
+        }
+    }
+    if let Some((max, exclusive)) = max {
+        if value > max || (exclusive && value == max) {
+            violation(path, ViolationKind::Maximum { max, exclusive }, violations);
+        }
+    }
+}
+
+pub(crate) fn check_items(
+    actual: usize,
+    min: Option<usize>,
+    max: Option<usize>,
+    path: &str,
+    violations: &mut Vec<Violation>,
+) {
+    if let Some(min) = min.filter(|min| actual < *min) {
+        violation(path, ViolationKind::MinItems { min, actual }, violations);
+    }
+    if let Some(max) = max.filter(|max| actual > *max) {
+        violation(path, ViolationKind::MaxItems { max, actual }, violations);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_report_paths_and_bounds() {
+        let mut violations = Vec::new();
+        check_length(
+            &"abcd".to_string(),
+            Some(2),
+            Some(3),
+            "name",
+            &mut violations,
+        );
+        check_range(
+            &0_i64,
+            Some((1.0, false)),
+            Some((10.0, false)),
+            "limit",
+            &mut violations,
+        );
+        check_range(&10.0_f64, None, Some((10.0, true)), "rate", &mut violations);
+        violation(
+            "country",
+            ViolationKind::Pattern {
+                pattern: "^[A-Z]{2}$",
+            },
+            &mut violations,
+        );
+        check_items(2, Some(3), None, &item_path("lines", 0), &mut violations);
+
+        let messages: Vec<String> = violations.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "name must be at most 3 characters long, got 4",
+                "limit must be at least 1",
+                "rate must be less than 10",
+                "country must match `^[A-Z]{2}$`",
+                "lines[0] must have at least 3 items, got 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn containers_extend_the_path() {
+        struct Code(&'static str);
+        impl Validate for Code {
+            fn validate_at(&self, path: &str, violations: &mut Vec<Violation>) {
+                check_required(&self.0.to_string(), path, violations);
+            }
+        }
+
+        let codes = vec![Some(Code("a")), None, Some(Code(""))];
+        let error = codes.validate().expect_err("empty code");
+        assert_eq!(error.violations().len(), 1);
+        assert_eq!(error.violations()[0].path(), "[2]");
+        assert_eq!(error.to_string(), "invalid request: [2] is required");
+    }
+}
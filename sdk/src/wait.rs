@@ -0,0 +1,240 @@
+//! Waiting for asynchronous operations to reach a terminal state.
+//!
+//! Payments complete asynchronously: a checkout stays `PENDING` until the payer finishes
+//! the payment. [`CheckoutsClient::wait_for_completion`] polls the checkout with growing
+//! intervals until it is paid, failed or expired:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use sumup::wait::{CheckoutOutcome, WaitOptions};
+//!
+//! # async fn run(client: sumup::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let completion = client
+//!     .checkouts()
+//!     .wait_for_completion(
+//!         "4e425463-3e1b-431d-83fa-1e51c2925e99",
+//!         WaitOptions::default().with_timeout(Duration::from_secs(300)),
+//!     )
+//!     .await?;
+//! match completion.outcome {
+//!     CheckoutOutcome::Paid => println!("paid with {:?}", completion.transaction_code),
+//!     outcome => println!("checkout not paid: {outcome:?}"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, SystemTime};
+
+use crate::error::SdkResult;
+use crate::nullable::Nullable;
+use crate::resources::checkouts::{
+    CheckoutSuccess, CheckoutSuccessStatus, CheckoutsClient, GetErrorBody,
+};
+
+/// Polling schedule used while waiting for an operation to complete.
+///
+/// The interval between polls starts at the initial interval and doubles after every
+/// poll, up to the maximum interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitOptions {
+    initial_interval: Duration,
+    max_interval: Duration,
+    timeout: Option<Duration>,
+}
+
+impl WaitOptions {
+    /// Creates the default schedule, see [`WaitOptions::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the interval before the second poll.
+    pub fn with_initial_interval(mut self, initial_interval: Duration) -> Self {
+        self.initial_interval = initial_interval;
+        self
+    }
+
+    /// Sets the upper bound for the interval between two polls.
+    pub fn with_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Sets how long to wait in total before giving up.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Waits until the operation completes, however long it takes.
+    pub fn without_timeout(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    /// Returns the interval before the second poll.
+    pub fn initial_interval(&self) -> Duration {
+        self.initial_interval
+    }
+
+    /// Returns the upper bound for the interval between two polls.
+    pub fn max_interval(&self) -> Duration {
+        self.max_interval
+    }
+
+    /// Returns how long to wait in total, if limited.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns the delay after the given (1-based) poll.
+    pub(crate) fn interval(&self, poll: u32) -> Duration {
+        crate::retry::exponential_backoff(self.initial_interval, poll, self.max_interval)
+    }
+}
+
+impl Default for WaitOptions {
+    /// Polls after 1 second, then with doubling intervals of at most 10 seconds, for up
+    /// to 10 minutes.
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(10),
+            timeout: Some(Duration::from_secs(600)),
+        }
+    }
+}
+
+/// Tracks the time left until the wait times out.
+pub(crate) struct Deadline {
+    timeout_at: Option<tokio::time::Instant>,
+}
+
+impl Deadline {
+    /// Starts a deadline that expires after `timeout`, or never.
+    pub(crate) fn new(timeout: Option<Duration>) -> Self {
+        Self {
+            timeout_at: timeout
+                .and_then(|timeout| tokio::time::Instant::now().checked_add(timeout)),
+        }
+    }
+
+    /// Returns `interval` shortened to the time left, or `None` once the wait has timed out.
+    pub(crate) fn next_sleep(&self, interval: Duration) -> Option<Duration> {
+        let remaining = match self.timeout_at {
+            Some(timeout_at) => timeout_at.saturating_duration_since(tokio::time::Instant::now()),
+            None => interval,
+        };
+        (!remaining.is_zero()).then(|| interval.min(remaining))
+    }
+
+    /// Sleeps for [`Self::next_sleep`]. Returns `false` without sleeping once the wait has
+    /// timed out.
+    pub(crate) async fn sleep(&self, interval: Duration) -> bool {
+        match self.next_sleep(interval) {
+            Some(duration) => {
+                tokio::time::sleep(duration).await;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// How a checkout ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckoutOutcome {
+    /// A payment succeeded.
+    Paid,
+    /// The latest processing attempt failed.
+    Failed,
+    /// The checkout expired, either as reported by the API or because its `valid_until`
+    /// passed while it was still pending.
+    Expired,
+    /// The checkout was still pending when [`WaitOptions::timeout`] elapsed.
+    TimedOut,
+}
+
+/// Result of [`CheckoutsClient::wait_for_completion`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckoutCompletion {
+    /// How the checkout ended.
+    pub outcome: CheckoutOutcome,
+    /// Code of the transaction that completed the checkout, if any.
+    pub transaction_code: Option<String>,
+    /// ID of the transaction that completed the checkout, if any.
+    pub transaction_id: Option<String>,
+    /// The checkout as last returned by the API.
+    pub checkout: CheckoutSuccess,
+}
+
+impl CheckoutCompletion {
+    fn new(outcome: CheckoutOutcome, checkout: CheckoutSuccess) -> Self {
+        Self {
+            outcome,
+            transaction_code: checkout.transaction_code.clone(),
+            transaction_id: checkout.transaction_id.clone(),
+            checkout,
+        }
+    }
+}
+
+impl CheckoutsClient<'_> {
+    /// Polls [`Self::get`] until the checkout is paid, failed or expired.
+    ///
+    /// A pending checkout whose `valid_until` has passed is polled one last time and then
+    /// reported as [`CheckoutOutcome::Expired`]. Errors returned by the API end the wait.
+    pub async fn wait_for_completion(
+        &self,
+        checkout_id: impl Into<String>,
+        options: WaitOptions,
+    ) -> SdkResult<CheckoutCompletion, GetErrorBody> {
+        let checkout_id = checkout_id.into();
+        let deadline = Deadline::new(options.timeout);
+        let mut poll = 0;
+        loop {
+            poll += 1;
+            let checkout = self.get(checkout_id.as_str()).await?;
+            let outcome = match checkout.status {
+                Some(CheckoutSuccessStatus::Paid) => Some(CheckoutOutcome::Paid),
+                Some(CheckoutSuccessStatus::Failed) => Some(CheckoutOutcome::Failed),
+                Some(CheckoutSuccessStatus::Expired) => Some(CheckoutOutcome::Expired),
+                _ => None,
+            };
+            if let Some(outcome) = outcome {
+                return Ok(CheckoutCompletion::new(outcome, checkout));
+            }
+
+            let mut interval = options.interval(poll);
+            if let Some(Nullable::Value(valid_until)) = &checkout.valid_until {
+                let valid_until = crate::datetime::to_system_time(valid_until);
+                match valid_until.duration_since(SystemTime::now()) {
+                    // Wake up right at the expiry for a final poll.
+                    Ok(expires_in) if !expires_in.is_zero() => interval = interval.min(expires_in),
+                    _ => return Ok(CheckoutCompletion::new(CheckoutOutcome::Expired, checkout)),
+                }
+            }
+            if !deadline.sleep(interval).await {
+                return Ok(CheckoutCompletion::new(CheckoutOutcome::TimedOut, checkout));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interval_doubles_up_to_the_maximum() {
+        let options = WaitOptions::new()
+            .with_initial_interval(Duration::from_millis(500))
+            .with_max_interval(Duration::from_secs(3));
+        let intervals: Vec<_> = (1..=5).map(|poll| options.interval(poll)).collect();
+        assert_eq!(
+            intervals,
+            [500, 1000, 2000, 3000, 3000].map(Duration::from_millis)
+        );
+    }
+}
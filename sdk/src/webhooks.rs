@@ -0,0 +1,374 @@
+//! Receiving the callbacks SumUp sends to your server.
+//!
+//! When a reader checkout is created with a `return_url`, SumUp POSTs a
+//! [`ReaderCheckoutStatusChange`] to that URL once the transaction completes, and retries
+//! the delivery until the server responds with `200 OK`.
+//!
+//! [`parse_event`] turns a request body into a typed [`WebhookEvent`] with any HTTP
+//! framework. [`WebhookHandler`] goes further: it is a tower [`Service`] that deduplicates
+//! deliveries by event ID and dispatches the events to an async handler, and can be
+//! mounted on an axum router as is:
+//!
+//! ```no_run
+//! use sumup::webhooks::{WebhookEvent, WebhookHandler};
+//!
+//! let callbacks = WebhookHandler::new(|event: WebhookEvent| async move {
+//!     if let WebhookEvent::ReaderCheckoutStatusChange(change) = event {
+//!         println!(
+//!             "{} is {:?}",
+//!             change.payload.client_transaction_id, change.payload.status
+//!         );
+//!     }
+//!     Ok::<_, std::convert::Infallible>(())
+//! });
+//! let app: axum::Router = axum::Router::new().route_service("/sumup/callbacks", callbacks);
+//! ```
+
+use std::collections::{HashSet, VecDeque};
+use std::convert::Infallible;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use http_body_util::{BodyExt, Limited};
+use tower_service::Service;
+
+pub use crate::resources::readers::ReaderCheckoutStatusChange;
+
+/// Event type of [`ReaderCheckoutStatusChange`] callbacks.
+pub const READER_CHECKOUT_STATUS_CHANGE: &str = "solo.transaction.updated";
+
+/// A callback received from SumUp.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    /// The transaction of a reader checkout completed.
+    ReaderCheckoutStatusChange(ReaderCheckoutStatusChange),
+    /// An event of a type this version of the SDK does not know.
+    Unknown(serde_json::Value),
+}
+
+impl WebhookEvent {
+    /// Returns the unique identifier of the event, if present.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Self::ReaderCheckoutStatusChange(event) => Some(&event.id),
+            Self::Unknown(value) => value.get("id").and_then(serde_json::Value::as_str),
+        }
+    }
+
+    /// Returns the type of the event, e.g. `solo.transaction.updated`, if present.
+    pub fn event_type(&self) -> Option<&str> {
+        match self {
+            Self::ReaderCheckoutStatusChange(event) => Some(&event.event_type),
+            Self::Unknown(value) => value.get("event_type").and_then(serde_json::Value::as_str),
+        }
+    }
+}
+
+/// Error returned when a callback body is not a valid event.
+#[derive(Debug)]
+pub struct ParseWebhookError {
+    source: serde_json::Error,
+}
+
+impl fmt::Display for ParseWebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid webhook payload: {}", self.source)
+    }
+}
+
+impl std::error::Error for ParseWebhookError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Parses the body of a callback request.
+///
+/// Events are dispatched on their `event_type`; types unknown to the SDK are returned as
+/// [`WebhookEvent::Unknown`] so that new events do not break existing receivers.
+pub fn parse_event(body: &[u8]) -> Result<WebhookEvent, ParseWebhookError> {
+    let value: serde_json::Value =
+        serde_json::from_slice(body).map_err(|source| ParseWebhookError { source })?;
+    match value.get("event_type").and_then(serde_json::Value::as_str) {
+        Some(READER_CHECKOUT_STATUS_CHANGE) => serde_json::from_value(value)
+            .map(WebhookEvent::ReaderCheckoutStatusChange)
+            .map_err(|source| ParseWebhookError { source }),
+        _ => Ok(WebhookEvent::Unknown(value)),
+    }
+}
+
+/// IDs of the most recently delivered events, oldest first.
+struct SeenEvents {
+    ids: HashSet<String>,
+    order: VecDeque<String>,
+    capacity: usize,
+}
+
+impl SeenEvents {
+    fn new(capacity: usize) -> Self {
+        Self {
+            ids: HashSet::new(),
+            order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    fn contains(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+
+    /// Records the ID, returning `false` if it was seen before.
+    fn insert(&mut self, id: &str) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+        if !self.ids.insert(id.to_string()) {
+            return false;
+        }
+        self.order.push_back(id.to_string());
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+/// Events being handled and events handled successfully, by ID.
+struct Deliveries {
+    in_progress: HashSet<String>,
+    done: SeenEvents,
+}
+
+/// What to do with a delivery, see [`Deliveries::start`].
+enum Delivery {
+    /// The event is new and now in progress.
+    Started,
+    /// The event is being handled by an earlier delivery.
+    InProgress,
+    /// The event was handled successfully before.
+    Done,
+}
+
+impl Deliveries {
+    fn new(capacity: usize) -> Self {
+        Self {
+            in_progress: HashSet::new(),
+            done: SeenEvents::new(capacity),
+        }
+    }
+
+    fn start(&mut self, id: &str) -> Delivery {
+        if self.done.capacity == 0 {
+            return Delivery::Started;
+        }
+        if self.done.contains(id) {
+            return Delivery::Done;
+        }
+        if !self.in_progress.insert(id.to_string()) {
+            return Delivery::InProgress;
+        }
+        Delivery::Started
+    }
+
+    /// Ends a started delivery, remembering the event if it was handled successfully.
+    fn finish(&mut self, id: &str, handled: bool) {
+        self.in_progress.remove(id);
+        if handled {
+            self.done.insert(id);
+        }
+    }
+}
+
+/// Ends a started delivery as failed unless [`InProgress::finish`] is called, so that an
+/// event whose handler panicked or was dropped can be delivered again.
+struct InProgress<'a> {
+    deliveries: &'a Mutex<Deliveries>,
+    id: Option<&'a str>,
+}
+
+impl InProgress<'_> {
+    fn finish(mut self, handled: bool) {
+        if let Some(id) = self.id.take() {
+            lock(self.deliveries).finish(id, handled);
+        }
+    }
+}
+
+impl Drop for InProgress<'_> {
+    fn drop(&mut self) {
+        if let Some(id) = self.id.take() {
+            lock(self.deliveries).finish(id, false);
+        }
+    }
+}
+
+fn lock(deliveries: &Mutex<Deliveries>) -> std::sync::MutexGuard<'_, Deliveries> {
+    deliveries
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Receives SumUp callbacks and dispatches them to an async handler.
+///
+/// [`WebhookHandler::handle`] answers with:
+/// - `200 OK` once the handler succeeded, or right away for an event ID that was
+///   already handled successfully,
+/// - `400 Bad Request` for a body that is not a valid event,
+/// - `409 Conflict` for an event ID whose handler is still running, so that SumUp
+///   retries the delivery in case that handler fails,
+/// - `500 Internal Server Error` when the handler fails, so that SumUp retries the
+///   delivery.
+///
+/// The IDs of the last 1024 events are remembered for deduplication, see
+/// [`WebhookHandler::with_dedup_capacity`]. As a [`Service`], the handler only accepts
+/// `POST` requests with bodies of up to 64 KiB.
+pub struct WebhookHandler<F> {
+    handler: Arc<F>,
+    deliveries: Arc<Mutex<Deliveries>>,
+    max_body_size: usize,
+}
+
+impl<F, Fut, E> WebhookHandler<F>
+where
+    F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send,
+{
+    /// Creates a receiver calling `handler` once per event.
+    pub fn new(handler: F) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            deliveries: Arc::new(Mutex::new(Deliveries::new(1024))),
+            max_body_size: 64 * 1024,
+        }
+    }
+
+    /// Sets how many event IDs are remembered for deduplication. `0` disables it.
+    pub fn with_dedup_capacity(mut self, capacity: usize) -> Self {
+        self.deliveries = Arc::new(Mutex::new(Deliveries::new(capacity)));
+        self
+    }
+
+    /// Sets the largest request body accepted by the [`Service`] implementation.
+    pub fn with_max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Handles the body of a callback request and returns the status to respond with.
+    pub async fn handle(&self, body: &[u8]) -> http::StatusCode {
+        let event = match parse_event(body) {
+            Ok(event) => event,
+            Err(_) => return http::StatusCode::BAD_REQUEST,
+        };
+        let id = event.id().map(str::to_string);
+        if let Some(id) = &id {
+            match lock(&self.deliveries).start(id) {
+                Delivery::Started => {}
+                Delivery::InProgress => return http::StatusCode::CONFLICT,
+                Delivery::Done => return http::StatusCode::OK,
+            }
+        }
+        let in_progress = InProgress {
+            deliveries: &self.deliveries,
+            id: id.as_deref(),
+        };
+
+        let handled = (self.handler)(event).await.is_ok();
+        in_progress.finish(handled);
+        if handled {
+            http::StatusCode::OK
+        } else {
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+impl<F> Clone for WebhookHandler<F> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: Arc::clone(&self.handler),
+            deliveries: Arc::clone(&self.deliveries),
+            max_body_size: self.max_body_size,
+        }
+    }
+}
+
+impl<F> fmt::Debug for WebhookHandler<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookHandler")
+            .field("max_body_size", &self.max_body_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, Fut, E, B> Service<http::Request<B>> for WebhookHandler<F>
+where
+    F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = http::Response<String>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let this = self.clone();
+        Box::pin(async move {
+            let status = if request.method() != http::Method::POST {
+                http::StatusCode::METHOD_NOT_ALLOWED
+            } else {
+                match Limited::new(request.into_body(), this.max_body_size)
+                    .collect()
+                    .await
+                {
+                    Ok(body) => this.handle(&body.to_bytes()).await,
+                    Err(err) if err.is::<http_body_util::LengthLimitError>() => {
+                        http::StatusCode::PAYLOAD_TOO_LARGE
+                    }
+                    Err(_) => http::StatusCode::BAD_REQUEST,
+                }
+            };
+            let mut response = http::Response::new(String::new());
+            *response.status_mut() = status;
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seen_events_forget_the_oldest_ids() {
+        let mut seen = SeenEvents::new(2);
+        assert!(seen.insert("a"));
+        assert!(!seen.insert("a"));
+        assert!(seen.insert("b"));
+        assert!(seen.insert("c"));
+        assert!(seen.insert("a"));
+        assert!(!seen.contains("b"));
+    }
+
+    #[test]
+    fn deliveries_remember_handled_events_only() {
+        let mut deliveries = Deliveries::new(2);
+        assert!(matches!(deliveries.start("a"), Delivery::Started));
+        assert!(matches!(deliveries.start("a"), Delivery::InProgress));
+        deliveries.finish("a", false);
+        assert!(matches!(deliveries.start("a"), Delivery::Started));
+        deliveries.finish("a", true);
+        assert!(matches!(deliveries.start("a"), Delivery::Done));
+    }
+}
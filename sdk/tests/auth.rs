@@ -0,0 +1,310 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::json;
+use sumup::auth::{AuthError, Authorization, ClientCredentialsProvider, RefreshTokenProvider};
+use sumup::{Client, SdkError};
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> RefreshTokenProvider {
+    RefreshTokenProvider::new("client-id", "client-secret", "refresh-1")
+        .with_token_url(format!("{}/token", server.uri()))
+}
+
+fn client(server: &MockServer, provider: RefreshTokenProvider) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::token_provider(provider))
+}
+
+fn token_response(access_token: &str, refresh_token: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "access_token": access_token,
+        "token_type": "Bearer",
+        "expires_in": 3600,
+        "refresh_token": refresh_token,
+    }))
+}
+
+async fn mount_checkouts(server: &MockServer, access_token: &str) {
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .and(header("Authorization", format!("Bearer {access_token}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn fetches_access_token_before_first_request() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(body_string_contains("grant_type=refresh_token"))
+        .and(body_string_contains("refresh_token=refresh-1"))
+        .and(body_string_contains("client_id=client-id"))
+        .respond_with(token_response("access-1", "refresh-2"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_checkouts(&server, "access-1").await;
+
+    let provider = provider(&server);
+    let client = client(&server, provider.clone());
+    client
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("first request should succeed");
+    client
+        .clone()
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("cached token should be reused by clones");
+
+    assert_eq!(provider.refresh_token().await.secret(), "refresh-2");
+}
+
+#[tokio::test]
+async fn reports_rotated_refresh_tokens() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_response("access-1", "refresh-2"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_checkouts(&server, "access-1").await;
+
+    let rotated = Arc::new(Mutex::new(Vec::new()));
+    let provider = provider(&server).on_refresh_token_rotated({
+        let rotated = rotated.clone();
+        move |token| rotated.lock().unwrap().push(token.secret().to_string())
+    });
+    client(&server, provider)
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(*rotated.lock().unwrap(), ["refresh-2"]);
+}
+
+#[tokio::test]
+async fn configuring_a_clone_keeps_the_shared_token_state() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_response("access-1", "refresh-2"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_checkouts(&server, "access-1").await;
+
+    let original = RefreshTokenProvider::new("client-id", "client-secret", "refresh-1");
+    let configured = original
+        .clone()
+        .with_token_url(format!("{}/token", server.uri()))
+        .with_refresh_margin(Duration::from_secs(5));
+    client(&server, configured)
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("configured clone should obtain a token");
+
+    assert_eq!(original.refresh_token().await.secret(), "refresh-2");
+}
+
+#[test]
+fn get_header_returns_static_tokens_only() {
+    let api_key = Authorization::api_key("key");
+    assert_eq!(api_key.get_header(), Some("key"));
+    assert!(api_key.provider().is_none());
+
+    let provider = Authorization::token_provider(RefreshTokenProvider::new(
+        "client-id",
+        "client-secret",
+        "refresh-1",
+    ));
+    assert_eq!(provider.get_header(), None);
+    assert!(provider.provider().is_some());
+    assert_eq!(
+        Client::new().with_authorization(provider).authorization(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn refreshes_access_token_shortly_before_expiry() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_response("access-2", "refresh-2"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_checkouts(&server, "access-2").await;
+
+    let provider = provider(&server)
+        .with_access_token("access-1", Duration::from_secs(30))
+        .await;
+    client(&server, provider)
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should use the refreshed token");
+}
+
+#[tokio::test]
+async fn huge_refresh_margin_refreshes_instead_of_panicking() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_response("access-2", "refresh-2"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_checkouts(&server, "access-2").await;
+
+    let provider = provider(&server)
+        .with_refresh_margin(Duration::MAX)
+        .with_access_token("access-1", Duration::from_secs(3600))
+        .await;
+    client(&server, provider)
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should use the refreshed token");
+}
+
+#[tokio::test]
+async fn refreshes_access_token_once_on_unauthorized() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_response("access-2", "refresh-2"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .and(header("Authorization", "Bearer access-1"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "type": "https://developer.sumup.com/problem/unauthorized",
+            "title": "Unauthorized",
+            "status": 401
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_checkouts(&server, "access-2").await;
+
+    let provider = provider(&server)
+        .with_access_token("access-1", Duration::from_secs(3600))
+        .await;
+    client(&server, provider)
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should be replayed with the refreshed token");
+}
+
+#[tokio::test]
+async fn reports_rejected_refresh_token() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "invalid_grant"
+        })))
+        .mount(&server)
+        .await;
+
+    let error = client(&server, provider(&server))
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect_err("request should fail without a token");
+
+    match error {
+        SdkError::Auth(AuthError::Rejected(status, body)) => {
+            assert_eq!(status, reqwest::StatusCode::BAD_REQUEST);
+            assert!(body.to_string().contains("invalid_grant"));
+        }
+        other => panic!("expected authorization error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn client_credentials_token_is_cached_and_renewed_on_unauthorized() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(body_string_contains("grant_type=client_credentials"))
+        .and(body_string_contains("client_secret=client-secret"))
+        .and(body_string_contains("scope=payments+transactions.history"))
+        .respond_with(token_response("access-1", "unused"))
+        .up_to_n_times(1)
+        .expect(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_response("access-2", "unused"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_checkouts(&server, "access-1").await;
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts/chk-1"))
+        .and(header("Authorization", "Bearer access-1"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts/chk-1"))
+        .and(header("Authorization", "Bearer access-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": "chk-1" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = ClientCredentialsProvider::new(
+        "client-id",
+        "client-secret",
+        ["payments", "transactions.history"],
+    )
+    .with_token_url(format!("{}/token", server.uri()));
+    let client = Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::token_provider(provider));
+
+    client
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should succeed with the fetched token");
+    client
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should reuse the cached token");
+    let checkout = client
+        .checkouts()
+        .get("chk-1")
+        .await
+        .expect("request should be replayed with a renewed token");
+    assert_eq!(checkout.id.as_deref(), Some("chk-1"));
+}
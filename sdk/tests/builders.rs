@@ -0,0 +1,37 @@
+use serde_json::json;
+use sumup::checkouts::{CreateRequest, CreateRequestPurpose, UpdateRequest};
+use sumup::{Amount, Currency, Nullable};
+
+#[test]
+fn builder_serializes_required_and_set_fields_only() {
+    let request = CreateRequest::builder("order-1", Amount::from(10), Currency::EUR, "MH4H92C7")
+        .description("Coffee")
+        .purpose(CreateRequestPurpose::Checkout)
+        .build();
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "checkout_reference": "order-1",
+            "amount": 10,
+            "currency": "EUR",
+            "merchant_code": "MH4H92C7",
+            "description": "Coffee",
+            "purpose": "CHECKOUT"
+        })
+    );
+}
+
+#[test]
+fn builder_sends_explicit_nulls_for_nullable_fields() {
+    let request = UpdateRequest::builder().valid_until(Nullable::Null).build();
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({ "valid_until": null })
+    );
+    assert_eq!(
+        serde_json::to_value(UpdateRequest::builder().build()).unwrap(),
+        json!({})
+    );
+}
@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use serde_json::json;
 use serial_test::serial;
+use sumup::checkouts::{CreateApplePaySessionBadRequestBody, CreateApplePaySessionErrorBody};
 use sumup::{version, Authorization, Client};
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -76,6 +77,16 @@ fn client_with_authorization_overrides_env_value() {
     assert_eq!(client.authorization(), Some(override_token));
 }
 
+#[test]
+fn client_debug_output_redacts_credentials() {
+    let client = Client::new().with_authorization(Authorization::access_token("sup_sk_secret"));
+
+    let debug_output = format!("{client:?}");
+
+    assert!(!debug_output.contains("sup_sk_secret"));
+    assert!(debug_output.contains("AccessToken(Secret { secret: \"***\" })"));
+}
+
 #[tokio::test]
 #[serial]
 async fn client_requests_include_user_agent_and_custom_authorization() {
@@ -142,3 +153,135 @@ fn client_returns_none_when_authorization_missing() {
     let client = Client::new();
     assert!(client.authorization().is_none());
 }
+
+#[tokio::test]
+async fn client_reports_decode_errors_with_field_path() {
+    let server = MockServer::start().await;
+
+    let body = json!([{ "id": "checkout-1", "amount": "ten" }]);
+    let _mock = Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&body))
+        .mount_as_scoped(&server)
+        .await;
+
+    let client = Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("token"));
+
+    let err = client
+        .checkouts()
+        .list(sumup::resources::checkouts::ListParams::default())
+        .await
+        .expect_err("body should not decode");
+
+    let sumup::SdkError::Decode(decode) = &err else {
+        panic!("expected decode error, got {:?}", err);
+    };
+    assert_eq!(decode.status(), reqwest::StatusCode::OK);
+    assert_eq!(decode.path(), "[0].amount");
+    assert_eq!(decode.body(), serde_json::to_vec(&body).unwrap());
+    assert_eq!(err.status(), Some(reqwest::StatusCode::OK));
+}
+
+#[tokio::test]
+async fn client_attaches_response_metadata_to_api_errors() {
+    let server = MockServer::start().await;
+
+    let _mock = Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts/missing"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .insert_header("X-Request-Id", "req-123")
+                .set_body_json(json!({ "error_code": "NOT_FOUND" })),
+        )
+        .mount_as_scoped(&server)
+        .await;
+
+    let client = Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("token"));
+
+    let err = client
+        .checkouts()
+        .get("missing")
+        .await
+        .expect_err("checkout should not exist");
+
+    assert!(err.is_not_found());
+    assert_eq!(err.status(), Some(reqwest::StatusCode::NOT_FOUND));
+    let response = err.response().expect("response metadata");
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    assert_eq!(response.request_id(), Some("req-123"));
+    assert_eq!(response.method(), reqwest::Method::GET);
+    assert_eq!(response.path(), "/v0.1/checkouts/missing");
+}
+
+#[tokio::test]
+async fn client_decodes_one_of_error_bodies() {
+    let server = MockServer::start().await;
+
+    let _mock = Mock::given(method("PUT"))
+        .and(path("/v0.2/checkouts/chk-1/apple-pay-session"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!([
+            { "error_code": "INVALID", "message": "Missing context" },
+            { "error_code": "INVALID", "message": "Missing target" }
+        ])))
+        .mount_as_scoped(&server)
+        .await;
+
+    let client = Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("token"));
+
+    let err = client
+        .checkouts()
+        .create_apple_pay_session("chk-1", None)
+        .await
+        .expect_err("request should be rejected");
+
+    match err.into_body() {
+        Some(CreateApplePaySessionErrorBody::BadRequest(
+            CreateApplePaySessionBadRequestBody::ErrorList(errors),
+        )) => {
+            assert_eq!(errors.len(), 2);
+            assert_eq!(errors[1].message.as_deref(), Some("Missing target"));
+        }
+        other => panic!("unexpected error body: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn client_returns_response_metadata_with_data() {
+    let server = MockServer::start().await;
+
+    let body = json!([{ "id": "checkout-1" }]);
+    let _mock = Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Request-Id", "req-456")
+                .set_body_json(&body),
+        )
+        .mount_as_scoped(&server)
+        .await;
+
+    let client = Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("token"));
+
+    let (checkouts, response) = client
+        .checkouts()
+        .list_with_response(sumup::resources::checkouts::ListParams::default())
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(checkouts.len(), 1);
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(response.request_id(), Some("req-456"));
+    assert_eq!(response.path(), "/v0.1/checkouts");
+    assert_eq!(
+        response.body(),
+        Some(serde_json::to_vec(&body).unwrap().as_slice())
+    );
+}
@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use serde_json::json;
+use sumup::{Authorization, Client, RequestOptions, RetryPolicy};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(server: &MockServer) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("test-key"))
+}
+
+fn create_customer() -> sumup::customers::CreateRequest {
+    sumup::customers::CreateRequest::builder("customer").build()
+}
+
+#[tokio::test]
+async fn sends_explicit_idempotency_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v0.1/customers"))
+        .and(header("Idempotency-Key", "customer-create-1"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "customer_id": "customer"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client(&server)
+        .customers()
+        .create_with_options(
+            create_customer(),
+            RequestOptions::new().with_idempotency_key("customer-create-1"),
+        )
+        .await
+        .expect("request should succeed");
+}
+
+#[tokio::test]
+async fn idempotency_key_applies_to_a_single_call() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v0.1/customers"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "customer_id": "customer"
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let customers = client.customers();
+    customers
+        .create_with_options(
+            create_customer(),
+            RequestOptions::new().with_idempotency_key("customer-create-1"),
+        )
+        .await
+        .expect("request should succeed");
+    customers
+        .create(create_customer())
+        .await
+        .expect("request should succeed");
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(
+        requests[0].headers.get("Idempotency-Key").unwrap(),
+        "customer-create-1"
+    );
+    assert!(requests[1].headers.get("Idempotency-Key").is_none());
+}
+
+#[tokio::test]
+async fn idempotency_key_does_not_make_post_retryable() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v0.1/customers"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = client(&server)
+        .with_retry_policy(
+            RetryPolicy::new(2)
+                .with_initial_backoff(Duration::from_millis(1))
+                .with_jitter(false),
+        )
+        .customers()
+        .create_with_options(
+            create_customer(),
+            RequestOptions::new().with_idempotency_key("customer-create-1"),
+        )
+        .await
+        .expect_err("request should fail without retries");
+    assert_eq!(
+        error.status(),
+        Some(reqwest::StatusCode::SERVICE_UNAVAILABLE)
+    );
+}
+
+#[tokio::test]
+async fn options_combine_with_response_metadata() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v0.1/customers"))
+        .and(header("Idempotency-Key", "customer-create-1"))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .insert_header("x-request-id", "req-1")
+                .set_body_json(json!({ "customer_id": "customer" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let (customer, meta) = client(&server)
+        .customers()
+        .create_with_response_and_options(
+            create_customer(),
+            RequestOptions::new().with_idempotency_key("customer-create-1"),
+        )
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(customer.customer_id, "customer");
+    assert_eq!(meta.status(), reqwest::StatusCode::CREATED);
+    assert_eq!(meta.request_id(), Some("req-1"));
+}
@@ -0,0 +1,112 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::json;
+use sumup::{Authorization, Client, Middleware, RetryPolicy};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+struct SetHeader(&'static str, &'static str);
+
+impl Middleware for SetHeader {
+    fn on_request(&self, request: &mut reqwest::Request) {
+        request
+            .headers_mut()
+            .insert(self.0, reqwest::header::HeaderValue::from_static(self.1));
+    }
+}
+
+#[derive(Clone, Default)]
+struct Recorder {
+    events: Arc<Mutex<Vec<String>>>,
+    responses: Arc<AtomicUsize>,
+}
+
+impl Middleware for Recorder {
+    fn on_request(&self, request: &mut reqwest::Request) {
+        let correlation_id = request
+            .headers()
+            .get("X-Correlation-Id")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("missing")
+            .to_string();
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", request.method(), correlation_id));
+    }
+
+    fn on_response(&self, response: &mut reqwest::Response) {
+        self.responses.fetch_add(1, Ordering::SeqCst);
+        self.events
+            .lock()
+            .unwrap()
+            .push(response.status().as_u16().to_string());
+    }
+}
+
+fn client(server: &MockServer) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("test-key"))
+}
+
+#[tokio::test]
+async fn middleware_can_modify_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .and(header("X-Correlation-Id", "abc-123"))
+        .and(header("Authorization", "Bearer test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client(&server)
+        .with_middleware(SetHeader("X-Correlation-Id", "abc-123"))
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should succeed");
+}
+
+#[tokio::test]
+async fn middleware_runs_in_registration_order_for_every_attempt() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&server)
+        .await;
+
+    let recorder = Recorder::default();
+    client(&server)
+        .with_retry_policy(
+            RetryPolicy::new(2)
+                .with_initial_backoff(Duration::from_millis(1))
+                .with_jitter(false),
+        )
+        .with_middleware(SetHeader("X-Correlation-Id", "abc-123"))
+        .with_middleware(recorder.clone())
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should succeed after retry");
+
+    assert_eq!(recorder.responses.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        *recorder.events.lock().unwrap(),
+        ["GET abc-123", "503", "GET abc-123", "200"]
+    );
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use serde_json::json;
+use sumup::oauth::{OAuthClient, Scope};
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn oauth_client(server: &MockServer) -> OAuthClient {
+    OAuthClient::new("client-id", "client-secret", "https://example.com/callback")
+        .with_token_url(format!("{}/token", server.uri()))
+}
+
+#[tokio::test]
+async fn exchanges_code_for_token_set() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(body_string_contains("grant_type=authorization_code"))
+        .and(body_string_contains("code=auth-code"))
+        .and(body_string_contains("code_verifier=verifier"))
+        .and(body_string_contains(
+            "redirect_uri=https%3A%2F%2Fexample.com%2Fcallback",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "access-1",
+            "token_type": "Bearer",
+            "expires_in": 3600,
+            "refresh_token": "refresh-1",
+            "scope": "payments transactions.history"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let tokens = oauth_client(&server)
+        .exchange_code("auth-code", "verifier")
+        .await
+        .expect("code exchange should succeed");
+
+    assert_eq!(tokens.access_token.secret(), "access-1");
+    assert_eq!(
+        tokens.refresh_token.as_ref().map(|token| token.secret()),
+        Some("refresh-1")
+    );
+    assert_eq!(
+        tokens.scopes,
+        vec![Scope::Payments, Scope::TransactionsHistory]
+    );
+    let expires_in = tokens.expires_in().expect("expiry should be known");
+    assert!(expires_in > Duration::from_secs(3500));
+}
+
+#[tokio::test]
+async fn token_set_authorizes_client_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "access-1",
+            "expires_in": 3600,
+            "refresh_token": "refresh-1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .and(header("Authorization", "Bearer access-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let oauth = oauth_client(&server);
+    let tokens = oauth
+        .exchange_code("auth-code", "verifier")
+        .await
+        .expect("code exchange should succeed");
+
+    oauth
+        .authorization(&tokens)
+        .provider()
+        .expect("refresh token should be used");
+    sumup::Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(oauth.authorization(&tokens))
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should use the exchanged access token");
+}
+
+#[tokio::test]
+async fn authorize_client_applies_granted_scopes() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "access-1",
+            "scope": "payments"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let oauth = oauth_client(&server);
+    let tokens = oauth
+        .exchange_code("auth-code", "verifier")
+        .await
+        .expect("code exchange should succeed");
+
+    let client = oauth.authorize_client(sumup::Client::new(), &tokens);
+    assert_eq!(client.granted_scopes(), Some(&[Scope::Payments][..]));
+    assert_eq!(client.authorization(), Some("access-1"));
+    assert!(oauth.refresh_token_provider(&tokens).is_none());
+}
@@ -0,0 +1,253 @@
+use futures_util::{StreamExt, TryStreamExt};
+use serde_json::json;
+use sumup::transactions::{ListParams, ListParamsStatusesItem, ListParamsTypesItem};
+use sumup::{Authorization, Client};
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const HISTORY_PATH: &str = "/v2.1/merchants/MH4H92C7/transactions/history";
+
+fn client(server: &MockServer) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("test-key"))
+}
+
+async fn mount_history(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(HISTORY_PATH))
+        .and(query_param_is_missing("oldest_ref"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [{ "transaction_code": "TX1" }, { "transaction_code": "TX2" }],
+            "links": [{ "rel": "next", "href": "limit=2&oldest_ref=ref-2&order=ascending" }]
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(HISTORY_PATH))
+        .and(query_param("oldest_ref", "ref-2"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [{ "transaction_code": "TX3" }],
+            "links": []
+        })))
+        .mount(server)
+        .await;
+}
+
+fn codes(transactions: &[sumup::transactions::TransactionHistory]) -> Vec<&str> {
+    transactions
+        .iter()
+        .filter_map(|transaction| transaction.transaction_code.as_deref())
+        .collect()
+}
+
+#[tokio::test]
+async fn list_all_follows_next_links() {
+    let server = MockServer::start().await;
+    mount_history(&server).await;
+
+    let transactions: Vec<_> = client(&server)
+        .transactions()
+        .list_all("MH4H92C7", Default::default())
+        .try_collect()
+        .await
+        .expect("pagination should succeed");
+
+    assert_eq!(codes(&transactions), ["TX1", "TX2", "TX3"]);
+}
+
+#[tokio::test]
+async fn list_all_stops_at_max_items_without_fetching_more_pages() {
+    let server = MockServer::start().await;
+    mount_history(&server).await;
+
+    let client = client(&server);
+    let mut transactions = client
+        .transactions()
+        .list_all("MH4H92C7", Default::default())
+        .with_max_items(2);
+    let mut collected = Vec::new();
+    while let Some(transaction) = transactions.next().await {
+        collected.push(transaction.expect("page should load"));
+    }
+
+    assert_eq!(codes(&collected), ["TX1", "TX2"]);
+    assert_eq!(
+        transactions.cursor().map(String::as_str),
+        Some("limit=2&oldest_ref=ref-2&order=ascending")
+    );
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn list_all_from_resumes_at_saved_cursor() {
+    let server = MockServer::start().await;
+    mount_history(&server).await;
+
+    let transactions: Vec<_> = client(&server)
+        .transactions()
+        .list_all_from(
+            "MH4H92C7",
+            Default::default(),
+            "limit=2&oldest_ref=ref-2&order=ascending",
+        )
+        .try_collect()
+        .await
+        .expect("pagination should succeed");
+
+    assert_eq!(codes(&transactions), ["TX3"]);
+}
+
+fn member(id: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "roles": ["role_employee"],
+        "permissions": [],
+        "created_at": "2023-01-20T15:16:17Z",
+        "updated_at": "2023-01-20T15:16:17Z",
+        "status": "accepted"
+    })
+}
+
+async fn mount_members(server: &MockServer) {
+    for (offset, ids) in [("0", ["mem_1", "mem_2"]), ("2", ["mem_3", "mem_4"])] {
+        Mock::given(method("GET"))
+            .and(path("/v0.1/merchants/MH4H92C7/members"))
+            .and(query_param("offset", offset))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "items": ids.map(member),
+                "total_count": 4
+            })))
+            .mount(server)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn members_list_all_stops_at_total_count() {
+    let server = MockServer::start().await;
+    mount_members(&server).await;
+
+    let members = client(&server)
+        .members()
+        .list_all("MH4H92C7", Default::default())
+        .collect_vec()
+        .await
+        .expect("pagination should succeed");
+
+    let ids: Vec<_> = members.iter().map(|member| member.id.as_str()).collect();
+    assert_eq!(ids, ["mem_1", "mem_2", "mem_3", "mem_4"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn members_collect_vec_respects_max_items() {
+    let server = MockServer::start().await;
+    mount_members(&server).await;
+
+    let members = client(&server)
+        .members()
+        .list_all("MH4H92C7", Default::default())
+        .with_max_items(3)
+        .collect_vec()
+        .await
+        .expect("pagination should succeed");
+
+    assert_eq!(members.len(), 3);
+}
+
+#[tokio::test]
+async fn list_all_keeps_filters_the_next_links_leave_out() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(HISTORY_PATH))
+        .and(query_param("newest_ref", "ref-9"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [{ "transaction_code": "TX1" }],
+            "links": [{ "rel": "next", "href": "limit=1&oldest_ref=ref-1" }]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(HISTORY_PATH))
+        .and(query_param("oldest_ref", "ref-1"))
+        .and(query_param("statuses[]", "SUCCESSFUL"))
+        .and(query_param("statuses[]", "FAILED"))
+        .and(query_param("types[]", "REFUND"))
+        .and(query_param("users[]", "cashier@example.com"))
+        .and(query_param_is_missing("newest_ref"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [{ "transaction_code": "TX2" }],
+            "links": []
+        })))
+        .mount(&server)
+        .await;
+
+    let params = ListParams::builder()
+        .statuses(vec![
+            ListParamsStatusesItem::Successful,
+            ListParamsStatusesItem::Failed,
+        ])
+        .types(vec![ListParamsTypesItem::Refund])
+        .users(vec!["cashier@example.com".to_string()])
+        .newest_ref("ref-9")
+        .limit(1)
+        .build();
+    let transactions: Vec<_> = client(&server)
+        .transactions()
+        .list_all("MH4H92C7", params)
+        .try_collect()
+        .await
+        .expect("pagination should succeed");
+
+    assert_eq!(codes(&transactions), ["TX1", "TX2"]);
+}
+
+#[tokio::test]
+async fn list_all_from_applies_params_the_cursor_leaves_out() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(HISTORY_PATH))
+        .and(query_param("oldest_ref", "ref-2"))
+        .and(query_param("statuses[]", "REFUNDED"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [{ "transaction_code": "TX3" }],
+            "links": []
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let params = ListParams::builder()
+        .statuses(vec![ListParamsStatusesItem::Refunded])
+        .oldest_ref("ref-0")
+        .build();
+    let transactions: Vec<_> = client(&server)
+        .transactions()
+        .list_all_from("MH4H92C7", params, "limit=2&oldest_ref=ref-2")
+        .try_collect()
+        .await
+        .expect("pagination should succeed");
+
+    assert_eq!(codes(&transactions), ["TX3"]);
+}
+
+#[tokio::test]
+async fn list_all_from_rejects_invalid_cursor_values() {
+    let server = MockServer::start().await;
+    mount_history(&server).await;
+
+    let error = client(&server)
+        .transactions()
+        .list_all_from("MH4H92C7", Default::default(), "limit=ten&oldest_ref=ref-2")
+        .try_collect::<Vec<_>>()
+        .await
+        .expect_err("limit is not a number");
+
+    let sumup::SdkError::Validation(error) = error else {
+        panic!("expected a validation error, got {error:?}");
+    };
+    assert_eq!(error.violations()[0].path(), "limit");
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
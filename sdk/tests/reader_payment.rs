@@ -0,0 +1,393 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use sumup::reader_payment::{
+    ReaderPayment, ReaderPaymentEvent, ReaderPaymentOutcome, TerminationReason,
+};
+use sumup::readers::{CreateCheckoutRequest, GetReaderCheckoutResponseDataStatus};
+use sumup::wait::WaitOptions;
+use sumup::{Authorization, Client, Currency, Money};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const CHECKOUT_PATH: &str = "/v0.1/merchants/MCODE/readers/rdr_1/checkout";
+const TERMINATE_PATH: &str = "/v0.1/merchants/MCODE/readers/rdr_1/terminate";
+const TRANSACTION_PATH: &str = "/v2.1/merchants/MCODE/transactions";
+
+fn client(server: &MockServer) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("test-key"))
+}
+
+fn request() -> CreateCheckoutRequest {
+    CreateCheckoutRequest::builder(Money::from_minor_units(1000, Currency::EUR)).build()
+}
+
+fn fast_polling() -> WaitOptions {
+    WaitOptions::default()
+        .with_initial_interval(Duration::from_millis(5))
+        .with_max_interval(Duration::from_millis(20))
+}
+
+fn reader_checkout(status: &str) -> Value {
+    json!({
+        "data": {
+            "card_type": "visa",
+            "checkout_id": "chk_1",
+            "client_transaction_id": "ctx_1",
+            "created_at": "2024-01-01T10:00:00+00:00",
+            "installments": 1,
+            "payment_status": status,
+            "payment_type": "ecom",
+            "reader_firmware_version": "1.0.0",
+            "reader_serial_number": "123456789",
+            "status": status,
+            "total_amount": {"currency": "EUR", "minor_unit": 2, "value": 1000},
+            "updated_at": "2024-01-01T10:00:00+00:00",
+            "valid_until": "2024-01-01T10:05:00+00:00"
+        }
+    })
+}
+
+async fn mount_created(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path(CHECKOUT_PATH))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "data": {"checkout_id": "chk_1", "client_transaction_id": "ctx_1"}
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+async fn mount_status(server: &MockServer, status: &str, times: Option<u64>, priority: u8) {
+    let mock = Mock::given(method("GET"))
+        .and(path(format!("{CHECKOUT_PATH}/chk_1")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(reader_checkout(status)))
+        .with_priority(priority);
+    match times {
+        Some(times) => mock.up_to_n_times(times).mount(server).await,
+        None => mock.mount(server).await,
+    }
+}
+
+async fn mount_terminate(server: &MockServer, expected: u64) {
+    Mock::given(method("POST"))
+        .and(path(TERMINATE_PATH))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(expected)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn run_polls_until_successful_and_fetches_the_transaction() {
+    let server = MockServer::start().await;
+    mount_created(&server).await;
+    mount_status(&server, "pending", Some(2), 1).await;
+    mount_status(&server, "successful", None, 2).await;
+    mount_terminate(&server, 0).await;
+    Mock::given(method("GET"))
+        .and(path(TRANSACTION_PATH))
+        .and(query_param("client_transaction_id", "ctx_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "410fc44a-5956-44e1-b5cc-19c6f8d727a4",
+            "transaction_code": "TEENSK4W2K",
+            "status": "SUCCESSFUL"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&events);
+    let completion = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .with_wait_options(fast_polling())
+        .on_event(move |event| recorded.lock().unwrap().push(event.clone()))
+        .run()
+        .await
+        .expect("payment should succeed");
+
+    assert_eq!(completion.outcome, ReaderPaymentOutcome::Successful);
+    assert_eq!(completion.terminated, None);
+    assert_eq!(completion.checkout_id.as_deref(), Some("chk_1"));
+    assert_eq!(
+        completion
+            .transaction
+            .and_then(|transaction| transaction.transaction_code)
+            .as_deref(),
+        Some("TEENSK4W2K")
+    );
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            ReaderPaymentEvent::CheckoutCreated {
+                checkout_id: Some("chk_1".to_string()),
+                client_transaction_id: "ctx_1".to_string(),
+            },
+            ReaderPaymentEvent::StatusChanged(GetReaderCheckoutResponseDataStatus::Pending),
+            ReaderPaymentEvent::StatusChanged(GetReaderCheckoutResponseDataStatus::Successful),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn run_terminates_the_checkout_after_timeout() {
+    let server = MockServer::start().await;
+    mount_created(&server).await;
+    mount_status(&server, "pending", Some(5), 1).await;
+    mount_status(&server, "cancelled", None, 2).await;
+    mount_terminate(&server, 1).await;
+
+    let client = client(&server);
+    let completion = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .with_wait_options(fast_polling().with_timeout(Duration::from_millis(30)))
+        .run()
+        .await
+        .expect("payment should complete");
+
+    assert_eq!(completion.outcome, ReaderPaymentOutcome::Cancelled);
+    assert_eq!(completion.terminated, Some(TerminationReason::Timeout));
+    assert_eq!(completion.transaction, None);
+}
+
+#[tokio::test]
+async fn run_terminates_the_checkout_when_cancelled() {
+    let server = MockServer::start().await;
+    mount_created(&server).await;
+    mount_status(&server, "pending", None, 1).await;
+    mount_terminate(&server, 1).await;
+
+    let client = client(&server);
+    let completion = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .with_wait_options(fast_polling().without_timeout())
+        .with_termination_grace(Duration::from_millis(30))
+        .with_cancellation(tokio::time::sleep(Duration::from_millis(10)))
+        .run()
+        .await
+        .expect("payment should complete");
+
+    assert_eq!(completion.outcome, ReaderPaymentOutcome::Unknown);
+    assert_eq!(completion.terminated, Some(TerminationReason::Cancelled));
+}
+
+#[tokio::test]
+async fn run_terminates_the_checkout_when_cancelled_during_a_slow_poll() {
+    let server = MockServer::start().await;
+    mount_created(&server).await;
+    Mock::given(method("GET"))
+        .and(path(format!("{CHECKOUT_PATH}/chk_1")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(reader_checkout("pending"))
+                .set_delay(Duration::from_secs(10)),
+        )
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    mount_status(&server, "cancelled", None, 2).await;
+    mount_terminate(&server, 1).await;
+
+    let client = client(&server);
+    let started = std::time::Instant::now();
+    let completion = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .with_wait_options(fast_polling().without_timeout())
+        .with_cancellation(tokio::time::sleep(Duration::from_millis(50)))
+        .run()
+        .await
+        .expect("payment should complete");
+
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(completion.outcome, ReaderPaymentOutcome::Cancelled);
+    assert_eq!(completion.terminated, Some(TerminationReason::Cancelled));
+}
+
+#[tokio::test]
+async fn run_terminates_the_checkout_created_after_a_cancellation() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(CHECKOUT_PATH))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(json!({
+                    "data": {"checkout_id": "chk_1", "client_transaction_id": "ctx_1"}
+                }))
+                .set_delay(Duration::from_millis(200)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_status(&server, "cancelled", None, 1).await;
+    mount_terminate(&server, 1).await;
+
+    let client = client(&server);
+    let completion = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .with_wait_options(fast_polling().without_timeout())
+        .with_cancellation(tokio::time::sleep(Duration::from_millis(50)))
+        .run()
+        .await
+        .expect("payment should complete");
+
+    assert_eq!(completion.outcome, ReaderPaymentOutcome::Cancelled);
+    assert_eq!(completion.terminated, Some(TerminationReason::Cancelled));
+    assert_eq!(completion.checkout_id.as_deref(), Some("chk_1"));
+    assert_eq!(completion.client_transaction_id, "ctx_1");
+
+    let requests = server.received_requests().await.unwrap();
+    let paths: Vec<_> = requests
+        .iter()
+        .filter(|request| request.method == wiremock::http::Method::POST)
+        .map(|request| request.url.path().to_string())
+        .collect();
+    assert_eq!(paths, [CHECKOUT_PATH, TERMINATE_PATH]);
+}
+
+#[tokio::test]
+async fn run_returns_checkout_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(CHECKOUT_PATH))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "errors": {"detail": "Reader not found"}
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let err = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .run()
+        .await
+        .expect_err("missing reader should fail");
+
+    assert!(err.is_not_found());
+}
+
+#[tokio::test]
+async fn run_treats_a_finished_checkout_as_terminated() {
+    let server = MockServer::start().await;
+    mount_created(&server).await;
+    mount_status(&server, "pending", Some(5), 1).await;
+    mount_status(&server, "successful", None, 2).await;
+    Mock::given(method("POST"))
+        .and(path(TERMINATE_PATH))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "type": "https://developer.sumup.com/problem/not-found",
+            "title": "Requested resource couldn't be found.",
+            "status": 404
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(TRANSACTION_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "txn_1"})))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let completion = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .with_wait_options(fast_polling().with_timeout(Duration::from_millis(30)))
+        .run()
+        .await
+        .expect("payment should complete");
+
+    assert_eq!(completion.outcome, ReaderPaymentOutcome::Successful);
+    assert_eq!(completion.terminated, Some(TerminationReason::Timeout));
+}
+
+#[tokio::test]
+async fn run_returns_termination_errors() {
+    let server = MockServer::start().await;
+    mount_created(&server).await;
+    mount_status(&server, "pending", None, 1).await;
+    Mock::given(method("POST"))
+        .and(path(TERMINATE_PATH))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "type": "https://developer.sumup.com/problem/unauthorized",
+            "title": "Unauthorized",
+            "status": 401
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let err = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .with_wait_options(fast_polling().with_timeout(Duration::from_millis(30)))
+        .run()
+        .await
+        .expect_err("rejected termination should fail");
+
+    assert!(err.is_auth_error());
+}
+
+#[tokio::test]
+async fn run_keeps_polling_after_transient_poll_errors() {
+    let server = MockServer::start().await;
+    mount_created(&server).await;
+    Mock::given(method("GET"))
+        .and(path(format!("{CHECKOUT_PATH}/chk_1")))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    mount_status(&server, "successful", None, 2).await;
+    mount_terminate(&server, 0).await;
+    Mock::given(method("GET"))
+        .and(path(TRANSACTION_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "txn_1"})))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let completion = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .with_wait_options(fast_polling().without_timeout())
+        .run()
+        .await
+        .expect("payment should succeed");
+
+    assert_eq!(completion.outcome, ReaderPaymentOutcome::Successful);
+    assert_eq!(completion.terminated, None);
+}
+
+#[tokio::test]
+async fn run_terminates_the_checkout_before_returning_poll_errors() {
+    let server = MockServer::start().await;
+    mount_created(&server).await;
+    mount_status(&server, "pending", Some(1), 1).await;
+    Mock::given(method("GET"))
+        .and(path(format!("{CHECKOUT_PATH}/chk_1")))
+        .respond_with(ResponseTemplate::new(500))
+        .with_priority(2)
+        .mount(&server)
+        .await;
+    mount_terminate(&server, 1).await;
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let client = client(&server);
+    let err = ReaderPayment::new(&client, "MCODE", "rdr_1", request())
+        .with_wait_options(fast_polling().without_timeout())
+        .on_event({
+            let events = events.clone();
+            move |event| events.lock().unwrap().push(event.clone())
+        })
+        .run()
+        .await
+        .expect_err("failed poll should be returned");
+
+    assert_eq!(
+        err.status(),
+        Some(reqwest::StatusCode::INTERNAL_SERVER_ERROR)
+    );
+    assert_eq!(
+        events.lock().unwrap().last(),
+        Some(&ReaderPaymentEvent::Terminating(
+            TerminationReason::PollFailed
+        ))
+    );
+}
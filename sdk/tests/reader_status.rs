@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use futures_util::TryStreamExt;
+use serde_json::json;
+use sumup::reader_status::ReaderStatusChange;
+use sumup::{Authorization, Client};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const STATUS_PATH: &str = "/v0.1/merchants/MCODE/readers/rdr_1/status";
+
+fn client(server: &MockServer) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("test-key"))
+}
+
+async fn mount_status(server: &MockServer, status: &str, battery_level: f32, times: u64) {
+    Mock::given(method("GET"))
+        .and(path(STATUS_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "battery_level": battery_level,
+                "firmware_version": "3.3.3.21",
+                "status": status
+            }
+        })))
+        .up_to_n_times(times)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn watch_status_yields_changes_and_survives_transient_errors() {
+    let server = MockServer::start().await;
+    mount_status(&server, "ONLINE", 80.0, 1).await;
+    mount_status(&server, "ONLINE", 75.0, 1).await;
+    Mock::given(method("GET"))
+        .and(path(STATUS_PATH))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    mount_status(&server, "OFFLINE", 10.0, 1).await;
+
+    let client = client(&server);
+    let readers = client.readers();
+    let mut updates = readers.watch_status("MCODE", "rdr_1", Duration::from_millis(5));
+
+    let first = updates.try_next().await.unwrap().expect("initial update");
+    assert_eq!(first.changes, [ReaderStatusChange::Initial]);
+
+    let second = updates.try_next().await.unwrap().expect("offline update");
+    assert_eq!(
+        second.changes,
+        [ReaderStatusChange::Offline, ReaderStatusChange::BatteryLow]
+    );
+    assert_eq!(second.status.battery_level, Some(10.0));
+    assert_eq!(server.received_requests().await.unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn watch_status_ends_after_other_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(STATUS_PATH))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "type": "https://developer.sumup.com/problem/not-found",
+            "title": "Not Found",
+            "status": 404
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let readers = client.readers();
+    let mut updates = readers.watch_status("MCODE", "rdr_1", Duration::from_millis(5));
+
+    let err = updates
+        .try_next()
+        .await
+        .expect_err("missing reader should fail");
+    assert!(err.is_not_found());
+    assert!(updates.try_next().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn watch_status_keeps_fields_the_reader_stops_reporting() {
+    let server = MockServer::start().await;
+    mount_status(&server, "ONLINE", 10.0, 1).await;
+    Mock::given(method("GET"))
+        .and(path(STATUS_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "status": "ONLINE" }
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    mount_status(&server, "ONLINE", 10.0, 1).await;
+    mount_status(&server, "OFFLINE", 10.0, 1).await;
+
+    let client = client(&server);
+    let readers = client.readers();
+    let mut updates = readers.watch_status("MCODE", "rdr_1", Duration::from_millis(5));
+
+    let first = updates.try_next().await.unwrap().expect("initial update");
+    assert_eq!(
+        first.changes,
+        [ReaderStatusChange::Initial, ReaderStatusChange::BatteryLow]
+    );
+
+    // Neither the incomplete poll nor the complete one after it report a change.
+    let next = updates.try_next().await.unwrap().expect("offline update");
+    assert_eq!(next.changes, [ReaderStatusChange::Offline]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    let last = updates.last_status().expect("last status");
+    assert_eq!(last.firmware_version.as_deref(), Some("3.3.3.21"));
+}
@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use serde_json::json;
+use sumup::{Amount, Authorization, Client, Currency, RequestOptions, RetryPolicy};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(server: &MockServer) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("test-key"))
+        .with_retry_policy(
+            RetryPolicy::new(3)
+                .with_initial_backoff(Duration::from_millis(1))
+                .with_jitter(false),
+        )
+}
+
+#[tokio::test]
+async fn retries_idempotent_requests_on_service_unavailable() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let checkouts = client(&server)
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("request should succeed after retries");
+    assert!(checkouts.is_empty());
+}
+
+#[tokio::test]
+async fn gives_up_after_max_attempts() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let error = client(&server)
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect_err("request should fail once attempts are exhausted");
+    assert_eq!(error.status(), Some(reqwest::StatusCode::TOO_MANY_REQUESTS));
+}
+
+#[tokio::test]
+async fn does_not_retry_post_without_idempotency_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v0.1/customers"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = client(&server)
+        .customers()
+        .create(sumup::customers::CreateRequest::builder("customer").build())
+        .await
+        .expect_err("request should fail without retries");
+    assert_eq!(
+        error.status(),
+        Some(reqwest::StatusCode::SERVICE_UNAVAILABLE)
+    );
+}
+
+#[tokio::test]
+async fn does_not_retry_post_with_idempotency_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v0.1/customers"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = client(&server)
+        .customers()
+        .create_with_options(
+            sumup::customers::CreateRequest::builder("customer").build(),
+            RequestOptions::new().with_idempotency_key("customer-create-1"),
+        )
+        .await
+        .expect_err("request should fail without retries");
+    assert_eq!(
+        error.status(),
+        Some(reqwest::StatusCode::SERVICE_UNAVAILABLE)
+    );
+}
+
+#[tokio::test]
+async fn retries_checkout_creation_deduplicated_by_checkout_reference() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client(&server)
+        .checkouts()
+        .create(
+            sumup::checkouts::CreateRequest::builder(
+                "order-1",
+                Amount::from(10),
+                Currency::EUR,
+                "MH4H92C7",
+            )
+            .build(),
+        )
+        .await
+        .expect("request should succeed after retry");
+}
+
+#[tokio::test]
+async fn does_not_wait_longer_than_max_backoff() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "3600"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = client(&server)
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect_err("request should fail without waiting for Retry-After");
+    assert_eq!(
+        error.status(),
+        Some(reqwest::StatusCode::SERVICE_UNAVAILABLE)
+    );
+}
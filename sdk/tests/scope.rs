@@ -0,0 +1,68 @@
+use serde_json::json;
+use sumup::auth::AuthError;
+use sumup::transactions::TransactionsClient;
+use sumup::{Authorization, Client, Scope, SdkError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(server: &MockServer) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::access_token("token"))
+}
+
+#[test]
+fn operations_expose_required_scopes() {
+    assert_eq!(
+        TransactionsClient::REFUND_SCOPES,
+        [Scope::Payments, Scope::RefundsWrite]
+    );
+    assert_eq!(Scope::from("refunds.write"), Scope::RefundsWrite);
+    assert_eq!(Scope::from("custom"), Scope::Other("custom".to_string()));
+    assert_eq!(Scope::UserAppSettings.to_string(), "user.app-settings");
+}
+
+#[tokio::test]
+async fn fails_fast_when_granted_scopes_do_not_cover_operation() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1.0/merchants/MC123/payments/tx-1/refunds"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let error = client(&server)
+        .with_granted_scopes([Scope::TransactionsHistory])
+        .transactions()
+        .refund("MC123", "tx-1", None)
+        .await
+        .expect_err("refund should require refunds.write");
+
+    match error {
+        SdkError::Auth(AuthError::MissingScopes(scopes)) => {
+            assert_eq!(scopes, [Scope::Payments, Scope::RefundsWrite]);
+        }
+        other => panic!("expected missing scopes error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn sends_request_when_any_accepted_scope_is_granted() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client(&server)
+        .with_granted_scopes(Scope::parse_list("checkouts.read"))
+        .checkouts()
+        .list(Default::default())
+        .await
+        .expect("checkouts.read should cover listing checkouts");
+}
@@ -0,0 +1,131 @@
+use serde_json::json;
+use sumup::checkouts::{CreateRequest, CreateRequestPurpose};
+use sumup::memberships::ListParams;
+use sumup::validation::{Validate, ViolationKind};
+use sumup::{Amount, Authorization, Client, Currency, SdkError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(server: &MockServer) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("key"))
+}
+
+fn create_request(checkout_reference: String) -> CreateRequest {
+    CreateRequest::builder(
+        checkout_reference,
+        Amount::from(10),
+        Currency::EUR,
+        "MH4H92C7",
+    )
+    .build()
+}
+
+#[test]
+fn validate_reports_every_violation_with_its_path() {
+    assert_eq!(create_request("order-42".to_string()).validate(), Ok(()));
+
+    let request = CreateRequest::builder(
+        "r".repeat(91),
+        Amount::from(10),
+        Currency::Other("XYZ".to_string()),
+        "MH4H92C7",
+    )
+    .purpose(CreateRequestPurpose::Checkout)
+    .build();
+    let error = request.validate().expect_err("request should be invalid");
+
+    let violations = error.violations();
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations[0].path(), "checkout_reference");
+    assert_eq!(
+        violations[0].kind(),
+        &ViolationKind::MaxLength {
+            max: 90,
+            actual: 91
+        }
+    );
+    assert_eq!(violations[1].path(), "currency");
+    assert_eq!(
+        violations[1].kind(),
+        &ViolationKind::Enum {
+            value: "XYZ".to_string()
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid request: checkout_reference must be at most 90 characters long, got 91; currency has unknown value `XYZ`"
+    );
+}
+
+#[tokio::test]
+async fn invalid_requests_fail_before_sending_when_enabled() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+        .expect(0)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v0.1/memberships"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [],
+            "total_count": 0
+        })))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = client(&server).with_request_validation(true);
+    assert!(client.request_validation());
+
+    let error = client
+        .checkouts()
+        .create(create_request("r".repeat(91)))
+        .await
+        .expect_err("checkout reference is too long");
+    match error {
+        SdkError::Validation(error) => {
+            assert_eq!(error.violations()[0].path(), "checkout_reference");
+        }
+        other => panic!("expected validation error, got {other:?}"),
+    }
+
+    let error = client
+        .memberships()
+        .list(ListParams::builder().limit(50).build())
+        .await
+        .expect_err("limit is above the maximum");
+    let SdkError::Validation(error) = error else {
+        panic!("expected validation error, got {error:?}");
+    };
+    assert_eq!(error.violations()[0].path(), "limit");
+    assert_eq!(
+        error.violations()[0].kind(),
+        &ViolationKind::Maximum {
+            max: 25.0,
+            exclusive: false
+        }
+    );
+}
+
+#[tokio::test]
+async fn requests_are_not_validated_by_default() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v0.1/checkouts"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client(&server)
+        .checkouts()
+        .create(create_request("r".repeat(91)))
+        .await
+        .expect("request should be sent");
+}
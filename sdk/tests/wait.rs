@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use serde_json::json;
+use sumup::wait::{CheckoutOutcome, WaitOptions};
+use sumup::{Authorization, Client};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const CHECKOUT_PATH: &str = "/v0.1/checkouts/checkout-1";
+
+fn client(server: &MockServer) -> Client {
+    Client::new()
+        .with_base_url(server.uri())
+        .with_authorization(Authorization::api_key("test-key"))
+}
+
+fn fast_polling() -> WaitOptions {
+    WaitOptions::default()
+        .with_initial_interval(Duration::from_millis(5))
+        .with_max_interval(Duration::from_millis(20))
+}
+
+async fn mount_pending(server: &MockServer, times: u64, valid_until: Option<&str>) {
+    Mock::given(method("GET"))
+        .and(path(CHECKOUT_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "checkout-1",
+            "status": "PENDING",
+            "valid_until": valid_until,
+        })))
+        .up_to_n_times(times)
+        .with_priority(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn wait_for_completion_polls_until_paid() {
+    let server = MockServer::start().await;
+    mount_pending(&server, 2, None).await;
+    Mock::given(method("GET"))
+        .and(path(CHECKOUT_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "checkout-1",
+            "status": "PAID",
+            "transaction_code": "TEENSK4W2K",
+            "transaction_id": "410fc44a-5956-44e1-b5cc-19c6f8d727a4"
+        })))
+        .with_priority(2)
+        .mount(&server)
+        .await;
+
+    let completion = client(&server)
+        .checkouts()
+        .wait_for_completion("checkout-1", fast_polling())
+        .await
+        .expect("wait should succeed");
+
+    assert_eq!(completion.outcome, CheckoutOutcome::Paid);
+    assert_eq!(completion.transaction_code.as_deref(), Some("TEENSK4W2K"));
+    assert_eq!(
+        completion.transaction_id.as_deref(),
+        Some("410fc44a-5956-44e1-b5cc-19c6f8d727a4")
+    );
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn wait_for_completion_reports_expired_after_valid_until() {
+    let server = MockServer::start().await;
+    mount_pending(&server, 10, Some("2020-02-29T10:56:56+00:00")).await;
+
+    let completion = client(&server)
+        .checkouts()
+        .wait_for_completion("checkout-1", fast_polling())
+        .await
+        .expect("wait should succeed");
+
+    assert_eq!(completion.outcome, CheckoutOutcome::Expired);
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn wait_for_completion_gives_up_after_timeout() {
+    let server = MockServer::start().await;
+    mount_pending(&server, 1000, None).await;
+
+    let completion = client(&server)
+        .checkouts()
+        .wait_for_completion(
+            "checkout-1",
+            fast_polling().with_timeout(Duration::from_millis(50)),
+        )
+        .await
+        .expect("wait should succeed");
+
+    assert_eq!(completion.outcome, CheckoutOutcome::TimedOut);
+    assert_eq!(completion.transaction_code, None);
+}
+
+#[tokio::test]
+async fn wait_for_completion_returns_api_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CHECKOUT_PATH))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": "NOT_FOUND",
+            "message": "Resource not found"
+        })))
+        .mount(&server)
+        .await;
+
+    let err = client(&server)
+        .checkouts()
+        .wait_for_completion("checkout-1", fast_polling())
+        .await
+        .expect_err("missing checkout should fail");
+
+    assert!(err.is_not_found());
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "webhooks")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use serde_json::json;
+use sumup::readers::ReaderCheckoutStatusChangePayloadStatus;
+use sumup::webhooks::{parse_event, WebhookEvent, WebhookHandler};
+use tower_service::Service;
+
+fn status_change(id: &str) -> Vec<u8> {
+    serde_json::to_vec(&json!({
+        "id": id,
+        "event_type": "solo.transaction.updated",
+        "timestamp": "2023-10-05T14:48:00Z",
+        "payload": {
+            "client_transaction_id": "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+            "merchant_code": "M1234567",
+            "status": "successful"
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn parse_event_dispatches_on_event_type() {
+    let event = parse_event(&status_change("evt_1")).expect("valid event");
+    let WebhookEvent::ReaderCheckoutStatusChange(change) = &event else {
+        panic!("unexpected event: {event:?}");
+    };
+    assert_eq!(
+        change.payload.status,
+        ReaderCheckoutStatusChangePayloadStatus::Successful
+    );
+    assert_eq!(event.id(), Some("evt_1"));
+
+    let unknown = parse_event(br#"{"id": "evt_2", "event_type": "new.event"}"#).unwrap();
+    assert_eq!(unknown.event_type(), Some("new.event"));
+    assert!(matches!(unknown, WebhookEvent::Unknown(_)));
+
+    assert!(parse_event(br#"{"event_type": "solo.transaction.updated"}"#).is_err());
+}
+
+#[tokio::test]
+async fn handler_deduplicates_deliveries_and_retries_failures() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let handler = WebhookHandler::new(move |_event: WebhookEvent| {
+        let call = counter.fetch_add(1, Ordering::SeqCst);
+        // The very first delivery fails.
+        async move {
+            if call == 0 {
+                Err("storage unavailable")
+            } else {
+                Ok(())
+            }
+        }
+    });
+
+    assert_eq!(handler.handle(&status_change("evt_1")).await, 500);
+    assert_eq!(handler.handle(&status_change("evt_1")).await, 200);
+    assert_eq!(handler.handle(&status_change("evt_1")).await, 200);
+    assert_eq!(handler.handle(b"not json").await, 400);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn duplicates_of_running_deliveries_are_retried() {
+    let (release, released) = tokio::sync::oneshot::channel::<()>();
+    let released = Arc::new(tokio::sync::Mutex::new(Some(released)));
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let handler = WebhookHandler::new(move |_event: WebhookEvent| {
+        let call = counter.fetch_add(1, Ordering::SeqCst);
+        let released = Arc::clone(&released);
+        // The first delivery fails once released, later ones succeed.
+        async move {
+            if call > 0 {
+                return Ok(());
+            }
+            if let Some(released) = released.lock().await.take() {
+                let _ = released.await;
+            }
+            Err("storage unavailable")
+        }
+    });
+
+    let first = tokio::spawn({
+        let handler = handler.clone();
+        async move { handler.handle(&status_change("evt_1")).await }
+    });
+    while calls.load(Ordering::SeqCst) == 0 {
+        tokio::task::yield_now().await;
+    }
+
+    assert_eq!(handler.handle(&status_change("evt_1")).await, 409);
+    release.send(()).unwrap();
+    assert_eq!(first.await.unwrap(), 500);
+
+    assert_eq!(handler.handle(&status_change("evt_1")).await, 200);
+    assert_eq!(handler.handle(&status_change("evt_1")).await, 200);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn handler_serves_http_requests() {
+    let mut handler =
+        WebhookHandler::new(|_event: WebhookEvent| async { Ok::<_, std::convert::Infallible>(()) })
+            .with_max_body_size(1024);
+
+    let request = http::Request::post("/callbacks")
+        .body(http_body_util::Full::new(bytes::Bytes::from(
+            status_change("evt_1"),
+        )))
+        .unwrap();
+    let response = handler.call(request).await.unwrap();
+    assert_eq!(response.status(), http::StatusCode::OK);
+
+    let request = http::Request::post("/callbacks")
+        .body(http_body_util::Full::new(bytes::Bytes::from(vec![
+            b' ';
+            2048
+        ])))
+        .unwrap();
+    let response = handler.call(request).await.unwrap();
+    assert_eq!(response.status(), http::StatusCode::PAYLOAD_TOO_LARGE);
+
+    let request = http::Request::get("/callbacks")
+        .body(http_body_util::Empty::<bytes::Bytes>::new())
+        .unwrap();
+    let response = handler.call(request).await.unwrap();
+    assert_eq!(response.status(), http::StatusCode::METHOD_NOT_ALLOWED);
+}